@@ -4,6 +4,15 @@ use syn::{Error, FnArg, LitStr, Path, Type, TypePath, TypeReference};
 
 use crate::{attribute::pass_through_attr_to_gen_code, symbol, syn_ext};
 
+/// The name of the function exported by contracts that declare an interface
+/// version with `contractinterface`.
+///
+/// This is not `__interface_version`: the host reserves functions starting
+/// with `__` and refuses to invoke them directly, so a client could never
+/// call it. The `soroban_` prefix keeps it apart from the contract's own
+/// functions instead.
+pub const INTERFACE_VERSION_FN: &str = "soroban_interface_version";
+
 pub fn derive_client_type(crate_path: &Path, ty: &str, name: &str) -> TokenStream {
     let ty_str = quote!(#ty).to_string();
     // Render the Client.
@@ -133,6 +142,30 @@ pub fn derive_client_type(crate_path: &Path, ty: &str, name: &str) -> TokenStrea
     }
 }
 
+/// Renders a `probe_version` function on the client of a contract that
+/// declares an interface version with `contractinterface`.
+pub fn derive_client_probe_version(crate_path: &Path, name: &str) -> TokenStream {
+    let client_ident = format_ident!("{}", name);
+    quote! {
+        impl<'a> #client_ident<'a> {
+            /// Returns the interface version declared by the contract with
+            /// `contractinterface`, or None if the contract does not declare one
+            /// or the call fails.
+            pub fn probe_version(&self) -> Option<u32> {
+                let res = self.env.try_invoke_contract::<u32, #crate_path::InvokeError>(
+                    &self.address,
+                    &#crate_path::Symbol::new(&self.env, #INTERFACE_VERSION_FN),
+                    #crate_path::Vec::new(&self.env),
+                );
+                match res {
+                    Ok(Ok(version)) => Some(version),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
 pub fn derive_client_impl(crate_path: &Path, name: &str, fns: &[syn_ext::Fn]) -> TokenStream {
     // Map the traits methods to methods for the Client.
     let mut errors = Vec::<Error>::new();
@@ -19,7 +19,13 @@ pub fn derive_type_error_enum_int(
     let mut errors = Vec::<Error>::new();
 
     let variants = &data.variants;
-    let (spec_cases, try_froms, into_errors, into_invoke_errors): (Vec<_>, Vec<_>, Vec<_>, Vec<_>) = variants
+    let (spec_cases, try_froms, into_errors, into_invoke_errors, as_u32s): (
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+    ) = variants
         .iter()
         .map(|v| {
             let ident = &v.ident;
@@ -53,7 +59,8 @@ pub fn derive_type_error_enum_int(
                 quote! { #enum_ident::#ident => #path::Error::from_contract_error(#discriminant) };
             let into_invoke_error =
                 quote! { #enum_ident::#ident => #path::InvokeError::Contract(#discriminant) };
-            (spec_case, try_from, into_error, into_invoke_error)
+            let as_u32 = quote! { #enum_ident::#ident => #discriminant };
+            (spec_case, try_from, into_error, into_invoke_error, as_u32)
         })
         .multiunzip();
 
@@ -93,6 +100,26 @@ pub fn derive_type_error_enum_int(
     quote! {
         #spec_gen
 
+        impl #enum_ident {
+            /// Returns the error code of the variant.
+            #[inline(always)]
+            pub const fn as_u32(&self) -> u32 {
+                match self {
+                    #(#as_u32s,)*
+                }
+            }
+
+            /// Returns the variant with the error code, or None if no variant
+            /// has the code.
+            #[inline(always)]
+            pub const fn from_u32(code: u32) -> Option<Self> {
+                Some(match code {
+                    #(#try_froms,)*
+                    _ => return None,
+                })
+            }
+        }
+
         impl TryFrom<#path::Error> for #enum_ident {
             type Error = #path::Error;
             #[inline(always)]
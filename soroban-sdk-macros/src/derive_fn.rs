@@ -19,6 +19,7 @@ pub fn derive_pub_fn(
     inputs: &Punctuated<FnArg, Comma>,
     trait_ident: Option<&Ident>,
     client_ident: &str,
+    auto_extend_instance: Option<(u32, u32)>,
 ) -> Result<TokenStream2, TokenStream2> {
     // Collect errors as they are encountered and emit them at the end.
     let mut errors = Vec::<Error>::new();
@@ -119,6 +120,11 @@ pub fn derive_pub_fn(
     } else {
         quote! {}
     };
+    let extend_instance = auto_extend_instance.map(|(threshold, extend_to)| {
+        quote! {
+            env.storage().instance().extend_ttl(#threshold, #extend_to);
+        }
+    });
 
     // If errors have occurred, render them instead.
     if !errors.is_empty() {
@@ -160,6 +166,7 @@ pub fn derive_pub_fn(
             #[deprecated(note = #deprecated_note)]
             pub fn invoke_raw(env: #crate_path::Env, #(#wrap_args),*) -> #crate_path::Val {
                 #use_trait;
+                #extend_instance
                 <_ as #crate_path::IntoVal<#crate_path::Env, #crate_path::Val>>::into_val(
                     #[allow(deprecated)]
                     &#call(
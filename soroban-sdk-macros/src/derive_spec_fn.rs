@@ -14,6 +14,10 @@ use syn::{
 use crate::attribute::pass_through_attr_to_gen_code;
 use crate::{doc::docs_from_attrs, map_type::map_type, DEFAULT_XDR_RW_LIMITS};
 
+/// The maximum number of parameters of a contract function, not counting the
+/// Env, which is the most the function's spec can hold.
+const MAX_FN_ARGS: usize = 10;
+
 #[allow(clippy::too_many_arguments)]
 pub fn derive_fn_spec(
     ty: &Type,
@@ -118,6 +122,7 @@ pub fn derive_fn_spec(
     };
 
     // Generated code spec.
+    let spec_args_len = spec_args.len();
     let name = &format!("{}", ident);
     let spec_entry = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
         doc: docs_from_attrs(attrs),
@@ -133,15 +138,18 @@ pub fn derive_fn_spec(
             ScSymbol::default()
         }),
         inputs: spec_args.try_into().unwrap_or_else(|_| {
-            const MAX: u32 = 10;
+            // Error at the first parameter over the limit.
+            let skip = if env_input.is_some() { 1 } else { 0 };
             errors.push(Error::new(
-                inputs.iter().nth(MAX as usize).span(),
+                inputs.iter().nth(skip + MAX_FN_ARGS).span(),
                 format!(
-                    "contract function has too many parameters, max count {} parameters",
-                    MAX,
+                    "contract function `{}` has {} parameters, but contract functions can have at most {} parameters, not counting the Env",
+                    ident,
+                    spec_args_len,
+                    MAX_FN_ARGS,
                 ),
             ));
-            VecM::<_, MAX>::default()
+            VecM::<_, { MAX_FN_ARGS as u32 }>::default()
         }),
         outputs: spec_result.try_into().unwrap(),
     });
@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, DataStruct, Error, Fields, Ident, Path};
+
+/// Generates functions for storing each field of a struct in its own
+/// persistent storage entry, keyed by a caller provided key and the field name.
+pub fn derive_split_storage(path: &Path, ident: &Ident, data: &DataStruct) -> TokenStream2 {
+    let Fields::Named(fields) = &data.fields else {
+        return Error::new(
+            data.fields.span(),
+            "split_storage is only supported on structs with named fields",
+        )
+        .to_compile_error();
+    };
+
+    let field_idents: Vec<_> = fields
+        .named
+        .iter()
+        .map(|f| f.ident.as_ref().unwrap())
+        .collect();
+    let field_names: Vec<_> = field_idents.iter().map(|i| i.to_string()).collect();
+    let load_idents: Vec<_> = field_names
+        .iter()
+        .map(|n| format_ident!("load_{}", n))
+        .collect();
+    let save_idents: Vec<_> = field_names
+        .iter()
+        .map(|n| format_ident!("save_{}", n))
+        .collect();
+
+    let field_fns = fields.named.iter().zip(&field_names).map(|(field, name)| {
+        let ty = &field.ty;
+        let load_ident = format_ident!("load_{}", name);
+        let save_ident = format_ident!("save_{}", name);
+        let load_doc = format!(
+            "Returns the `{name}` field stored under the key by [`{ident}::save_fields`], or None if there is none."
+        );
+        let save_doc = format!(
+            "Stores the `{name}` field under the key, without reading or writing the other fields."
+        );
+        quote! {
+            #[doc = #load_doc]
+            pub fn #load_ident<K: #path::IntoVal<#path::Env, #path::Val>>(env: &#path::Env, key: &K) -> Option<#ty> {
+                env.storage().persistent().get(&(#path::IntoVal::<#path::Env, #path::Val>::into_val(key, env), #path::Symbol::new(env, #name)))
+            }
+
+            #[doc = #save_doc]
+            pub fn #save_ident<K: #path::IntoVal<#path::Env, #path::Val>>(env: &#path::Env, key: &K, value: &#ty) {
+                env.storage().persistent().set(&(#path::IntoVal::<#path::Env, #path::Val>::into_val(key, env), #path::Symbol::new(env, #name)), value);
+            }
+        }
+    });
+
+    let load_fields_doc = format!(
+        "Returns the [`{ident}`] stored under the key by [`{ident}::save_fields`], or None if any field is missing."
+    );
+    let save_fields_doc = format!(
+        "Stores each field of the [`{ident}`] in its own persistent storage entry under the key."
+    );
+    let remove_fields_doc = format!("Removes every field of the [`{ident}`] stored under the key.");
+
+    quote! {
+        impl #ident {
+            #(#field_fns)*
+
+            #[doc = #load_fields_doc]
+            pub fn load_fields<K: #path::IntoVal<#path::Env, #path::Val>>(env: &#path::Env, key: &K) -> Option<Self> {
+                Some(Self {
+                    #(#field_idents: Self::#load_idents(env, key)?,)*
+                })
+            }
+
+            #[doc = #save_fields_doc]
+            pub fn save_fields<K: #path::IntoVal<#path::Env, #path::Val>>(&self, env: &#path::Env, key: &K) {
+                #(Self::#save_idents(env, key, &self.#field_idents);)*
+            }
+
+            #[doc = #remove_fields_doc]
+            pub fn remove_fields<K: #path::IntoVal<#path::Env, #path::Val>>(env: &#path::Env, key: &K) {
+                #(env.storage().persistent().remove(&(#path::IntoVal::<#path::Env, #path::Val>::into_val(key, env), #path::Symbol::new(env, #field_names)));)*
+            }
+        }
+    }
+}
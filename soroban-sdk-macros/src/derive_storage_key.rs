@@ -0,0 +1,186 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Ident, Path, Type};
+
+const ATTR: &str = "storage";
+
+/// The storage a variant's value is kept in.
+enum StorageKind {
+    Persistent,
+    Temporary,
+    Instance,
+}
+
+/// The parsed `#[storage(...)]` attribute of a variant.
+struct StorageAttr {
+    kind: StorageKind,
+    value: Type,
+}
+
+fn parse_storage_attr(variant_ident: &Ident, attr: &syn::Attribute) -> Result<StorageAttr, Error> {
+    let mut kind = None;
+    let mut value = None;
+    attr.parse_nested_meta(|meta| {
+        let k = if meta.path.is_ident("persistent") {
+            StorageKind::Persistent
+        } else if meta.path.is_ident("temporary") {
+            StorageKind::Temporary
+        } else if meta.path.is_ident("instance") {
+            StorageKind::Instance
+        } else if meta.path.is_ident("value") {
+            value = Some(meta.value()?.parse::<Type>()?);
+            return Ok(());
+        } else {
+            return Err(meta.error("expected `value`, `persistent`, `temporary`, or `instance`"));
+        };
+        if kind.replace(k).is_some() {
+            return Err(
+                meta.error("only one of `persistent`, `temporary`, or `instance` is allowed")
+            );
+        }
+        Ok(())
+    })?;
+    let value = value.ok_or_else(|| {
+        Error::new(
+            attr.span(),
+            format!(
+                "storage attribute on variant {} must specify the value type, e.g. `#[storage(value = u32)]`",
+                variant_ident
+            ),
+        )
+    })?;
+    Ok(StorageAttr {
+        kind: kind.unwrap_or(StorageKind::Persistent),
+        value,
+    })
+}
+
+fn snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev_lower = i > 0 && (chars[i - 1].is_lowercase() || chars[i - 1].is_numeric());
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            let prev_upper = i > 0 && chars[i - 1].is_uppercase();
+            if prev_lower || (prev_upper && next_lower) {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(*c);
+        }
+    }
+    out
+}
+
+pub fn derive_storage_key(path: &Path, input: &DeriveInput) -> TokenStream2 {
+    let enum_ident = &input.ident;
+    let data = match &input.data {
+        Data::Enum(e) => e,
+        _ => {
+            return Error::new(
+                input.ident.span(),
+                "StorageKey can only be derived for enums",
+            )
+            .to_compile_error()
+        }
+    };
+
+    // Collect errors as they are encountered and emit them at the end.
+    let mut errors = Vec::<Error>::new();
+    let mut fns = Vec::<TokenStream2>::new();
+
+    for variant in &data.variants {
+        let attrs: Vec<_> = variant
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident(ATTR))
+            .collect();
+        let attr = match attrs.as_slice() {
+            [] => continue,
+            [attr] => match parse_storage_attr(&variant.ident, attr) {
+                Ok(attr) => attr,
+                Err(e) => {
+                    errors.push(e);
+                    continue;
+                }
+            },
+            [_, dup, ..] => {
+                errors.push(Error::new(
+                    dup.span(),
+                    "only one storage attribute is allowed per variant",
+                ));
+                continue;
+            }
+        };
+
+        let variant_ident = &variant.ident;
+        let (field_idents, field_types): (Vec<_>, Vec<_>) = match &variant.fields {
+            Fields::Unit => (vec![], vec![]),
+            Fields::Unnamed(f) => f
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, f)| (format_ident!("field_{}", i), &f.ty))
+                .unzip(),
+            Fields::Named(_) => {
+                errors.push(Error::new(
+                    variant.span(),
+                    "StorageKey variants must be unit or tuple variants",
+                ));
+                continue;
+            }
+        };
+        let key = if field_idents.is_empty() {
+            quote! { Self::#variant_ident }
+        } else {
+            quote! { Self::#variant_ident(#(#field_idents.clone()),*) }
+        };
+        let storage = match attr.kind {
+            StorageKind::Persistent => quote! { persistent },
+            StorageKind::Temporary => quote! { temporary },
+            StorageKind::Instance => quote! { instance },
+        };
+        let value = &attr.value;
+
+        let variant_name = snake_case(&variant_ident.to_string());
+        let load_ident = format_ident!("load_{}", variant_name);
+        let save_ident = format_ident!("save_{}", variant_name);
+        let remove_ident = format_ident!("remove_{}", variant_name);
+        let load_doc = format!(
+            "Returns the value stored under the [`{enum_ident}::{variant_ident}`] key, or None if there is none."
+        );
+        let save_doc = format!("Stores the value under the [`{enum_ident}::{variant_ident}`] key.");
+        let remove_doc =
+            format!("Removes the value stored under the [`{enum_ident}::{variant_ident}`] key.");
+
+        fns.push(quote! {
+            #[doc = #load_doc]
+            pub fn #load_ident(env: &#path::Env, #(#field_idents: &#field_types),*) -> Option<#value> {
+                env.storage().#storage().get(&#key)
+            }
+
+            #[doc = #save_doc]
+            pub fn #save_ident(env: &#path::Env, #(#field_idents: &#field_types,)* value: &#value) {
+                env.storage().#storage().set(&#key, value);
+            }
+
+            #[doc = #remove_doc]
+            pub fn #remove_ident(env: &#path::Env, #(#field_idents: &#field_types),*) {
+                env.storage().#storage().remove(&#key);
+            }
+        });
+    }
+
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(Error::to_compile_error);
+        return quote! { #(#compile_errors)* };
+    }
+
+    quote! {
+        impl #enum_ident {
+            #(#fns)*
+        }
+    }
+}
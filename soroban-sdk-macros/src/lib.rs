@@ -9,6 +9,8 @@ mod derive_enum_int;
 mod derive_error_enum_int;
 mod derive_fn;
 mod derive_spec_fn;
+mod derive_split_storage;
+mod derive_storage_key;
 mod derive_struct;
 mod derive_struct_tuple;
 mod doc;
@@ -18,12 +20,16 @@ mod symbol;
 mod syn_ext;
 
 use derive_args::{derive_args_impl, derive_args_type};
-use derive_client::{derive_client_impl, derive_client_type};
+use derive_client::{
+    derive_client_impl, derive_client_probe_version, derive_client_type, INTERFACE_VERSION_FN,
+};
 use derive_enum::derive_type_enum;
 use derive_enum_int::derive_type_enum_int;
 use derive_error_enum_int::derive_type_error_enum_int;
 use derive_fn::{derive_contract_function_registration_ctor, derive_pub_fn};
 use derive_spec_fn::derive_fn_spec;
+use derive_split_storage::derive_split_storage;
+use derive_storage_key::derive_storage_key;
 use derive_struct::derive_type_struct;
 use derive_struct_tuple::derive_type_struct_tuple;
 
@@ -190,6 +196,14 @@ pub fn contract(metadata: TokenStream, input: TokenStream) -> TokenStream {
 struct ContractImplArgs {
     #[darling(default = "default_crate_path")]
     crate_path: Path,
+    #[darling(default)]
+    auto_extend_instance: Option<AutoExtendInstanceArgs>,
+}
+
+#[derive(Debug, FromMeta)]
+struct AutoExtendInstanceArgs {
+    threshold: u32,
+    extend_to: u32,
 }
 
 #[proc_macro_attribute]
@@ -204,6 +218,22 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
         Ok(v) => v,
         Err(e) => return e.write_errors().into(),
     };
+    let auto_extend_instance = args
+        .auto_extend_instance
+        .as_ref()
+        .map(|a| (a.threshold, a.extend_to));
+    if let Some((threshold, extend_to)) = auto_extend_instance {
+        if threshold > extend_to {
+            return Error::new(
+                Span::call_site(),
+                format!(
+                    "auto_extend_instance threshold ({threshold}) must not be greater than extend_to ({extend_to})"
+                ),
+            )
+            .into_compile_error()
+            .into();
+        }
+    }
     let crate_path = &args.crate_path;
     let crate_path_str = quote!(#crate_path).to_string();
 
@@ -250,6 +280,7 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
                 &m.sig.inputs,
                 trait_ident,
                 &client_ident,
+                auto_extend_instance,
             )
         })
         .collect();
@@ -382,6 +413,8 @@ struct ContractTypeArgs {
     crate_path: Path,
     lib: Option<String>,
     export: Option<bool>,
+    #[darling(default)]
+    split_storage: bool,
 }
 
 #[proc_macro_attribute]
@@ -449,13 +482,33 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
         )
         .to_compile_error(),
     };
+    let split_storage = match &input.data {
+        Data::Struct(s) if args.split_storage => {
+            Some(derive_split_storage(&args.crate_path, ident, s))
+        }
+        _ if args.split_storage => Some(
+            Error::new(
+                input.ident.span(),
+                "split_storage is only supported on structs with named fields",
+            )
+            .to_compile_error(),
+        ),
+        _ => None,
+    };
     quote! {
         #input
         #derived
+        #split_storage
     }
     .into()
 }
 
+#[proc_macro_derive(StorageKey, attributes(storage))]
+pub fn storagekey(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_storage_key(&default_crate_path(), &input).into()
+}
+
 #[proc_macro_attribute]
 pub fn contracterror(metadata: TokenStream, input: TokenStream) -> TokenStream {
     let args = match NestedMeta::parse_meta_list(metadata.into()) {
@@ -619,6 +672,50 @@ pub fn contractclient(metadata: TokenStream, input: TokenStream) -> TokenStream
     .into()
 }
 
+#[derive(Debug, FromMeta)]
+struct ContractInterfaceArgs {
+    #[darling(default = "default_crate_path")]
+    crate_path: Path,
+    version: u32,
+}
+
+#[proc_macro_attribute]
+pub fn contractinterface(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = match NestedMeta::parse_meta_list(metadata.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(darling::Error::from(e).write_errors());
+        }
+    };
+    let args = match ContractInterfaceArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+    let input2: TokenStream2 = input.clone().into();
+    let item = parse_macro_input!(input as ItemStruct);
+    let ty = &item.ident;
+    let crate_path = &args.crate_path;
+    let crate_path_str = quote!(#crate_path).to_string();
+    let fn_ident = format_ident!("{}", INTERFACE_VERSION_FN);
+    let version = args.version;
+    let client_ident = format!("{}Client", quote!(#ty));
+    let probe_version = derive_client_probe_version(crate_path, &client_ident);
+    quote! {
+        #input2
+
+        #probe_version
+
+        #[#crate_path::contractimpl(crate_path = #crate_path_str)]
+        impl #ty {
+            /// Returns the version of the interface the contract implements.
+            pub fn #fn_ident() -> u32 {
+                #version
+            }
+        }
+    }
+    .into()
+}
+
 #[derive(Debug, FromMeta)]
 struct ContractImportArgs {
     file: String,
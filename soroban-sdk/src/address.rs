@@ -9,7 +9,16 @@ use super::{
 use crate::env::internal::xdr::ScVal;
 #[cfg(any(test, feature = "testutils", not(target_family = "wasm")))]
 use crate::env::xdr::ScAddress;
-use crate::{unwrap::UnwrapInfallible, Bytes, Vec};
+use crate::{unwrap::UnwrapInfallible, xdr::ToXdr, Bytes, BytesN, Vec};
+
+/// The leading bytes of the XDR encoding of an `ScVal::Address` holding a
+/// contract address: the big-endian `ScValType::Address` discriminant (18)
+/// followed by the big-endian `ScAddressType::Contract` discriminant (1).
+const CONTRACT_ADDRESS_XDR_HEADER: [u8; 8] = [0, 0, 0, 18, 0, 0, 0, 1];
+
+/// The length of the XDR encoding of an `ScVal::Address` holding a contract
+/// address: the header followed by the 32-byte contract ID.
+const CONTRACT_ADDRESS_XDR_LEN: u32 = CONTRACT_ADDRESS_XDR_HEADER.len() as u32 + 32;
 
 /// Address is a universal opaque identifier to use in contracts.
 ///
@@ -217,6 +226,21 @@ impl Address {
     /// authorize the contract invocation and there are no dynamic arguments
     /// that don't need authorization.
     ///
+    /// Contracts have no way to read the address of their immediate caller.
+    /// For caller-based access control, take the caller as an argument and
+    /// call `require_auth` on it. A contract that directly invokes the current
+    /// contract is authorized implicitly, so it can pass
+    /// [`Env::current_contract_address`][crate::Env::current_contract_address]
+    /// without any signatures, while any other caller claiming that address
+    /// fails authorization.
+    ///
+    /// Contracts also cannot read the source account of the transaction, the
+    /// account paying its fees, because the host does not expose it. A
+    /// contract that needs the source account, such as to rebate fees, should
+    /// take it as an argument and call `require_auth` on it. The source
+    /// account can authorize with its signature on the transaction, so this
+    /// does not require it to sign anything extra.
+    ///
     /// ### Panics
     ///
     /// If the invocation is not authorized.
@@ -285,6 +309,26 @@ impl Address {
         .unwrap_optimized()
     }
 
+    /// Returns the 32-byte contract ID if the address is a contract address,
+    /// or `None` if it is an account address.
+    pub fn contract_id_bytes(&self) -> Option<BytesN<32>> {
+        let xdr = self.to_val().to_xdr(&self.env);
+        if xdr.len() != CONTRACT_ADDRESS_XDR_LEN {
+            return None;
+        }
+        let mut header = [0u8; CONTRACT_ADDRESS_XDR_HEADER.len()];
+        xdr.slice(..CONTRACT_ADDRESS_XDR_HEADER.len() as u32)
+            .copy_into_slice(&mut header);
+        if header != CONTRACT_ADDRESS_XDR_HEADER {
+            return None;
+        }
+        Some(
+            xdr.slice(CONTRACT_ADDRESS_XDR_HEADER.len() as u32..)
+                .try_into()
+                .unwrap_optimized(),
+        )
+    }
+
     #[inline(always)]
     pub(crate) unsafe fn unchecked_new(env: Env, obj: AddressObject) -> Self {
         Self { env, obj }
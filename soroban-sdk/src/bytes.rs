@@ -13,13 +13,53 @@ use core::{
 use super::{
     env::internal::{BytesObject, Env as _, EnvBase as _},
     env::IntoVal,
-    ConversionError, Env, TryFromVal, TryIntoVal, Val,
+    xdr::{ScErrorCode, ScErrorType},
+    ConversionError, Env, Error, TryFromVal, TryIntoVal, Val,
 };
 
 use crate::unwrap::{UnwrapInfallible, UnwrapOptimized};
+use crate::String;
 #[cfg(doc)]
 use crate::{storage::Storage, Map, Vec};
 
+/// The standard base64 alphabet of RFC 4648.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Returns the value of a character of the standard base64 alphabet.
+fn base64_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// The lookup table for CRC-32/ISO-HDLC, the reflected polynomial
+/// 0xEDB88320, one entry per byte value.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
 #[cfg(not(target_family = "wasm"))]
 use super::xdr::ScVal;
 
@@ -441,6 +481,15 @@ impl Bytes {
         }
     }
 
+    /// Create a Bytes from a Rust alloc Vec.
+    ///
+    /// The inverse of [`Bytes::to_alloc_vec`].
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub fn from_alloc_vec(env: &Env, vec: alloc::vec::Vec<u8>) -> Bytes {
+        Self::from_slice(env, vec.as_slice())
+    }
+
     /// Sets the byte at the position with new value.
     ///
     /// ### Panics
@@ -713,6 +762,296 @@ impl Bytes {
         self.clone().into_iter()
     }
 
+    /// Returns an iterator over the bytes interpreted as a sequence of
+    /// big-endian `u32`s.
+    ///
+    /// ### Panics
+    ///
+    /// If the length of the bytes is not a multiple of 4.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let bytes = Bytes::from_array(&env, &[0, 0, 0, 1, 0, 0, 1, 0]);
+    /// let mut iter = bytes.iter_u32_be();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(256));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_u32_be(&self) -> BytesU32BeIter {
+        if self.len() % 4 != 0 {
+            sdk_panic!("Bytes::iter_u32_be with length not a multiple of 4")
+        }
+        BytesU32BeIter(BytesReader::new(self))
+    }
+
+    /// Returns an iterator over the bytes interpreted as a sequence of
+    /// big-endian `u64`s.
+    ///
+    /// ### Panics
+    ///
+    /// If the length of the bytes is not a multiple of 8.
+    pub fn iter_u64_be(&self) -> BytesU64BeIter {
+        if self.len() % 8 != 0 {
+            sdk_panic!("Bytes::iter_u64_be with length not a multiple of 8")
+        }
+        BytesU64BeIter(BytesReader::new(self))
+    }
+
+    /// Returns the bitwise XOR of the bytes and `other`, byte by byte.
+    ///
+    /// ### Panics
+    ///
+    /// If the bytes and `other` are of different lengths.
+    #[must_use]
+    pub fn xor(&self, other: &Bytes) -> Bytes {
+        if self.len() != other.len() {
+            sdk_panic!("Bytes::xor with mismatched lengths")
+        }
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Returns the bitwise AND of the bytes and `other`, byte by byte.
+    ///
+    /// ### Panics
+    ///
+    /// If the bytes and `other` are of different lengths.
+    #[must_use]
+    pub fn and(&self, other: &Bytes) -> Bytes {
+        if self.len() != other.len() {
+            sdk_panic!("Bytes::and with mismatched lengths")
+        }
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns the bitwise OR of the bytes and `other`, byte by byte.
+    ///
+    /// ### Panics
+    ///
+    /// If the bytes and `other` are of different lengths.
+    #[must_use]
+    pub fn or(&self, other: &Bytes) -> Bytes {
+        if self.len() != other.len() {
+            sdk_panic!("Bytes::or with mismatched lengths")
+        }
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns the bytes in reverse order, such as for converting a
+    /// big-endian encoding to little-endian.
+    #[must_use]
+    pub fn reverse(&self) -> Bytes {
+        // Reverse in chunks from the end to bound the size of the buffer.
+        let mut buf = [0u8; 64];
+        let mut out = Bytes::new(self.env());
+        let mut end = self.len();
+        while end > 0 {
+            let n = end.min(buf.len() as u32);
+            let chunk = &mut buf[..n as usize];
+            self.slice(end - n..end).copy_into_slice(chunk);
+            chunk.reverse();
+            out.extend_from_slice(chunk);
+            end -= n;
+        }
+        out
+    }
+
+    /// Returns the bytes encoded as base64, using the standard alphabet of
+    /// RFC 4648 and padded with `=` to a multiple of four characters.
+    ///
+    /// The encoding is computed by the SDK in the contract, as the host has
+    /// no base64 functions, so its cost grows with the length of the bytes.
+    ///
+    /// ### Panics
+    ///
+    /// If the encoding is longer than 4096 bytes, which is the case for bytes
+    /// longer than 3072 bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env, String};
+    ///
+    /// let env = Env::default();
+    /// let bytes = Bytes::from_slice(&env, b"hi!?");
+    /// assert_eq!(bytes.to_base64(), String::from_str(&env, "aGkhPw=="));
+    /// ```
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        let env = self.env();
+        let mut encoded = Bytes::new(env);
+        // Encode in chunks to bound the size of the buffers.
+        let mut buf = [0u8; 48];
+        let mut output = [0u8; 64];
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(buf.len() as u32);
+            let input = &mut buf[..n as usize];
+            self.slice(i..i + n).copy_into_slice(input);
+            let mut o = 0;
+            for c in input.chunks(3) {
+                let b = [
+                    c[0],
+                    c.get(1).copied().unwrap_or(0),
+                    c.get(2).copied().unwrap_or(0),
+                ];
+                output[o] = BASE64_ALPHABET[(b[0] >> 2) as usize];
+                output[o + 1] = BASE64_ALPHABET[(((b[0] & 0x03) << 4) | (b[1] >> 4)) as usize];
+                output[o + 2] = if c.len() > 1 {
+                    BASE64_ALPHABET[(((b[1] & 0x0f) << 2) | (b[2] >> 6)) as usize]
+                } else {
+                    b'='
+                };
+                output[o + 3] = if c.len() > 2 {
+                    BASE64_ALPHABET[(b[2] & 0x3f) as usize]
+                } else {
+                    b'='
+                };
+                o += 4;
+            }
+            encoded.extend_from_slice(&output[..o]);
+            i += n;
+        }
+        String::from_contents(env, &encoded)
+    }
+
+    /// Returns the bytes decoded from base64 in the standard alphabet of
+    /// RFC 4648.
+    ///
+    /// The input must be padded with `=` to a multiple of four characters.
+    /// The URL-safe alphabet, unpadded input, and whitespace are not
+    /// supported, and unused bits in the final character must be zero.
+    ///
+    /// As with [`to_base64`][Self::to_base64], the decoding is computed by the
+    /// SDK in the contract.
+    ///
+    /// ### Errors
+    ///
+    /// If the input is not valid padded base64.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env, String};
+    ///
+    /// let env = Env::default();
+    /// let encoded = String::from_str(&env, "aGkhPw==");
+    /// assert_eq!(
+    ///     Bytes::from_base64(&env, &encoded),
+    ///     Ok(Bytes::from_slice(&env, b"hi!?")),
+    /// );
+    /// ```
+    pub fn from_base64(env: &Env, encoded: &String) -> Result<Bytes, ConversionError> {
+        let encoded = encoded.contents();
+        let len = encoded.len();
+        if len % 4 != 0 {
+            return Err(ConversionError);
+        }
+        let mut decoded = Bytes::new(env);
+        // Decode in chunks to bound the size of the buffers.
+        let mut buf = [0u8; 64];
+        let mut output = [0u8; 48];
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(buf.len() as u32);
+            let input = &mut buf[..n as usize];
+            encoded.slice(i..i + n).copy_into_slice(input);
+            // Only the final group of four characters may be padded.
+            let padded_group = if i + n == len {
+                n as usize / 4 - 1
+            } else {
+                usize::MAX
+            };
+            let mut o = 0;
+            for (g, c) in input.chunks(4).enumerate() {
+                let pad = if g == padded_group {
+                    c.iter().rev().take_while(|&&c| c == b'=').count()
+                } else {
+                    0
+                };
+                if pad > 2 {
+                    return Err(ConversionError);
+                }
+                let mut v = [0u8; 4];
+                for (v, c) in v.iter_mut().zip(&c[..4 - pad]) {
+                    *v = base64_value(*c).ok_or(ConversionError)?;
+                }
+                if (pad == 1 && v[2] & 0x03 != 0) || (pad == 2 && v[1] & 0x0f != 0) {
+                    return Err(ConversionError);
+                }
+                output[o] = (v[0] << 2) | (v[1] >> 4);
+                output[o + 1] = (v[1] << 4) | (v[2] >> 2);
+                output[o + 2] = (v[2] << 6) | v[3];
+                o += 3 - pad;
+            }
+            decoded.extend_from_slice(&output[..o]);
+            i += n;
+        }
+        Ok(decoded)
+    }
+
+    /// Returns the CRC-32 checksum of the bytes.
+    ///
+    /// The variant is CRC-32/ISO-HDLC, the CRC-32 of zlib, gzip, and PNG: the
+    /// reflected polynomial 0xEDB88320, with an initial value and final XOR of
+    /// 0xFFFFFFFF.
+    ///
+    /// The checksum is computed by the SDK in the contract, as the host has no
+    /// CRC functions, so its cost grows with the length of the bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let bytes = Bytes::from_slice(&env, b"123456789");
+    /// assert_eq!(bytes.crc32(), 0xCBF43926);
+    /// ```
+    pub fn crc32(&self) -> u32 {
+        // Read in chunks to bound the size of the buffer.
+        let mut buf = [0u8; 64];
+        let mut crc = 0xFFFF_FFFFu32;
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(buf.len() as u32);
+            let input = &mut buf[..n as usize];
+            self.slice(i..i + n).copy_into_slice(input);
+            for b in input.iter() {
+                crc = (crc >> 8) ^ CRC32_TABLE[((crc ^ *b as u32) & 0xff) as usize];
+            }
+            i += n;
+        }
+        !crc
+    }
+
+    fn zip_with(&self, other: &Bytes, f: impl Fn(u8, u8) -> u8) -> Bytes {
+        // Combine in chunks to bound the size of the buffers.
+        let mut buf = [0u8; 64];
+        let mut other_buf = [0u8; 64];
+        let mut out = Bytes::new(self.env());
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(buf.len() as u32);
+            let chunk = &mut buf[..n as usize];
+            let other_chunk = &mut other_buf[..n as usize];
+            self.slice(i..i + n).copy_into_slice(chunk);
+            other.slice(i..i + n).copy_into_slice(other_chunk);
+            for (a, b) in chunk.iter_mut().zip(other_chunk.iter()) {
+                *a = f(*a, *b);
+            }
+            out.extend_from_slice(chunk);
+            i += n;
+        }
+        out
+    }
+
     /// Copy the bytes into a buffer of given size.
     ///
     /// Returns the buffer and a range of where the bytes live in the given
@@ -846,6 +1185,260 @@ impl ExactSizeIterator for BytesIter {
     }
 }
 
+/// An iterator over big-endian `u32`s, returned by [`Bytes::iter_u32_be`].
+#[derive(Clone)]
+pub struct BytesU32BeIter(BytesReader);
+
+impl Iterator for BytesU32BeIter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.read_u32_be().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.0.remaining() / 4) as usize;
+        (len, Some(len))
+    }
+}
+
+impl FusedIterator for BytesU32BeIter {}
+
+impl ExactSizeIterator for BytesU32BeIter {}
+
+/// An iterator over big-endian `u64`s, returned by [`Bytes::iter_u64_be`].
+#[derive(Clone)]
+pub struct BytesU64BeIter(BytesReader);
+
+impl Iterator for BytesU64BeIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.read_u64_be().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.0.remaining() / 8) as usize;
+        (len, Some(len))
+    }
+}
+
+impl FusedIterator for BytesU64BeIter {}
+
+impl ExactSizeIterator for BytesU64BeIter {}
+
+/// A `BytesReader` reads values from the front of a [Bytes], advancing a
+/// cursor past each value read.
+///
+/// Multi-byte integers are read in big-endian byte order.
+///
+/// Reading past the end of the bytes returns an error with type
+/// [`ScErrorType::Object`] and code [`ScErrorCode::IndexBounds`], and leaves
+/// the position unchanged.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Bytes, BytesReader, Env};
+///
+/// # fn main() -> Result<(), soroban_sdk::Error> {
+/// let env = Env::default();
+/// let bytes = Bytes::from_array(&env, &[1, 0, 0, 0, 2, 3, 4]);
+/// let mut reader = BytesReader::new(&bytes);
+/// assert_eq!(reader.read_u8()?, 1);
+/// assert_eq!(reader.read_u32_be()?, 2);
+/// assert_eq!(reader.read_bytes(2)?, Bytes::from_array(&env, &[3, 4]));
+/// assert_eq!(reader.position(), 7);
+/// assert!(reader.read_u8().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BytesReader {
+    bytes: Bytes,
+    pos: u32,
+}
+
+impl BytesReader {
+    /// Create a reader positioned at the start of the bytes.
+    #[inline(always)]
+    pub fn new(bytes: &Bytes) -> Self {
+        Self {
+            bytes: bytes.clone(),
+            pos: 0,
+        }
+    }
+
+    /// Returns the position of the next byte to be read.
+    #[inline(always)]
+    pub fn position(&self) -> u32 {
+        self.pos
+    }
+
+    /// Returns the number of bytes remaining to be read.
+    #[inline(always)]
+    pub fn remaining(&self) -> u32 {
+        self.bytes.len() - self.pos
+    }
+
+    /// Returns true if there are no bytes remaining to be read.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Read a single byte.
+    ///
+    /// ### Errors
+    ///
+    /// If there are no bytes remaining.
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        let [b] = self.read_array::<1>()?;
+        Ok(b)
+    }
+
+    /// Read a big-endian `u32`.
+    ///
+    /// ### Errors
+    ///
+    /// If there are less than 4 bytes remaining.
+    pub fn read_u32_be(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    /// Read a big-endian `u64`.
+    ///
+    /// ### Errors
+    ///
+    /// If there are less than 8 bytes remaining.
+    pub fn read_u64_be(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_be_bytes(self.read_array()?))
+    }
+
+    /// Read the next `n` bytes.
+    ///
+    /// ### Errors
+    ///
+    /// If there are less than `n` bytes remaining.
+    pub fn read_bytes(&mut self, n: u32) -> Result<Bytes, Error> {
+        let start = self.advance(n)?;
+        Ok(self.bytes.slice(start..self.pos))
+    }
+
+    /// Read the next `N` bytes into an array.
+    ///
+    /// ### Errors
+    ///
+    /// If there are less than `N` bytes remaining.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let start = self.advance(N as u32)?;
+        let mut array = [0u8; N];
+        self.bytes
+            .slice(start..self.pos)
+            .copy_into_slice(&mut array);
+        Ok(array)
+    }
+
+    /// Returns the bytes that have not been read yet.
+    #[must_use]
+    pub fn rest(&self) -> Bytes {
+        self.bytes.slice(self.pos..)
+    }
+
+    /// Move the position forward by `n` bytes, returning the previous
+    /// position.
+    fn advance(&mut self, n: u32) -> Result<u32, Error> {
+        if n > self.remaining() {
+            return Err(Error::from_type_and_code(
+                ScErrorType::Object,
+                ScErrorCode::IndexBounds,
+            ));
+        }
+        let start = self.pos;
+        self.pos += n;
+        Ok(start)
+    }
+}
+
+/// A `BytesWriter` builds a [Bytes] by appending values to its end.
+///
+/// Multi-byte integers are written in big-endian byte order, matching
+/// [BytesReader].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Bytes, BytesWriter, Env};
+///
+/// let env = Env::default();
+/// let mut writer = BytesWriter::new(&env);
+/// writer.write_u8(1);
+/// writer.write_u32_be(2);
+/// assert_eq!(writer.into_bytes(), Bytes::from_array(&env, &[1, 0, 0, 0, 2]));
+/// ```
+#[derive(Clone)]
+pub struct BytesWriter {
+    bytes: Bytes,
+}
+
+impl BytesWriter {
+    /// Create a writer with no bytes written.
+    #[inline(always)]
+    pub fn new(env: &Env) -> Self {
+        Self {
+            bytes: Bytes::new(env),
+        }
+    }
+
+    /// Returns the number of bytes written.
+    #[inline(always)]
+    pub fn len(&self) -> u32 {
+        self.bytes.len()
+    }
+
+    /// Returns true if no bytes have been written.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Write a single byte.
+    #[inline(always)]
+    pub fn write_u8(&mut self, v: u8) {
+        self.bytes.push_back(v);
+    }
+
+    /// Write a `u32` in big-endian byte order.
+    #[inline(always)]
+    pub fn write_u32_be(&mut self, v: u32) {
+        self.bytes.extend_from_array(&v.to_be_bytes());
+    }
+
+    /// Write a `u64` in big-endian byte order.
+    #[inline(always)]
+    pub fn write_u64_be(&mut self, v: u64) {
+        self.bytes.extend_from_array(&v.to_be_bytes());
+    }
+
+    /// Write the bytes.
+    #[inline(always)]
+    pub fn write_bytes(&mut self, b: &Bytes) {
+        self.bytes.append(b);
+    }
+
+    /// Write the bytes in the slice.
+    #[inline(always)]
+    pub fn write_slice(&mut self, slice: &[u8]) {
+        self.bytes.extend_from_slice(slice);
+    }
+
+    /// Returns the bytes written.
+    #[inline(always)]
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
 /// BytesN is a contiguous fixed-size array type containing `u8`s.
 ///
 /// The array is stored in the Host and available to the Guest through the
@@ -1217,6 +1810,71 @@ impl<const N: usize> BytesN<N> {
     pub fn iter(&self) -> BytesIter {
         self.clone().into_iter()
     }
+
+    /// Returns the bitwise XOR of the bytes and `other`, byte by byte.
+    #[must_use]
+    pub fn xor(&self, other: &BytesN<N>) -> BytesN<N> {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Returns the bitwise AND of the bytes and `other`, byte by byte.
+    #[must_use]
+    pub fn and(&self, other: &BytesN<N>) -> BytesN<N> {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns the bitwise OR of the bytes and `other`, byte by byte.
+    #[must_use]
+    pub fn or(&self, other: &BytesN<N>) -> BytesN<N> {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns the bytes in reverse order, such as for swapping the
+    /// endianness of a fixed-width integer.
+    #[must_use]
+    pub fn reverse(&self) -> BytesN<N> {
+        let mut array = self.to_array();
+        array.reverse();
+        BytesN::from_array(self.env(), &array)
+    }
+
+    /// Returns the bytes followed by the bytes of `other`.
+    ///
+    /// The length of the result is checked at compile time, so the result
+    /// type must be a [BytesN] exactly as long as both inputs combined.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{BytesN, Env};
+    ///
+    /// let env = Env::default();
+    /// let r = BytesN::from_array(&env, &[1; 32]);
+    /// let s = BytesN::from_array(&env, &[2; 32]);
+    /// let sig: BytesN<64> = r.concat(&s);
+    /// assert_eq!(sig.get(31), Some(1));
+    /// assert_eq!(sig.get(32), Some(2));
+    /// ```
+    #[must_use]
+    pub fn concat<const B: usize, const C: usize>(&self, other: &BytesN<B>) -> BytesN<C> {
+        const {
+            assert!(
+                N + B == C,
+                "BytesN::concat result length must equal the sum of the input lengths"
+            )
+        };
+        let mut bytes = self.0.clone();
+        bytes.append(&other.0);
+        BytesN(bytes)
+    }
+
+    fn zip_with(&self, other: &BytesN<N>, f: impl Fn(u8, u8) -> u8) -> BytesN<N> {
+        let mut array = self.to_array();
+        for (a, b) in array.iter_mut().zip(other.to_array()) {
+            *a = f(*a, b);
+        }
+        BytesN::from_array(self.env(), &array)
+    }
 }
 
 #[cfg(any(test, feature = "testutils"))]
@@ -1752,4 +2410,339 @@ mod test {
         let bin = bytes![&env, [0, 1, 2, 3, 4]];
         let _ = bin.slice(..=bin.len());
     }
+
+    #[test]
+    fn test_bytes_bitwise() {
+        let env = Env::default();
+        let a = bytes![&env, [0b1100, 0xff, 0x00]];
+        let b = bytes![&env, [0b1010, 0x0f, 0x00]];
+        assert_eq!(a.xor(&b), bytes![&env, [0b0110, 0xf0, 0x00]]);
+        assert_eq!(a.and(&b), bytes![&env, [0b1000, 0x0f, 0x00]]);
+        assert_eq!(a.or(&b), bytes![&env, [0b1110, 0xff, 0x00]]);
+
+        let zeros = Bytes::from_array(&env, &[0; 3]);
+        let ones = Bytes::from_array(&env, &[0xff; 3]);
+        assert_eq!(a.xor(&a), zeros);
+        assert_eq!(a.xor(&zeros), a);
+        assert_eq!(a.xor(&ones), bytes![&env, [0xf3, 0x00, 0xff]]);
+        assert_eq!(a.and(&zeros), zeros);
+        assert_eq!(a.and(&ones), a);
+        assert_eq!(a.or(&zeros), a);
+        assert_eq!(a.or(&ones), ones);
+
+        let empty = Bytes::new(&env);
+        assert_eq!(empty.xor(&empty), empty);
+
+        // Lengths around the internal chunk size.
+        for len in [63u32, 64, 65, 200] {
+            let mut a = Bytes::new(&env);
+            let mut b = Bytes::new(&env);
+            let mut expected = Bytes::new(&env);
+            for i in 0..len {
+                let (x, y) = ((i * 37 % 256) as u8, (i * 11 % 256) as u8);
+                a.push_back(x);
+                b.push_back(y);
+                expected.push_back(x ^ y);
+            }
+            assert_eq!(a.xor(&b), expected);
+        }
+    }
+
+    #[test]
+    fn test_base64() {
+        let env = Env::default();
+        // The test vectors of RFC 4648, covering each padding length.
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (raw, encoded) in vectors {
+            let bytes = Bytes::from_slice(&env, raw.as_bytes());
+            let encoded = String::from_str(&env, encoded);
+            assert_eq!(bytes.to_base64(), encoded);
+            assert_eq!(Bytes::from_base64(&env, &encoded), Ok(bytes));
+        }
+
+        // Characters at both ends of the alphabet.
+        let bytes = bytes![&env, [0xfb, 0xff, 0xbf, 0x00]];
+        assert_eq!(bytes.to_base64(), String::from_str(&env, "+/+/AA=="));
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        let env = Env::default();
+        // Lengths around the internal chunk sizes.
+        for len in [1u32, 2, 3, 47, 48, 49, 95, 96, 97, 200] {
+            let mut bytes = Bytes::new(&env);
+            for i in 0..len {
+                bytes.push_back((i * 37 % 256) as u8);
+            }
+            let encoded = bytes.to_base64();
+            assert_eq!(encoded.len(), (len + 2) / 3 * 4);
+            assert_eq!(Bytes::from_base64(&env, &encoded), Ok(bytes));
+        }
+    }
+
+    #[test]
+    fn test_base64_invalid() {
+        let env = Env::default();
+        let invalid = [
+            // Invalid characters, including the URL-safe alphabet.
+            "Zm9v!A==", "Zm9v-A==", "Zm9v_A==", "Zm 9", // Missing or misplaced padding.
+            "Zg", "Zm9", "Z===", "====", "Zg==Zm9v", "Zm=v", // Nonzero unused bits.
+            "Zh==", "Zm9=",
+        ];
+        for encoded in invalid {
+            let encoded = String::from_str(&env, encoded);
+            assert_eq!(Bytes::from_base64(&env, &encoded), Err(ConversionError));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes::xor with mismatched lengths")]
+    fn test_bytes_xor_mismatched_lengths() {
+        let env = Env::default();
+        let _ = bytes![&env, [1, 2]].xor(&bytes![&env, [1, 2, 3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes::and with mismatched lengths")]
+    fn test_bytes_and_mismatched_lengths() {
+        let env = Env::default();
+        let _ = bytes![&env, [1, 2]].and(&bytes![&env, [1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes::or with mismatched lengths")]
+    fn test_bytes_or_mismatched_lengths() {
+        let env = Env::default();
+        let _ = Bytes::new(&env).or(&bytes![&env, [1]]);
+    }
+
+    #[test]
+    fn test_bytesn_bitwise() {
+        let env = Env::default();
+        let a = BytesN::from_array(&env, &[0b1100, 0xff, 0x00, 0x5a]);
+        let b = BytesN::from_array(&env, &[0b1010, 0x0f, 0x00, 0xa5]);
+        assert_eq!(a.xor(&b).to_array(), [0b0110, 0xf0, 0x00, 0xff]);
+        assert_eq!(a.and(&b).to_array(), [0b1000, 0x0f, 0x00, 0x00]);
+        assert_eq!(a.or(&b).to_array(), [0b1110, 0xff, 0x00, 0xff]);
+
+        let zeros = BytesN::from_array(&env, &[0; 4]);
+        let ones = BytesN::from_array(&env, &[0xff; 4]);
+        assert_eq!(a.xor(&a), zeros);
+        assert_eq!(a.xor(&zeros), a);
+        assert_eq!(a.xor(&ones).to_array(), [0xf3, 0x00, 0xff, 0xa5]);
+        assert_eq!(a.and(&zeros), zeros);
+        assert_eq!(a.and(&ones), a);
+        assert_eq!(a.or(&zeros), a);
+        assert_eq!(a.or(&ones), ones);
+    }
+
+    #[test]
+    fn test_bytesn_concat() {
+        let env = Env::default();
+        let mut first = [0u8; 32];
+        let mut second = [0u8; 32];
+        for i in 0..32 {
+            first[i] = i as u8;
+            second[i] = 0xff - i as u8;
+        }
+        let a = BytesN::from_array(&env, &first);
+        let b = BytesN::from_array(&env, &second);
+
+        let ab: BytesN<64> = a.concat(&b);
+        let mut expected = [0u8; 64];
+        expected[..32].copy_from_slice(&first);
+        expected[32..].copy_from_slice(&second);
+        assert_eq!(ab.to_array(), expected);
+
+        let ba: BytesN<64> = b.concat(&a);
+        assert_eq!(ba.to_array()[..32], second);
+        assert_eq!(ba.to_array()[32..], first);
+
+        // The inputs are unchanged.
+        assert_eq!(a.to_array(), first);
+        assert_eq!(b.to_array(), second);
+
+        // Inputs of different lengths, including empty.
+        let c: BytesN<3> =
+            BytesN::from_array(&env, &[1, 2]).concat(&BytesN::from_array(&env, &[3]));
+        assert_eq!(c.to_array(), [1, 2, 3]);
+        let d: BytesN<2> = BytesN::from_array(&env, &[]).concat(&BytesN::from_array(&env, &[4, 5]));
+        assert_eq!(d.to_array(), [4, 5]);
+    }
+
+    #[test]
+    fn test_bytes_reverse() {
+        let env = Env::default();
+        assert_eq!(Bytes::new(&env).reverse(), Bytes::new(&env));
+        assert_eq!(bytes!(&env, 0x01).reverse(), bytes!(&env, 0x01));
+        assert_eq!(bytes!(&env, 0x0102).reverse(), bytes!(&env, 0x0201));
+        assert_eq!(bytes!(&env, 0x010203).reverse(), bytes!(&env, 0x030201));
+        assert_eq!(bytes!(&env, 0x01020304).reverse(), bytes!(&env, 0x04030201));
+
+        // Reversing twice is the identity, and the input is unchanged.
+        let b = bytes!(&env, 0x0a0b0c0d0e);
+        assert_eq!(b.reverse().reverse(), b);
+        assert_eq!(b, bytes!(&env, 0x0a0b0c0d0e));
+
+        // Lengths around the internal chunk size.
+        for len in [63u32, 64, 65, 200] {
+            let mut bytes = Bytes::new(&env);
+            let mut expected = Bytes::new(&env);
+            for i in 0..len {
+                bytes.push_back((i * 37 % 256) as u8);
+                expected.insert(0, (i * 37 % 256) as u8);
+            }
+            assert_eq!(bytes.reverse(), expected);
+        }
+    }
+
+    #[test]
+    fn test_bytesn_reverse() {
+        let env = Env::default();
+        let one = BytesN::from_array(&env, &[7]);
+        assert_eq!(one.reverse(), one);
+        let even = BytesN::from_array(&env, &[1, 2, 3, 4]);
+        assert_eq!(even.reverse().to_array(), [4, 3, 2, 1]);
+        let odd = BytesN::from_array(&env, &[1, 2, 3, 4, 5]);
+        assert_eq!(odd.reverse().to_array(), [5, 4, 3, 2, 1]);
+
+        // Swapping the endianness of a fixed-width integer.
+        let be = BytesN::from_array(&env, &0x1122334455667788u64.to_be_bytes());
+        assert_eq!(be.reverse().to_array(), 0x1122334455667788u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_reader_record() {
+        let env = Env::default();
+        let mut writer = BytesWriter::new(&env);
+        writer.write_u8(7);
+        writer.write_u32_be(0x01020304);
+        writer.write_u64_be(u64::MAX - 1);
+        writer.write_bytes(&bytes![&env, [0xaa, 0xbb]]);
+        writer.write_slice(&[0xcc]);
+        assert_eq!(writer.len(), 16);
+        let record = writer.into_bytes();
+        assert_eq!(
+            record,
+            bytes![
+                &env,
+                [7, 1, 2, 3, 4, 255, 255, 255, 255, 255, 255, 255, 254, 0xaa, 0xbb, 0xcc]
+            ]
+        );
+
+        let mut reader = BytesReader::new(&record);
+        assert_eq!(reader.read_u8(), Ok(7));
+        assert_eq!(reader.position(), 1);
+        assert_eq!(reader.read_u32_be(), Ok(0x01020304));
+        assert_eq!(reader.position(), 5);
+        assert_eq!(reader.read_u64_be(), Ok(u64::MAX - 1));
+        assert_eq!(reader.position(), 13);
+        assert_eq!(reader.remaining(), 3);
+        assert_eq!(reader.read_bytes(2), Ok(bytes![&env, [0xaa, 0xbb]]));
+        assert_eq!(reader.rest(), bytes![&env, [0xcc]]);
+        assert_eq!(reader.read_array::<1>(), Ok([0xcc]));
+        assert!(reader.is_empty());
+        assert_eq!(reader.read_bytes(0), Ok(bytes![&env]));
+    }
+
+    #[test]
+    fn test_reader_past_end() {
+        let env = Env::default();
+        let bin = bytes![&env, [1, 2, 3]];
+        let past_end = Error::from_type_and_code(ScErrorType::Object, ScErrorCode::IndexBounds);
+
+        let mut reader = BytesReader::new(&bin);
+        assert_eq!(reader.read_u32_be(), Err(past_end));
+        // A failed read does not move the position.
+        assert_eq!(reader.position(), 0);
+        assert_eq!(reader.read_u8(), Ok(1));
+        assert_eq!(reader.read_u64_be(), Err(past_end));
+        assert_eq!(reader.read_bytes(3), Err(past_end));
+        assert_eq!(reader.read_bytes(2), Ok(bytes![&env, [2, 3]]));
+        assert_eq!(reader.read_u8(), Err(past_end));
+        assert_eq!(reader.position(), 3);
+    }
+
+    #[test]
+    fn test_iter_u32_be() {
+        let env = Env::default();
+        let bin = bytes![
+            &env,
+            [0, 0, 0, 1, 0xff, 0xff, 0xff, 0xff, 0x12, 0x34, 0x56, 0x78]
+        ];
+
+        let mut iter = bin.iter_u32_be();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(u32::MAX));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(0x12345678));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(Bytes::new(&env).iter_u32_be().next(), None);
+    }
+
+    #[test]
+    fn test_iter_u64_be() {
+        let env = Env::default();
+        let bin = bytes![
+            &env,
+            [0, 0, 0, 0, 0, 0, 1, 0, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]
+        ];
+
+        let mut iter = bin.iter_u64_be();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(256));
+        assert_eq!(iter.next(), Some(0x0123456789abcdef));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(Bytes::new(&env).iter_u64_be().next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes::iter_u32_be with length not a multiple of 4")]
+    fn test_iter_u32_be_not_multiple() {
+        let env = Env::default();
+        let bin = bytes![&env, [0, 0, 0, 1, 2]];
+        let _ = bin.iter_u32_be();
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes::iter_u64_be with length not a multiple of 8")]
+    fn test_iter_u64_be_not_multiple() {
+        let env = Env::default();
+        let bin = bytes![&env, [0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5]];
+        let _ = bin.iter_u64_be();
+    }
+
+    #[test]
+    fn test_crc32() {
+        let env = Env::default();
+
+        assert_eq!(Bytes::new(&env).crc32(), 0);
+        assert_eq!(bytes![&env, [0]].crc32(), 0xD202EF8D);
+        assert_eq!(Bytes::from_slice(&env, b"a").crc32(), 0xE8B7BE43);
+        assert_eq!(Bytes::from_slice(&env, b"123456789").crc32(), 0xCBF43926);
+        assert_eq!(
+            Bytes::from_slice(&env, b"The quick brown fox jumps over the lazy dog").crc32(),
+            0x414FA339
+        );
+    }
+
+    #[test]
+    fn test_crc32_across_chunks() {
+        let env = Env::default();
+
+        // Longer than the buffer the bytes are read through.
+        let bin = Bytes::from_slice(&env, &[0xffu8; 100]);
+        assert_eq!(bin.crc32(), 0x03D28681);
+    }
 }
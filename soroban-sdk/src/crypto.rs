@@ -3,7 +3,7 @@
 use crate::{
     env::internal::{self, BytesObject},
     unwrap::UnwrapInfallible,
-    Bytes, BytesN, ConversionError, Env, IntoVal, TryFromVal, Val,
+    Bytes, BytesN, ConversionError, Env, IntoVal, TryFromVal, Val, Vec,
 };
 
 pub mod bls12_381;
@@ -102,6 +102,14 @@ impl<const N: usize> crate::TryFromValForContractFn<Env, Val> for Hash<N> {
     }
 }
 
+/// The hash function used to combine nodes when verifying a merkle proof with
+/// [`Crypto::verify_merkle_proof`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MerkleHash {
+    Sha256,
+    Keccak256,
+}
+
 /// Crypto provides access to cryptographic functions.
 pub struct Crypto {
     env: Env,
@@ -123,6 +131,35 @@ impl Crypto {
         unsafe { Hash(BytesN::unchecked_new(env.clone(), bin)) }
     }
 
+    /// Returns the SHA-256 hash of the value's XDR serialization.
+    ///
+    /// The value is converted to a [Val] and serialized as an `ScVal` in its
+    /// canonical XDR form, the same bytes returned by
+    /// [`to_xdr`][crate::xdr::ToXdr::to_xdr], and those bytes are hashed with
+    /// SHA-256. Equal values always produce the same hash, which makes this
+    /// suitable for commitments and merkle leaves. Off-chain code can reproduce
+    /// the hash by hashing the XDR encoding of the equivalent `ScVal`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{vec, xdr::ToXdr, Env};
+    ///
+    /// let env = Env::default();
+    /// let value = vec![&env, 1u32, 2, 3];
+    /// assert_eq!(
+    ///     env.crypto().hash_val(&value).to_bytes(),
+    ///     env.crypto().sha256(&value.to_xdr(&env)).to_bytes(),
+    /// );
+    /// ```
+    pub fn hash_val<T: IntoVal<Env, Val>>(&self, value: &T) -> Hash<32> {
+        let env = self.env();
+        let val: Val = value.into_val(env);
+        let bin = internal::Env::serialize_to_bytes(env, val).unwrap_infallible();
+        let data = unsafe { Bytes::unchecked_new(env.clone(), bin) };
+        self.sha256(&data)
+    }
+
     /// Returns the Keccak-256 hash of the data.
     pub fn keccak256(&self, data: &Bytes) -> Hash<32> {
         let env = self.env();
@@ -130,6 +167,61 @@ impl Crypto {
         unsafe { Hash(BytesN::unchecked_new(env.clone(), bin)) }
     }
 
+    /// Returns a [Hasher] that computes the SHA-256 hash of the data passed
+    /// to it in pieces.
+    pub fn sha256_hasher(&self) -> Hasher {
+        Hasher::new(self.env(), HashFunction::Sha256)
+    }
+
+    /// Returns a [Hasher] that computes the Keccak-256 hash of the data
+    /// passed to it in pieces.
+    pub fn keccak256_hasher(&self) -> Hasher {
+        Hasher::new(self.env(), HashFunction::Keccak256)
+    }
+
+    /// Returns true if the proof shows that the leaf is in the merkle tree with
+    /// the given root.
+    ///
+    /// The proof is the list of sibling nodes on the path from the leaf to the
+    /// root, starting with the leaf's sibling. Starting from the leaf, each
+    /// node of the proof is combined with the computed node by hashing the
+    /// concatenation of the two 32-byte values with the lower value first,
+    /// comparing them as big-endian byte strings, i.e.
+    /// `hash(min(a, b) || max(a, b))`. The proof is valid if the final
+    /// computed node equals the root. An empty proof is valid only if the leaf
+    /// is the root.
+    ///
+    /// This is the sorted pair convention used by OpenZeppelin's
+    /// `MerkleProof`, and because pairs are sorted the proof does not need to
+    /// encode whether each sibling is on the left or right. Leaves are used as
+    /// given, so they should already be hashes of the leaf data, ideally with
+    /// a different hash or encoding than inner nodes to prevent a node being
+    /// presented as a leaf.
+    pub fn verify_merkle_proof(
+        &self,
+        root: &BytesN<32>,
+        leaf: &BytesN<32>,
+        proof: &Vec<BytesN<32>>,
+        hash: MerkleHash,
+    ) -> bool {
+        let mut computed = leaf.clone();
+        for node in proof.iter() {
+            let (a, b) = if computed <= node {
+                (computed, node)
+            } else {
+                (node, computed)
+            };
+            let mut data = Bytes::from(a);
+            data.append(&Bytes::from(b));
+            computed = match hash {
+                MerkleHash::Sha256 => self.sha256(&data),
+                MerkleHash::Keccak256 => self.keccak256(&data),
+            }
+            .into();
+        }
+        &computed == root
+    }
+
     /// Verifies an ed25519 signature.
     ///
     /// The signature is verified as a valid signature of the message by the
@@ -148,6 +240,39 @@ impl Crypto {
         );
     }
 
+    /// Verifies ed25519 signatures, each of the message at the same position
+    /// by the public key at the same position.
+    ///
+    /// This is not a batched host operation. It calls
+    /// [`ed25519_verify`][Crypto::ed25519_verify] for each signature in turn,
+    /// so it costs the same as verifying them one by one, and like
+    /// `ed25519_verify` it does not return a result. The first invalid
+    /// signature panics, failing the invocation, and the remaining signatures
+    /// are not checked. An empty batch verifies successfully.
+    ///
+    /// ### Panics
+    ///
+    /// If the public keys, messages, and signatures are not all of the same
+    /// length, or if any signature is invalid.
+    pub fn ed25519_verify_batch(
+        &self,
+        public_keys: &Vec<BytesN<32>>,
+        messages: &Vec<Bytes>,
+        signatures: &Vec<BytesN<64>>,
+    ) {
+        let len = public_keys.len();
+        if messages.len() != len || signatures.len() != len {
+            sdk_panic!("Crypto::ed25519_verify_batch with mismatched lengths");
+        }
+        for i in 0..len {
+            self.ed25519_verify(
+                &public_keys.get_unchecked(i),
+                &messages.get_unchecked(i),
+                &signatures.get_unchecked(i),
+            );
+        }
+    }
+
     /// Recovers the ECDSA secp256k1 public key.
     ///
     /// The public key returned is the SEC-1-encoded ECDSA secp256k1 public key
@@ -184,6 +309,70 @@ impl Crypto {
     }
 }
 
+#[derive(Clone, Copy)]
+enum HashFunction {
+    Sha256,
+    Keccak256,
+}
+
+/// Hasher computes a hash of data passed to it in pieces, as if the pieces
+/// had been concatenated.
+///
+/// Create one with [`Crypto::sha256_hasher`] or
+/// [`Crypto::keccak256_hasher`].
+///
+/// The host only hashes whole inputs, so the pieces are appended to a buffer
+/// held by the host, and hashed when the hasher is finalized. This saves the
+/// contract from building the concatenation itself, but not the host from
+/// storing it.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{bytes, Env};
+///
+/// let env = Env::default();
+/// let mut hasher = env.crypto().sha256_hasher();
+/// hasher.update(&bytes!(&env, 0x0102));
+/// hasher.update(&bytes!(&env, 0x03));
+/// assert_eq!(
+///     hasher.finalize().to_bytes(),
+///     env.crypto().sha256(&bytes!(&env, 0x010203)).to_bytes(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Hasher {
+    function: HashFunction,
+    data: Bytes,
+}
+
+impl Hasher {
+    fn new(env: &Env, function: HashFunction) -> Hasher {
+        Hasher {
+            function,
+            data: Bytes::new(env),
+        }
+    }
+
+    pub fn env(&self) -> &Env {
+        self.data.env()
+    }
+
+    /// Adds the data to the input being hashed.
+    pub fn update(&mut self, data: &Bytes) {
+        self.data.append(data);
+    }
+
+    /// Returns the hash of all the data passed to [`update`][Hasher::update].
+    pub fn finalize(self) -> Hash<32> {
+        let crypto = Crypto::new(self.env());
+        match self.function {
+            HashFunction::Sha256 => crypto.sha256(&self.data),
+            HashFunction::Keccak256 => crypto.keccak256(&self.data),
+        }
+    }
+}
+
 /// # ⚠️ Hazardous Materials
 ///
 /// Cryptographic functions under [CryptoHazmat] are low-leveled which can be
@@ -124,10 +124,22 @@
 //! ```
 
 use crate::{
-    env::internal::Env as _, unwrap::UnwrapInfallible, Address, Bytes, BytesN, ConstructorArgs,
-    Env, IntoVal,
+    contracttype,
+    env::internal::Env as _,
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+    Address, Bytes, BytesN, ConstructorArgs, Env, IntoVal, TryFromVal,
 };
 
+/// The executable of an address, in the form the host returns it from
+/// `get_address_executable`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype(crate_path = "crate", export = false)]
+enum AddressExecutable {
+    Wasm(BytesN<32>),
+    StellarAsset,
+    Account,
+}
+
 /// Deployer provides access to deploying contracts.
 pub struct Deployer {
     env: Env,
@@ -171,6 +183,42 @@ impl Deployer {
         }
     }
 
+    /// Derive the contract address that a deployment by `base` with `salt`
+    /// would receive, without deploying anything.
+    ///
+    /// The contract ID is the SHA-256 hash of the XDR encoded
+    /// `HashIdPreimage::ContractId`, which holds the network ID and the
+    /// `ContractIdPreimage::FromAddress` made of `base` and `salt`. The
+    /// derived address is therefore stable for the same inputs on the same
+    /// network, different for every salt, and independent of the Wasm that
+    /// is eventually deployed to it.
+    ///
+    /// Equivalent to `self.with_address(base, salt).deployed_address()`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let base = Address::generate(&env);
+    ///     let salt = BytesN::from_array(&env, &[1; 32]);
+    ///
+    ///     let derived = env.deployer().derive_address(&base, &salt);
+    ///     assert_eq!(derived, env.deployer().derive_address(&base, &salt));
+    /// }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn derive_address(&self, base: &Address, salt: &BytesN<32>) -> Address {
+        self.with_address(base.clone(), salt.clone())
+            .deployed_address()
+    }
+
     /// Get a deployer that deploys an instance of Stellar Asset Contract
     /// corresponding to the provided serialized asset.
     ///
@@ -224,6 +272,41 @@ impl Deployer {
             .unwrap_infallible();
     }
 
+    /// Replaces the executable of the current contract with the provided
+    /// Wasm, optionally refusing an upgrade to the Wasm the contract already
+    /// runs.
+    ///
+    /// When `require_different` is true the `wasm_hash` is compared with the
+    /// hash of the current contract's executable, and an upgrade that would
+    /// leave the executable unchanged is rejected. When false this behaves
+    /// exactly like
+    /// [`update_current_contract_wasm`][Deployer::update_current_contract_wasm].
+    ///
+    /// ### Panics
+    ///
+    /// If `require_different` is true and `wasm_hash` is the hash of the
+    /// current contract's executable.
+    pub fn update_current_contract_wasm_checked(
+        &self,
+        wasm_hash: impl IntoVal<Env, BytesN<32>>,
+        require_different: bool,
+    ) {
+        let wasm_hash = wasm_hash.into_val(&self.env);
+        if require_different {
+            let address = self.env.current_contract_address();
+            let executable = self
+                .env
+                .get_address_executable(address.to_object())
+                .unwrap_infallible();
+            let executable = Option::<AddressExecutable>::try_from_val(&self.env, &executable)
+                .unwrap_optimized();
+            if executable == Some(AddressExecutable::Wasm(wasm_hash.clone())) {
+                sdk_panic!("contract executable is already the wasm");
+            }
+        }
+        self.update_current_contract_wasm(wasm_hash);
+    }
+
     /// Extend the TTL of the contract instance and code.
     ///
     /// Extends the TTL of the instance and code only if the TTL for the provided contract is below `threshold` ledgers.
@@ -373,7 +456,10 @@ impl DeployerWithAsset {
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
 mod testutils {
     use crate::deploy::Deployer;
-    use crate::Address;
+    use crate::{xdr, Address, BytesN};
+
+    extern crate std;
+    use std::rc::Rc;
 
     impl crate::testutils::Deployer for Deployer {
         fn get_contract_instance_ttl(&self, contract: &Address) -> u32 {
@@ -393,5 +479,35 @@ mod testutils {
                 .checked_sub(self.env.ledger().sequence())
                 .unwrap()
         }
+
+        fn get_contract_executable(&self, contract: &Address) -> Option<BytesN<32>> {
+            let key = Rc::new(xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+                contract: contract.into(),
+                key: xdr::ScVal::LedgerKeyContractInstance,
+                durability: xdr::ContractDataDurability::Persistent,
+            }));
+            let entry = self
+                .env
+                .host()
+                .with_mut_storage(|s| {
+                    let budget = soroban_env_host::budget::AsBudget::as_budget(self.env.host());
+                    Ok(s.map.get(&key, budget)?.cloned())
+                })
+                .unwrap();
+            let Some(Some((entry, _))) = entry else {
+                return None;
+            };
+            match &entry.data {
+                xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+                    val:
+                        xdr::ScVal::ContractInstance(xdr::ScContractInstance {
+                            executable: xdr::ContractExecutable::Wasm(hash),
+                            ..
+                        }),
+                    ..
+                }) => Some(BytesN::from_array(&self.env, &hash.0)),
+                _ => None,
+            }
+        }
     }
 }
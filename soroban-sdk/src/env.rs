@@ -112,14 +112,53 @@ where
     }
 }
 
+/// Converts a [Val] to another type, reporting the type of the value if it
+/// can't be converted.
+pub trait TryAs {
+    /// Converts the value to the type `T`, returning an error with the type
+    /// of the value if it is not of the type `T`.
+    ///
+    /// Useful in code that handles values of any type, where the bare
+    /// [ConversionError] returned by [`TryFromVal`] doesn't say what went
+    /// wrong. The error converts into a [ConversionError] for callers that need
+    /// one.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{xdr::ScValType, Env, IntoVal, TryAs, Val};
+    ///
+    /// let env = Env::default();
+    /// let val: Val = 5u32.into_val(&env);
+    /// assert_eq!(val.try_as::<u32>(&env), Ok(5));
+    ///
+    /// let err = val.try_as::<i64>(&env).unwrap_err();
+    /// assert_eq!(err.actual, ScValType::U32);
+    /// ```
+    fn try_as<T>(&self, env: &Env) -> Result<T, TypeMismatchError>
+    where
+        T: TryFromVal<Env, Val>;
+}
+
+impl TryAs for Val {
+    fn try_as<T>(&self, env: &Env) -> Result<T, TypeMismatchError>
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        T::try_from_val(env, self).map_err(|_| {
+            TypeMismatchError::new::<T>(self.get_tag().get_scval_type().unwrap_optimized())
+        })
+    }
+}
+
 use crate::auth::InvokerContractAuthEntry;
 use crate::unwrap::UnwrapInfallible;
 use crate::unwrap::UnwrapOptimized;
-use crate::InvokeError;
 use crate::{
     crypto::Crypto, deploy::Deployer, events::Events, ledger::Ledger, logs::Logs, prng::Prng,
     storage::Storage, Address, Vec,
 };
+use crate::{iter::IterationBound, InvokeError, TypeMismatchError};
 use internal::{
     AddressObject, Bool, BytesObject, DurationObject, I128Object, I256Object, I256Val, I64Object,
     StorageType, StringObject, Symbol, SymbolObject, TimepointObject, U128Object, U256Object,
@@ -246,6 +285,11 @@ struct EnvTestState {
     generators: Rc<RefCell<Generators>>,
     auth_snapshot: Rc<RefCell<AuthSnapshot>>,
     snapshot: Option<Rc<LedgerSnapshot>>,
+    fault_injection: Rc<FaultInjection>,
+    host_trace: Rc<RefCell<HostTraceState>>,
+    registered_contracts: Rc<RefCell<std::vec::Vec<xdr::ScAddress>>>,
+    named_contracts: Rc<RefCell<std::collections::BTreeMap<std::string::String, xdr::ScAddress>>>,
+    last_footprint: Rc<RefCell<Option<xdr::LedgerFootprint>>>,
 }
 
 /// Config for changing the default behavior of the Env when used in tests.
@@ -367,6 +411,13 @@ impl Env {
     ///
     /// Will panic if the value returned from the contract cannot be converted
     /// into the type `T`.
+    ///
+    /// # Re-entry
+    ///
+    /// A contract cannot be invoked while it is already executing, whether it
+    /// calls itself or is called back by a contract it called. The host fails
+    /// such invocations with `Error(Context, InvalidAction)`, so contracts
+    /// don't need to guard against re-entry themselves.
     pub fn invoke_contract<T>(
         &self,
         contract_address: &Address,
@@ -414,6 +465,36 @@ impl Env {
         }
     }
 
+    /// Invokes a function of a contract that is registered in the [Env],
+    /// returning an error with the type of the returned value if it is not of
+    /// the type `T`.
+    ///
+    /// This is useful for catching a callee whose interface has changed, such
+    /// as a function that used to return a `u64` now returning an `i128`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic in the same cases as [`Env::invoke_contract`], other than
+    /// the returned value failing to convert into the type `T`.
+    pub fn invoke_contract_strict<T>(
+        &self,
+        contract_address: &Address,
+        func: &crate::Symbol,
+        args: Vec<Val>,
+    ) -> Result<T, TypeMismatchError>
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        let rv = internal::Env::call(
+            self,
+            contract_address.to_object(),
+            func.to_symbol_val(),
+            args.to_object(),
+        )
+        .unwrap_infallible();
+        rv.try_as(self)
+    }
+
     /// Authorizes sub-contract calls on behalf of the current contract.
     ///
     /// All the direct calls that the current contract performs are always
@@ -434,6 +515,71 @@ impl Env {
             .unwrap_infallible();
     }
 
+    /// Ensures that the amount is not negative.
+    ///
+    /// Use at the start of functions that take token amounts, so that every
+    /// contract rejects negative amounts in the same way.
+    ///
+    /// ### Panics
+    ///
+    /// If the amount is less than zero.
+    #[inline(always)]
+    pub fn require_nonnegative(&self, amount: i128) {
+        if amount < 0 {
+            sdk_panic!("amount must not be negative");
+        }
+    }
+
+    /// Ensures that none of the amounts are negative.
+    ///
+    /// ### Panics
+    ///
+    /// If any of the amounts is less than zero.
+    pub fn require_all_nonnegative(&self, amounts: &Vec<i128>) {
+        for amount in amounts.iter() {
+            self.require_nonnegative(amount);
+        }
+    }
+
+    /// Returns the size in bytes of the value's XDR serialization, the same
+    /// bytes returned by [`to_xdr`][crate::xdr::ToXdr::to_xdr].
+    ///
+    /// Useful for rejecting oversized data provided by a caller before
+    /// storing it. An entry written to storage also holds its key and some
+    /// overhead, so the size of the value alone is less than the size that
+    /// the write is charged for.
+    pub fn serialized_size<T: IntoVal<Env, Val>>(&self, value: &T) -> u32 {
+        let val: Val = value.into_val(self);
+        let bin = internal::Env::serialize_to_bytes(self, val).unwrap_infallible();
+        internal::Env::bytes_len(self, bin)
+            .unwrap_infallible()
+            .into()
+    }
+
+    /// Returns a bound of at most `max` items, for capping iteration with
+    /// [`bounded`][crate::iter::BoundedEnumerable::bounded].
+    ///
+    /// Equivalent to [`IterationBound::new`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{iter::BoundedEnumerable, vec, Env};
+    ///
+    /// let env = Env::default();
+    /// let amounts = vec![&env, 1i128, 2, 3];
+    ///
+    /// let bound = env.bounded(10);
+    /// let mut total = 0;
+    /// for amount in amounts.iter().bounded(&bound) {
+    ///     total += amount;
+    /// }
+    /// assert_eq!(total, 6);
+    /// ```
+    pub fn bounded(&self, max: u32) -> IterationBound {
+        IterationBound::new(max)
+    }
+
     /// Get the [Logs] for logging debug events.
     #[inline(always)]
     #[deprecated(note = "use [Env::logs]")]
@@ -452,12 +598,16 @@ impl Env {
 #[cfg(any(test, feature = "testutils"))]
 use crate::testutils::cost_estimate::CostEstimate;
 #[cfg(any(test, feature = "testutils"))]
+use crate::testutils::fault_injection::{FaultInjection, FaultInjectionState, HostFaultInjection};
+#[cfg(any(test, feature = "testutils"))]
+use crate::testutils::host_trace::{HostCall, HostTraceState};
+#[cfg(any(test, feature = "testutils"))]
 use crate::{
     auth,
     testutils::{
         budget::Budget, Address as _, AuthSnapshot, AuthorizedInvocation, ContractFunctionSet,
-        EventsSnapshot, Generators, Ledger as _, MockAuth, MockAuthContract, Register, Snapshot,
-        StellarAssetContract, StellarAssetIssuer,
+        EventsSnapshot, Generators, Ledger as _, MockAuth, MockAuthContract, MockAuthEntry,
+        Register, Snapshot, StellarAssetContract, StellarAssetIssuer,
     },
     Bytes, BytesN, ConstructorArgs,
 };
@@ -490,6 +640,61 @@ impl Env {
         f((*self.test_state.generators).borrow_mut())
     }
 
+    #[doc(hidden)]
+    pub(crate) fn with_fault_injection<T>(
+        &self,
+        f: impl FnOnce(&mut FaultInjectionState) -> T,
+    ) -> T {
+        self.test_state.fault_injection.with_state(f)
+    }
+
+    /// Returns the entries currently in the host's storage footprint, and how
+    /// they have been accessed.
+    fn footprint_snapshot(
+        &self,
+    ) -> std::collections::BTreeMap<Rc<xdr::LedgerKey>, internal::storage::AccessType> {
+        self.host()
+            .with_mut_storage(|s| Ok(s.footprint.0.clone()))
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    /// Records the storage footprint of the top level invocation that has just
+    /// finished, as the entries that were added to the host's footprint, or
+    /// that became read-write, since `before` was taken.
+    fn record_footprint(
+        &self,
+        before: std::collections::BTreeMap<Rc<xdr::LedgerKey>, internal::storage::AccessType>,
+    ) {
+        use internal::storage::AccessType;
+        let mut read_only = std::vec::Vec::new();
+        let mut read_write = std::vec::Vec::new();
+        for (k, access) in self.footprint_snapshot() {
+            match (before.get(&k), access) {
+                (Some(AccessType::ReadWrite), _)
+                | (Some(AccessType::ReadOnly), AccessType::ReadOnly) => (),
+                (_, AccessType::ReadOnly) => read_only.push((*k).clone()),
+                (_, AccessType::ReadWrite) => read_write.push((*k).clone()),
+            }
+        }
+        *(*self.test_state.last_footprint).borrow_mut() = Some(xdr::LedgerFootprint {
+            read_only: read_only.try_into().unwrap(),
+            read_write: read_write.try_into().unwrap(),
+        });
+    }
+
+    /// Returns the storage footprint of the last top level invocation, if there
+    /// has been one.
+    pub(crate) fn last_footprint(&self) -> Option<xdr::LedgerFootprint> {
+        (*self.test_state.last_footprint).borrow().clone()
+    }
+
+    #[doc(hidden)]
+    pub(crate) fn with_host_trace<T>(&self, f: impl FnOnce(RefMut<'_, HostTraceState>) -> T) -> T {
+        f((*self.test_state.host_trace).borrow_mut())
+    }
+
     /// Create an Env with the test config.
     pub fn new_with_config(config: EnvTestConfig) -> Env {
         struct EmptySnapshotSource();
@@ -571,6 +776,11 @@ impl Env {
                 generators: generators.unwrap_or_default(),
                 snapshot,
                 auth_snapshot,
+                fault_injection: Default::default(),
+                host_trace: Default::default(),
+                registered_contracts: Default::default(),
+                named_contracts: Default::default(),
+                last_footprint: Default::default(),
             },
         };
 
@@ -604,6 +814,230 @@ impl Env {
         CostEstimate::new(self.clone())
     }
 
+    /// Get a [HostFaultInjection] for making host function calls fail.
+    ///
+    /// Injected failures apply to calls made by this [Env] and by the native
+    /// contracts registered with it. Host functions called by Wasm contracts
+    /// cannot be failed. See [HostFaultInjection] for details.
+    pub fn host_fault_injection(&self) -> HostFaultInjection {
+        HostFaultInjection::new(self.clone())
+    }
+
+    /// Returns the host function calls made during the last top level contract
+    /// invocation, in the order they were made.
+    ///
+    /// The trace is reset when the next top level invocation starts, and
+    /// includes the calls made by every contract in the invocation, with
+    /// cross-contract calls recorded as `call` or `try_call`. Calls made by
+    /// the test outside of an invocation, such as those in
+    /// [`Env::as_contract`], are not recorded.
+    ///
+    /// Like [`HostFaultInjection`], only host functions called by the SDK are
+    /// recorded, so the calls made by Wasm contracts are missing.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn store(env: Env) {
+    ///         env.storage().persistent().set(&1, &2);
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///     ContractClient::new(&env, &contract_id).store();
+    ///
+    ///     assert!(env
+    ///         .host_trace()
+    ///         .iter()
+    ///         .any(|c| c.fn_name == "put_contract_data"));
+    /// }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn host_trace(&self) -> std::vec::Vec<HostCall> {
+        self.with_host_trace(|t| t.calls())
+    }
+
+    /// Invokes a function of a contract that is registered in the [Env] as a
+    /// read-only view, panicking if the invocation mutates state.
+    ///
+    /// While the invocation is in progress, writing or removing storage and
+    /// publishing events fails, including in any contracts it calls. Extending
+    /// TTLs is allowed. If the invocation attempted a mutation, this panics
+    /// naming the operation, even if the contract recovered from the failure.
+    ///
+    /// Like [`HostFaultInjection`], only host functions called by the SDK are
+    /// checked, so mutations made by Wasm contracts go undetected.
+    ///
+    /// ### Panics
+    ///
+    /// If the invocation mutates state, fails for any other reason, or returns
+    /// a value that cannot be converted into the type `T`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, symbol_short, vec, Env};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn get(env: Env) -> u32 {
+    ///         env.storage().persistent().get(&1).unwrap_or(0)
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///
+    ///     let v: u32 = env.invoke_view(&contract_id, &symbol_short!("get"), vec![&env]);
+    ///     assert_eq!(v, 0);
+    /// }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn invoke_view<T>(
+        &self,
+        contract_address: &Address,
+        func: &crate::Symbol,
+        args: Vec<Val>,
+    ) -> T
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        self.with_fault_injection(|f| f.begin_view());
+        let rv = internal::Env::try_call(
+            self,
+            contract_address.to_object(),
+            func.to_symbol_val(),
+            args.to_object(),
+        )
+        .unwrap_infallible();
+        if let Some(op) = self.with_fault_injection(|f| f.end_view()) {
+            panic!(
+                "view call to {:?} attempted to mutate state: {:?}",
+                func, op
+            );
+        }
+        if let Ok(err) = internal::Error::try_from_val(self, &rv) {
+            panic!("view call to {:?} failed: {:?}", func, err);
+        }
+        T::try_from_val(self, &rv)
+            .map_err(|_| ConversionError)
+            .unwrap()
+    }
+
+    /// Runs the function twice from the same starting state and asserts that
+    /// both runs return equal values and leave the ledger in an equal state.
+    ///
+    /// Before the second run, the ledger entries and the test generators are
+    /// restored to what they were before the first run, so addresses
+    /// generated inside the function are the same in both runs. The PRNG is
+    /// not restored, so a function that relies on unseeded randomness from
+    /// [`Env::prng`] sees different values in each run and fails the
+    /// assertion. Contract registrations and auth mocks made before calling
+    /// this function are kept for both runs.
+    ///
+    /// Published events are not restored between the runs. Each contract
+    /// invocation starts with an empty event buffer, so a function that
+    /// invokes a contract and returns [`Events::all`][crate::testutils::Events::all]
+    /// compares the events published by each run.
+    ///
+    /// The state after the second run is left in place, and the value it
+    /// returned is returned.
+    ///
+    /// ### Panics
+    ///
+    /// If the runs return different values or leave the ledger in different
+    /// states.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn incr(env: Env) -> u32 {
+    ///         let count = env.storage().persistent().get(&1).unwrap_or(0) + 1;
+    ///         env.storage().persistent().set(&1, &count);
+    ///         count
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///
+    ///     let count = env.assert_deterministic(|_| client.incr());
+    ///     assert_eq!(count, 1);
+    /// }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn assert_deterministic<R>(&self, f: impl Fn(&Env) -> R) -> R
+    where
+        R: PartialEq + core::fmt::Debug,
+    {
+        let generators = (*self.test_state.generators).borrow().clone();
+        let (map, footprint) = self
+            .env_impl
+            .with_mut_storage(|s| Ok((s.map.clone(), s.footprint.0.clone())))
+            .unwrap();
+
+        let first = f(self);
+        let first_state = self.to_ledger_snapshot();
+
+        *(*self.test_state.generators).borrow_mut() = generators;
+        self.env_impl
+            .with_mut_storage(|s| {
+                s.map = map;
+                s.footprint.0 = footprint;
+                Ok(())
+            })
+            .unwrap();
+
+        let second = f(self);
+        if first != second {
+            panic!(
+                "function is not deterministic: first run returned {:?}, second run returned {:?}",
+                first, second
+            );
+        }
+        if first_state != self.to_ledger_snapshot() {
+            panic!("function is not deterministic: runs left the ledger in different states");
+        }
+        second
+    }
+
     /// Register a contract with the [Env] for testing.
     ///
     /// Pass the contract type when the contract is defined in the current crate
@@ -663,7 +1097,9 @@ impl Env {
         C: Register,
         A: ConstructorArgs,
     {
-        contract.register(self, None, constructor_args)
+        let contract_id = contract.register(self, None, constructor_args);
+        self.record_registered_contract(&contract_id);
+        contract_id
     }
 
     /// Register a contract with the [Env] for testing.
@@ -734,7 +1170,99 @@ impl Env {
         C: Register,
         A: ConstructorArgs,
     {
-        contract.register(self, contract_id, constructor_args)
+        let contract_id = contract.register(self, contract_id, constructor_args);
+        self.record_registered_contract(&contract_id);
+        contract_id
+    }
+
+    /// Returns the addresses of all contracts registered with the [Env], in
+    /// the order they were first registered.
+    ///
+    /// Only contracts registered via this [Env]'s `register*` functions are
+    /// included. Contracts deployed by other contracts, contracts loaded from a
+    /// snapshot, and contracts registered with a different [Env] are not.
+    /// Registering a contract again at the same address does not add it
+    /// twice.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, Env};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let a = env.register(Contract, ());
+    ///     let b = env.register(Contract, ());
+    ///     assert_eq!(env.registered_contracts(), soroban_sdk::vec![&env, a, b]);
+    /// }
+    /// ```
+    pub fn registered_contracts(&self) -> Vec<Address> {
+        let mut contracts = Vec::new(self);
+        for id in (*self.test_state.registered_contracts).borrow().iter() {
+            contracts.push_back(Address::try_from_val(self, id).unwrap());
+        }
+        contracts
+    }
+
+    /// Register a contract with the [Env] for testing, under a name that it
+    /// can be looked up by with [`contract_by_name`][Self::contract_by_name].
+    ///
+    /// Behaves like [`register`][Self::register] otherwise. Registering
+    /// another contract under the same name replaces the name's contract.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, Env};
+    ///
+    /// #[contract]
+    /// pub struct Router;
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let router = env.register_named("router", Router, ());
+    ///     assert_eq!(env.contract_by_name("router"), router);
+    /// }
+    /// ```
+    pub fn register_named<C, A>(&self, name: &str, contract: C, constructor_args: A) -> Address
+    where
+        C: Register,
+        A: ConstructorArgs,
+    {
+        let contract_id = self.register(contract, constructor_args);
+        (*self.test_state.named_contracts)
+            .borrow_mut()
+            .insert(name.to_owned(), xdr::ScAddress::from(&contract_id));
+        contract_id
+    }
+
+    /// Returns the address of the contract registered under the name with
+    /// [`register_named`][Self::register_named].
+    ///
+    /// ### Panics
+    ///
+    /// If no contract has been registered under the name.
+    pub fn contract_by_name(&self, name: &str) -> Address {
+        let named = (*self.test_state.named_contracts).borrow();
+        let Some(id) = named.get(name) else {
+            panic!("no contract registered with the name {name:?}");
+        };
+        Address::try_from_val(self, id).unwrap()
+    }
+
+    fn record_registered_contract(&self, contract_id: &Address) {
+        let id = xdr::ScAddress::from(contract_id);
+        let mut registered = (*self.test_state.registered_contracts).borrow_mut();
+        if !registered.contains(&id) {
+            registered.push(id);
+        }
     }
 
     /// Register a contract with the [Env] for testing.
@@ -783,7 +1311,9 @@ impl Env {
         contract_id: impl Into<Option<&'a Address>>,
         contract: T,
     ) -> Address {
-        self.register_contract_with_constructor(contract_id, contract, ())
+        let contract_id = self.register_contract_with_constructor(contract_id, contract, ());
+        self.record_registered_contract(&contract_id);
+        contract_id
     }
 
     /// Register a contract with the [Env] for testing.
@@ -813,7 +1343,11 @@ impl Env {
         contract: T,
         constructor_args: A,
     ) -> Address {
-        struct InternalContractFunctionSet<T: ContractFunctionSet>(pub(crate) T);
+        struct InternalContractFunctionSet<T: ContractFunctionSet>(
+            pub(crate) T,
+            Rc<FaultInjection>,
+            Rc<RefCell<HostTraceState>>,
+        );
         impl<T: ContractFunctionSet> internal::ContractFunctionSet for InternalContractFunctionSet<T> {
             fn call(
                 &self,
@@ -823,7 +1357,11 @@ impl Env {
             ) -> Option<Val> {
                 let env = Env {
                     env_impl: env_impl.clone(),
-                    test_state: Default::default(),
+                    test_state: EnvTestState {
+                        fault_injection: self.1.clone(),
+                        host_trace: self.2.clone(),
+                        ..Default::default()
+                    },
                 };
                 self.0.call(
                     crate::Symbol::try_from_val(&env, func)
@@ -844,7 +1382,11 @@ impl Env {
         self.env_impl
             .register_test_contract_with_constructor(
                 contract_id.to_object(),
-                Rc::new(InternalContractFunctionSet(contract)),
+                Rc::new(InternalContractFunctionSet(
+                    contract,
+                    self.test_state.fault_injection.clone(),
+                    self.test_state.host_trace.clone(),
+                )),
                 constructor_args.into_val(self).to_object(),
             )
             .unwrap();
@@ -887,11 +1429,13 @@ impl Env {
         contract_wasm: impl IntoVal<Env, Bytes>,
     ) -> Address {
         let wasm_hash: BytesN<32> = self.deployer().upload_contract_wasm(contract_wasm);
-        self.register_contract_with_optional_contract_id_and_executable(
+        let contract_id = self.register_contract_with_optional_contract_id_and_executable(
             contract_id,
             xdr::ContractExecutable::Wasm(xdr::Hash(wasm_hash.into())),
             crate::vec![&self],
-        )
+        );
+        self.record_registered_contract(&contract_id);
+        contract_id
     }
 
     /// Register a contract in a Wasm file with the [Env] for testing.
@@ -981,7 +1525,7 @@ impl Env {
             issuer: issuer_id.clone(),
         });
         let create = xdr::HostFunction::CreateContract(xdr::CreateContractArgs {
-            contract_id_preimage: xdr::ContractIdPreimage::Asset(asset),
+            contract_id_preimage: xdr::ContractIdPreimage::Asset(asset.clone()),
             executable: xdr::ContractExecutable::StellarAsset,
         });
 
@@ -1003,9 +1547,11 @@ impl Env {
         );
         self.env_impl.set_auth_manager(prev_auth_manager).unwrap();
 
+        self.record_registered_contract(&token_id);
+
         let issuer = StellarAssetIssuer::new(self.clone(), issuer_id);
 
-        StellarAssetContract::new(token_id, issuer)
+        StellarAssetContract::new(token_id, issuer, asset)
     }
 
     /// Register the built-in Stellar Asset Contract with provided admin address.
@@ -1020,6 +1566,89 @@ impl Env {
         self.register_stellar_asset_contract_v2(admin).address()
     }
 
+    /// Sets the native XLM balance, in stroops, of the account, creating the
+    /// account if it does not exist.
+    ///
+    /// The balance is the one held in the account's ledger entry, that the
+    /// Stellar Asset Contract of the native asset transfers from and to.
+    ///
+    /// ### Panics
+    ///
+    /// If the address is not an account address, or if the balance is
+    /// negative or greater than [`i64::MAX`].
+    pub fn set_account_balance(&self, account: &Address, balance: i128) {
+        let account_id = Self::account_id(account);
+        let balance = match i64::try_from(balance) {
+            Ok(balance) if balance >= 0 => balance,
+            _ => panic!("account balance {balance} is out of range"),
+        };
+        self.host()
+            .with_mut_storage(|storage| {
+                let budget = soroban_env_host::budget::AsBudget::as_budget(self.host());
+                let k = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+                    account_id: account_id.clone(),
+                }));
+                let mut entry = if storage.has(&k, budget)? {
+                    storage.get(&k, budget)?.as_ref().clone()
+                } else {
+                    xdr::LedgerEntry {
+                        data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
+                            account_id: account_id.clone(),
+                            balance: 0,
+                            flags: 0,
+                            home_domain: Default::default(),
+                            inflation_dest: None,
+                            num_sub_entries: 0,
+                            seq_num: xdr::SequenceNumber(0),
+                            thresholds: xdr::Thresholds([1; 4]),
+                            signers: xdr::VecM::default(),
+                            ext: xdr::AccountEntryExt::V0,
+                        }),
+                        last_modified_ledger_seq: 0,
+                        ext: xdr::LedgerEntryExt::V0,
+                    }
+                };
+                match entry.data {
+                    xdr::LedgerEntryData::Account(ref mut e) => e.balance = balance,
+                    _ => panic!("expected account entry but got {:?}", entry.data),
+                }
+                storage.put(&k, &Rc::new(entry), None, budget)
+            })
+            .unwrap();
+    }
+
+    /// Returns the native XLM balance, in stroops, of the account.
+    ///
+    /// ### Panics
+    ///
+    /// If the address is not an account address, or if the account does not
+    /// exist.
+    pub fn account_balance(&self, account: &Address) -> i128 {
+        let account_id = Self::account_id(account);
+        self.host()
+            .with_mut_storage(|storage| {
+                let budget = soroban_env_host::budget::AsBudget::as_budget(self.host());
+                let k = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+                    account_id: account_id.clone(),
+                }));
+                if !storage.has(&k, budget)? {
+                    panic!("account {account:?} does not exist");
+                }
+                match storage.get(&k, budget)?.data {
+                    xdr::LedgerEntryData::Account(ref e) => Ok(e.balance.into()),
+                    ref data => panic!("expected account entry but got {data:?}"),
+                }
+            })
+            .unwrap()
+    }
+
+    fn account_id(account: &Address) -> xdr::AccountId {
+        match xdr::ScAddress::from(account) {
+            xdr::ScAddress::Account(account_id) => account_id,
+            _ => panic!("address {account:?} is not an account address"),
+        }
+    }
+
     fn register_contract_with_optional_contract_id_and_executable<'a>(
         &self,
         contract_id: impl Into<Option<&'a Address>>,
@@ -1141,7 +1770,7 @@ impl Env {
     /// ```
     pub fn mock_auths(&self, auths: &[MockAuth]) {
         for a in auths {
-            self.register_at(a.address, MockAuthContract, ());
+            MockAuthContract.register(self, a.address, ());
         }
         let auths = auths
             .iter()
@@ -1151,6 +1780,59 @@ impl Env {
         self.env_impl.set_authorization_entries(auths).unwrap();
     }
 
+    /// Mock authorizations like [`mock_auths`][Self::mock_auths], with the
+    /// nonce and signature expiration ledger of each authorization entry
+    /// controlled by the [`MockAuthEntry`].
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env, Address, testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal};
+    ///
+    /// #[contract]
+    /// pub struct HelloContract;
+    ///
+    /// #[contractimpl]
+    /// impl HelloContract {
+    ///     pub fn hello(env: Env, from: Address) {
+    ///         from.require_auth();
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(HelloContract, ());
+    ///
+    ///     let client = HelloContractClient::new(&env, &contract_id);
+    ///     let addr = Address::generate(&env);
+    ///     let auth = MockAuth {
+    ///         address: &addr,
+    ///         invoke: &MockAuthInvoke {
+    ///             contract: &contract_id,
+    ///             fn_name: "hello",
+    ///             args: (&addr,).into_val(&env),
+    ///             sub_invokes: &[],
+    ///         },
+    ///     };
+    ///
+    ///     env.mock_auth_entries(&[auth.with_nonce(1)]);
+    ///     client.hello(&addr);
+    ///
+    ///     // The nonce has been consumed.
+    ///     env.mock_auth_entries(&[auth.with_nonce(1)]);
+    ///     assert!(client.try_hello(&addr).is_err());
+    /// }
+    /// ```
+    pub fn mock_auth_entries(&self, auths: &[MockAuthEntry]) {
+        for a in auths {
+            MockAuthContract.register(self, a.address(), ());
+        }
+        let auths = auths.iter().map(Into::into).collect::<std::vec::Vec<_>>();
+        self.env_impl.set_authorization_entries(auths).unwrap();
+    }
+
     /// Mock all calls to the [`Address::require_auth`] and
     /// [`Address::require_auth_for_args`] functions in invoked contracts,
     /// having them succeed as if authorization was provided.
@@ -1281,6 +1963,9 @@ impl Env {
     /// [`Address::require_auth`] with the same address and args in the same
     /// tree of contract invocations will appear only once in the vector.
     ///
+    /// Use [`Auths::assert_eq`][crate::testutils::Auths::assert_eq] to assert
+    /// the authorizations with a readable diff when they don't match.
+    ///
     /// ### Examples
     /// ```
     /// use soroban_sdk::{contract, contractimpl, testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation}, symbol_short, Address, Symbol, Env, IntoVal};
@@ -1509,6 +2194,46 @@ impl Env {
     ///
     /// Used to write or read contract data, or take other actions in tests for
     /// setting up tests or asserting on internal state.
+    ///
+    /// Within the function, storage and
+    /// [`current_contract_address`][Self::current_contract_address] resolve
+    /// to the given contract, which makes it possible to test a contract's
+    /// internal helper functions directly without invoking the contract. The
+    /// contract must be registered.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn get(env: Env) -> Option<u32> {
+    ///         env.storage().persistent().get(&1)
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///
+    ///     env.as_contract(&contract_id, || {
+    ///         assert_eq!(env.current_contract_address(), contract_id);
+    ///         env.storage().persistent().set(&1, &2u32);
+    ///     });
+    ///
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///     assert_eq!(client.get(), Some(2));
+    /// }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
     pub fn as_contract<T>(&self, id: &Address, f: impl FnOnce() -> T) -> T {
         let id: [u8; 32] = id.contract_id().into();
         let func = Symbol::from_small_str("");
@@ -1522,6 +2247,50 @@ impl Env {
         t.unwrap()
     }
 
+    /// Run the function with the storage of the given contract.
+    ///
+    /// Used to set up or inspect the state of a contract that the contract
+    /// under test depends on, without that contract exposing functions for it.
+    /// It is shorthand for calling [`storage`][Self::storage] inside
+    /// [`as_contract`][Self::as_contract]. The contract must be registered.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn set(env: Env, value: u32) {
+    ///         env.storage().persistent().set(&symbol_short!("value"), &value);
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///
+    ///     client.set(&7);
+    ///
+    ///     let value: Option<u32> = env.storage_of(&contract_id, |storage| {
+    ///         storage.persistent().get(&symbol_short!("value"))
+    ///     });
+    ///     assert_eq!(value, Some(7));
+    /// }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn storage_of<T>(&self, id: &Address, f: impl FnOnce(&Storage) -> T) -> T {
+        self.as_contract(id, || f(&self.storage()))
+    }
+
     /// Creates a new Env loaded with the [`Snapshot`].
     ///
     /// The ledger info and state in the snapshot are loaded into the Env.
@@ -1936,7 +2705,27 @@ macro_rules! sdk_function_helper {
     =>
     {
         fn $fn_id(&self, $($arg:$type),*) -> Result<$ret, Self::Error> {
-            internal::reject_err(&self.env_impl, self.env_impl.$fn_id($($arg),*))
+            #[cfg(any(test, feature = "testutils"))]
+            if let Some(e) = self.test_state.fault_injection.take(stringify!($fn_id)) {
+                return internal::reject_err(
+                    &self.env_impl,
+                    Err(internal::EnvBase::error_from_error_val(&self.env_impl, e)),
+                );
+            }
+            #[cfg(any(test, feature = "testutils"))]
+            let invoke = self.with_host_trace(|mut t| t.enter(stringify!($fn_id)));
+            #[cfg(any(test, feature = "testutils"))]
+            let footprint = (invoke == Some(1)).then(|| self.footprint_snapshot());
+            let res = self.env_impl.$fn_id($($arg),*);
+            #[cfg(any(test, feature = "testutils"))]
+            if invoke.is_some() {
+                self.with_host_trace(|mut t| t.leave());
+            }
+            #[cfg(any(test, feature = "testutils"))]
+            if let Some(before) = footprint {
+                self.record_footprint(before);
+            }
+            internal::reject_err(&self.env_impl, res)
         }
     };
 }
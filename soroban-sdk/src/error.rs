@@ -35,3 +35,45 @@ impl From<Infallible> for InvokeError {
         unreachable!()
     }
 }
+
+/// TypeMismatchError is returned by [`TryAs::try_as`][crate::TryAs::try_as]
+/// when a [`Val`][crate::Val] is not of the type it is converted to, and by
+/// [`Env::invoke_contract_strict`][crate::Env::invoke_contract_strict] when
+/// the value returned from the invoked contract is not of the expected type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TypeMismatchError {
+    /// The name of the Rust type the value was converted to.
+    ///
+    /// Only available in tests, so that contracts don't carry the names of
+    /// the types they convert to.
+    #[cfg(any(test, feature = "testutils"))]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
+    pub expected: &'static str,
+    /// The type of the value.
+    pub actual: xdr::ScValType,
+}
+
+impl TypeMismatchError {
+    pub(crate) fn new<T>(actual: xdr::ScValType) -> Self {
+        Self {
+            #[cfg(any(test, feature = "testutils"))]
+            expected: core::any::type_name::<T>(),
+            actual,
+        }
+    }
+}
+
+impl core::fmt::Display for TypeMismatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "value of type {} can't be converted", self.actual.name())?;
+        #[cfg(any(test, feature = "testutils"))]
+        write!(f, " to {}", self.expected)?;
+        Ok(())
+    }
+}
+
+impl From<TypeMismatchError> for crate::ConversionError {
+    fn from(_: TypeMismatchError) -> Self {
+        crate::ConversionError
+    }
+}
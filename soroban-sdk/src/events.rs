@@ -128,4 +128,70 @@ impl testutils::Events for Events {
             });
         vec
     }
+
+    fn nth(&self, i: u32) -> Option<(crate::Address, Vec<Val>, Val)> {
+        self.all().get(i)
+    }
+
+    fn assert_published<T, D>(&self, contract: &Address, topics: T, data: D)
+    where
+        T: Topics,
+        D: IntoVal<Env, Val>,
+    {
+        let env = self.env();
+        let expected = (contract.clone(), topics.into_val(env), data.into_val(env));
+        let all = self.all();
+        if !all.iter().any(|e| event_eq(env, &e, &expected)) {
+            let mut published = std::string::String::new();
+            for e in all.iter() {
+                published.push_str(&std::format!("\n  {}", describe_event(env, &e)));
+            }
+            if published.is_empty() {
+                published.push_str("\n  (none)");
+            }
+            panic!(
+                "expected event was not published\nexpected:\n  {}\npublished:{}",
+                describe_event(env, &expected),
+                published
+            );
+        }
+    }
+
+    fn assert_not_published<T, D>(&self, contract: &Address, topics: T, data: D)
+    where
+        T: Topics,
+        D: IntoVal<Env, Val>,
+    {
+        let env = self.env();
+        let unexpected = (contract.clone(), topics.into_val(env), data.into_val(env));
+        if self.all().iter().any(|e| event_eq(env, &e, &unexpected)) {
+            panic!(
+                "unexpected event was published\nunexpected:\n  {}",
+                describe_event(env, &unexpected)
+            );
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+type Event = (Address, Vec<Val>, Val);
+
+#[cfg(any(test, feature = "testutils"))]
+fn event_eq(env: &Env, a: &Event, b: &Event) -> bool {
+    // Compare as a Vec so that the host compares objects by value instead of
+    // by handle.
+    let a = crate::vec![env, a.0.to_val(), a.1.to_val(), a.2];
+    let b = crate::vec![env, b.0.to_val(), b.1.to_val(), b.2];
+    a == b
+}
+
+#[cfg(any(test, feature = "testutils"))]
+fn describe_event(env: &Env, (contract, topics, data): &Event) -> std::string::String {
+    use crate::TryFromVal;
+    std::format!(
+        "contract: {:?}, topics: {:?}, data: {:?}",
+        contract,
+        xdr::ScVal::try_from_val(env, &topics.to_val()).unwrap(),
+        xdr::ScVal::try_from_val(env, data).unwrap(),
+    )
 }
@@ -77,3 +77,112 @@ where
         self.iter.len()
     }
 }
+
+/// A limit on the number of items an iteration may take.
+///
+/// Create one with [`Env::bounded`][crate::Env::bounded] or
+/// [`IterationBound::new`], and use it with [`BoundedEnumerable::bounded`] to
+/// cap loops over collections
+/// whose length is controlled by a caller. Such a loop can otherwise run out
+/// of resources part way through; bounding it makes the limit explicit, and
+/// the invocation panics as soon as the loop goes past the bound.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{
+///     iter::{BoundedEnumerable, IterationBound},
+///     vec, Env,
+/// };
+///
+/// let env = Env::default();
+/// let amounts = vec![&env, 1i128, 2, 3];
+///
+/// let bound = IterationBound::new(10);
+/// let mut total = 0;
+/// for amount in amounts.iter().bounded(&bound) {
+///     total += amount;
+/// }
+/// assert_eq!(total, 6);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IterationBound {
+    max: u32,
+}
+
+impl IterationBound {
+    /// Returns a bound of at most `max` items.
+    pub fn new(max: u32) -> Self {
+        IterationBound { max }
+    }
+
+    /// Returns the maximum number of items an iteration may take.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+}
+
+/// Adds [`bounded`][BoundedEnumerable::bounded] to every [`Iterator`].
+///
+/// The trait is implemented for all iterators, so once it is imported its
+/// method is in scope on any iterator. If another trait in scope also has a
+/// method named `bounded`, calls become ambiguous and have to be written as
+/// `BoundedEnumerable::bounded(iter, &bound)`. Import the trait only where it
+/// is used to keep that from happening.
+pub trait BoundedEnumerable: Iterator + Sized {
+    /// Returns an iterator that panics if the iteration goes on for more
+    /// items than the bound allows.
+    ///
+    /// Once the bound is reached, the next call takes one more item from the
+    /// underlying iterator to find out whether the iteration is over. If there
+    /// is none the iteration ends, so iterating exactly the maximum number of
+    /// items succeeds; otherwise the iterator panics. Each call counts
+    /// separately, so the same bound can cap several loops.
+    fn bounded(self, bound: &IterationBound) -> BoundedIter<Self>;
+}
+
+impl<I> BoundedEnumerable for I
+where
+    I: Iterator,
+{
+    fn bounded(self, bound: &IterationBound) -> BoundedIter<Self> {
+        BoundedIter {
+            iter: self,
+            remaining: bound.max,
+        }
+    }
+}
+
+/// An iterator that panics if it goes on for more items than an
+/// [`IterationBound`] allows, created with [`BoundedEnumerable::bounded`].
+#[derive(Clone)]
+pub struct BoundedIter<I> {
+    iter: I,
+    remaining: u32,
+}
+
+impl<I> Iterator for BoundedIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            if self.iter.next().is_none() {
+                return None;
+            }
+            sdk_panic!("iteration exceeded its bound");
+        }
+        let item = self.iter.next()?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for BoundedIter<I> where I: FusedIterator {}
@@ -175,6 +175,10 @@ pub use soroban_sdk_macros::symbol_short;
 /// Includes the type in the contract spec so that clients can generate bindings
 /// for the type.
 ///
+/// Also generates `as_u32` and `from_u32` functions on the enum for converting
+/// between a variant and its error code, such as when mapping errors to
+/// another error type or decoding a code logged elsewhere.
+///
 /// ### Examples
 ///
 /// Defining an error and capturing errors using the `try_` variant.
@@ -404,12 +408,53 @@ pub use soroban_sdk_macros::contract;
 /// # #[cfg(not(feature = "testutils"))]
 /// # fn main() { }
 /// ```
+///
+/// ### Extending the instance TTL automatically
+///
+/// Pass `auto_extend_instance(threshold = <ledgers>, extend_to = <ledgers>)`
+/// to extend the TTL of the contract instance at the start of every function
+/// in the impl block, before the function body runs. This is equivalent to
+/// beginning each function with:
+///
+/// ```ignore
+/// env.storage().instance().extend_ttl(threshold, extend_to);
+/// ```
+///
+/// The instance, along with its code and instance storage, is extended to
+/// `extend_to` ledgers only when its TTL has dropped below `threshold`. An
+/// extension adds rent to the fee of the invocation that performs it, so the
+/// gap between the two values sets how often that happens: a `threshold`
+/// close to `extend_to` extends on nearly every invocation in a new ledger,
+/// while a lower `threshold` spreads the cost over fewer, larger extensions.
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl(auto_extend_instance(threshold = 50_000, extend_to = 100_000))]
+/// impl Contract {
+///     pub fn hello(_env: Env) {}
+/// }
+/// # fn main() { }
+/// ```
+///
+/// ### Parameter limit
+///
+/// Contract functions can have at most 10 parameters, not counting the
+/// [Env]. A function with more parameters fails to compile with an error
+/// naming the function and the limit.
 pub use soroban_sdk_macros::contractimpl;
 
 /// Adds a serialized SCMetaEntry::SCMetaV0 to the WASM contracts custom section
 /// under the section name 'contractmetav0'. Contract developers can use this to
 /// append metadata to their contract.
 ///
+/// The client generated by [`contractimport`] for a contract exposes the
+/// contract's meta entries as `Client::META`, so code using the client can
+/// read them without calling the contract.
+///
 /// ### Examples
 ///
 /// ```
@@ -464,6 +509,14 @@ pub use soroban_sdk_macros::contractmeta;
 /// Includes the type in the contract spec so that clients can generate bindings
 /// for the type.
 ///
+/// Enums with unit and tuple-like variants are encoded by variant name, as a
+/// [Vec] whose first element is the name as a [Symbol] followed by the tuple
+/// field, if any. Variants can therefore be reordered, and new variants
+/// inserted, without changing the encoding of existing values, but renaming a
+/// variant changes it. Enums with integer values are encoded as their explicit
+/// integer literal, which pins the encoding of each variant regardless of
+/// order, and the compiler rejects two variants with the same value.
+///
 /// ### Examples
 ///
 /// Defining a contract type that is a struct and use it in a contract.
@@ -602,8 +655,149 @@ pub use soroban_sdk_macros::contractmeta;
 /// # #[cfg(not(feature = "testutils"))]
 /// # fn main() { }
 /// ```
+///
+/// ### Split storage
+///
+/// Structs with named fields accept `#[contracttype(split_storage)]`, which
+/// generates functions for storing each field in its own entry in
+/// persistent storage instead of storing the whole struct in one entry. The
+/// entry of a field is keyed by a caller chosen key and the field name, as the
+/// tuple `(key, Symbol::new(env, "field"))`.
+///
+/// For each field `f` of type `T`:
+/// - `load_f(env, key) -> Option<T>` reads the field.
+/// - `save_f(env, key, value: &T)` writes the field.
+///
+/// And for the whole struct:
+/// - `load_fields(env, key) -> Option<Self>` reads every field, returning
+///   `None` if any is missing.
+/// - `self.save_fields(env, key)` writes every field.
+/// - `remove_fields(env, key)` removes every field.
+///
+/// Writing a single field only pays the write fee for the size of that field,
+/// which makes updating a frequently changing field of a large struct cheaper.
+/// In exchange, reading or writing the whole struct touches one entry per
+/// field, which costs more in reads, writes, and rent than a single entry, so
+/// split storage suits structs that are mostly updated a field at a time.
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Env, String};
+///
+/// #[contracttype(split_storage)]
+/// #[derive(Clone, Debug, Eq, PartialEq)]
+/// pub struct Profile {
+///     pub name: String,
+///     pub visits: u32,
+/// }
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn visit(env: Env) -> Profile {
+///         let key = symbol_short!("profile");
+///         let visits = Profile::load_visits(&env, &key).unwrap_or(0) + 1;
+///         Profile::save_visits(&env, &key, &visits);
+///         Profile::load_fields(&env, &key).unwrap()
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     env.as_contract(&contract_id, || {
+///         let profile = Profile { name: String::from_str(&env, "alice"), visits: 0 };
+///         profile.save_fields(&env, &symbol_short!("profile"));
+///     });
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     assert_eq!(client.visit().visits, 1);
+///     assert_eq!(client.visit().visits, 2);
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
 pub use soroban_sdk_macros::contracttype;
 
+/// Generates typed storage accessors for the variants of a storage key enum.
+///
+/// Each variant marked with a `#[storage(value = T)]` attribute is a storage
+/// slot holding a value of type `T`. For a variant `Name`, associated
+/// functions are generated on the enum:
+/// - `load_name(env, ..) -> Option<T>`
+/// - `save_name(env, .., value: &T)`
+/// - `remove_name(env, ..)`
+///
+/// where `..` are references to the variant's fields, if it has any. The
+/// function names use the variant name in snake case.
+///
+/// Values are kept in persistent storage unless the attribute includes
+/// `temporary` or `instance`, e.g. `#[storage(instance, value = Address)]`.
+/// Variants without the attribute get no accessors.
+///
+/// The enum must also be a [`contracttype`] so that it can be used as a
+/// storage key.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, StorageKey};
+///
+/// #[contracttype]
+/// #[derive(StorageKey)]
+/// pub enum DataKey {
+///     #[storage(instance, value = Address)]
+///     Admin,
+///     #[storage(value = i128)]
+///     Balance(Address),
+/// }
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: Env, admin: Address) {
+///         DataKey::save_admin(&env, &admin);
+///     }
+///
+///     pub fn deposit(env: Env, to: Address, amount: i128) {
+///         DataKey::load_admin(&env).unwrap().require_auth();
+///         let balance = DataKey::load_balance(&env, &to).unwrap_or(0);
+///         DataKey::save_balance(&env, &to, &(balance + amount));
+///     }
+///
+///     pub fn balance(env: Env, id: Address) -> i128 {
+///         DataKey::load_balance(&env, &id).unwrap_or(0)
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+///     # use soroban_sdk::testutils::Address as _;
+///     let env = Env::default();
+///     env.mock_all_auths();
+///     let admin = Address::generate(&env);
+///     let contract_id = env.register(Contract, (&admin,));
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     let user = Address::generate(&env);
+///     client.deposit(&user, &10);
+///     assert_eq!(client.balance(&user), 10);
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub use soroban_sdk_macros::StorageKey;
+
 /// Generates a type that helps build function args for a contract trait.
 pub use soroban_sdk_macros::contractargs;
 
@@ -623,6 +817,12 @@ pub use soroban_sdk_macros::contractargs;
 /// Note that [`contractimport`] also automatically generates a client when
 /// importing someone elses contract where they have shared a .wasm file.
 ///
+/// A trait with a client is also how to define a callback, such as the
+/// function a lender calls on the borrower during a flash loan. The contract
+/// making the callback invokes it with the client, and the contract receiving
+/// it implements the trait with [`contractimpl`], so the function name and
+/// arguments cannot drift apart between the two.
+///
 /// ### Examples
 ///
 /// ```
@@ -665,6 +865,46 @@ pub use soroban_sdk_macros::contractargs;
 /// # fn main() { }
 pub use soroban_sdk_macros::contractclient;
 
+/// Declares the version of the interface a contract implements.
+///
+/// Exports a function `soroban_interface_version` from the contract that
+/// returns the version, and adds a `probe_version` function to the contract's
+/// generated client that calls it. `probe_version` returns `None` if the
+/// contract at the address does not declare a version, which lets a caller
+/// adapt to the capabilities of an older or newer contract.
+///
+/// Must be placed on the contract type, alongside [`contract`].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, contractinterface, Env};
+///
+/// #[contract]
+/// #[contractinterface(version = 2)]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn hello(_env: Env) {}
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     assert_eq!(client.probe_version(), Some(2));
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub use soroban_sdk_macros::contractinterface;
+
 /// Generates a contract spec for a trait or impl.
 ///
 /// Note that [`contractimpl`] also generates a contract spec and it is in most
@@ -771,6 +1011,9 @@ pub use env::Val;
 pub use env::FromVal;
 /// Used to do conversions between values in the Soroban environment.
 pub use env::IntoVal;
+/// Used to convert a [Val] to a type, reporting the type of the value if the
+/// conversion fails.
+pub use env::TryAs;
 /// Used to do conversions between values in the Soroban environment.
 pub use env::TryFromVal;
 /// Used to do conversions between values in the Soroban environment.
@@ -805,19 +1048,20 @@ mod bytes;
 pub mod crypto;
 pub mod deploy;
 mod error;
-pub use error::InvokeError;
+pub use error::{InvokeError, TypeMismatchError};
 pub mod events;
 pub use events::Topics;
 pub mod iter;
 pub mod ledger;
 pub mod logs;
 mod map;
+pub mod math;
 pub mod prng;
 pub mod storage;
 pub mod token;
 mod vec;
 pub use address::Address;
-pub use bytes::{Bytes, BytesN};
+pub use bytes::{Bytes, BytesN, BytesReader, BytesWriter};
 pub use map::Map;
 pub use symbol::Symbol;
 pub use vec::Vec;
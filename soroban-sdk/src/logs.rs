@@ -3,6 +3,8 @@
 //! See [`log`][crate::log] for how to conveniently log debug events.
 use core::fmt::Debug;
 
+#[cfg(any(test, feature = "testutils"))]
+use crate::TryFromVal;
 use crate::{env::internal::EnvBase, Env, Val};
 
 /// Log a debug event.
@@ -85,6 +87,62 @@ macro_rules! log {
     };
 }
 
+/// Log a debug event with named fields.
+///
+/// Takes a [Env], a literal string, and a trailing sequence of `name => value`
+/// pairs, where each value may be any value that is convertible to [`Val`].
+/// The fields are collected into a [`Map`][crate::Map] keyed by the field
+/// names as [`Symbol`][crate::Symbol]s, and logged as the only argument of the
+/// log entry.
+///
+/// Like [`log`][crate::log], `log_fields!` statements are only enabled in
+/// builds that have `debug-assertions` enabled, and the fields are not
+/// evaluated otherwise.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{log_fields, testutils::Address as _, Address, Env};
+///
+/// let env = Env::default();
+///
+/// let from = Address::generate(&env);
+/// let amount = 100i128;
+/// log_fields!(&env, "transfer", from => from, amount => amount);
+/// ```
+///
+/// Assert on the fields in tests:
+///
+/// ```
+/// # #[cfg(feature = "testutils")]
+/// # {
+/// use soroban_sdk::{log_fields, Env};
+///
+/// let env = Env::default();
+///
+/// log_fields!(&env, "transfer", amount => 100i128);
+///
+/// use soroban_sdk::testutils::Logs;
+/// let entry = env.logs().find("transfer").unwrap();
+/// assert_eq!(entry.field::<i128>("amount"), Some(100));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! log_fields {
+    ($env:expr, $fmt:literal, $($name:ident => $value:expr),+ $(,)?) => {
+        if cfg!(debug_assertions) {
+            let fields = $crate::map![
+                $env,
+                $((
+                    $crate::Symbol::new($env, stringify!($name)),
+                    <_ as $crate::IntoVal<$crate::Env, $crate::Val>>::into_val(&$value, $env)
+                )),+
+            ];
+            $env.logs().add($fmt, &[fields.to_val()]);
+        }
+    };
+}
+
 /// Logs logs debug events.
 ///
 /// See [`log`][crate::log] for how to conveniently log debug events.
@@ -142,6 +200,35 @@ use crate::testutils;
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
 impl testutils::Logs for Logs {
     fn all(&self) -> std::vec::Vec<String> {
+        self.log_events()
+            .into_iter()
+            .map(|e| format!("{}", &e))
+            .collect::<std::vec::Vec<_>>()
+    }
+
+    fn rendered(&self) -> std::vec::Vec<String> {
+        self.log_entries()
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<std::vec::Vec<_>>()
+    }
+
+    fn find(&self, message: &str) -> Option<testutils::LogEntry> {
+        self.log_entries()
+            .into_iter()
+            .rev()
+            .find(|e| e.message() == message)
+    }
+
+    fn print(&self) {
+        std::println!("{}", self.all().join("\n"))
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+impl Logs {
+    /// Returns the diagnostic events that were emitted as logs.
+    fn log_events(&self) -> std::vec::Vec<crate::env::internal::events::HostEvent> {
         use crate::xdr::{
             ContractEventBody, ContractEventType, ScSymbol, ScVal, ScVec, StringM, VecM,
         };
@@ -153,18 +240,43 @@ impl testutils::Logs for Logs {
             .unwrap()
             .0
             .into_iter()
-            .filter_map(|e| match (&e.event.type_, &e.event.body) {
-                (ContractEventType::Diagnostic, ContractEventBody::V0(ce))
-                    if &ce.topics == &log_topics.0 =>
-                {
-                    Some(format!("{}", &e))
+            .filter(|e| match (&e.event.type_, &e.event.body) {
+                (ContractEventType::Diagnostic, ContractEventBody::V0(ce)) => {
+                    &ce.topics == &log_topics.0
                 }
-                _ => None,
+                _ => false,
             })
             .collect::<std::vec::Vec<_>>()
     }
 
-    fn print(&self) {
-        std::println!("{}", self.all().join("\n"))
+    /// Returns the logs as entries of their message and arguments, skipping
+    /// any log whose message is not a string.
+    fn log_entries(&self) -> std::vec::Vec<testutils::LogEntry> {
+        use crate::xdr::{ContractEventBody, ScVal};
+        let env = self.env();
+        self.log_events()
+            .into_iter()
+            .filter_map(|e| {
+                let data = match &e.event.body {
+                    ContractEventBody::V0(ce) => &ce.data,
+                };
+                let (msg, args) = match data {
+                    ScVal::String(msg) => (msg, &[][..]),
+                    ScVal::Vec(Some(v)) => match v.0.split_first() {
+                        Some((ScVal::String(msg), args)) => (msg, args),
+                        _ => return None,
+                    },
+                    _ => return None,
+                };
+                let mut vals = crate::Vec::new(env);
+                for arg in args {
+                    vals.push_back(Val::try_from_val(env, arg).unwrap());
+                }
+                Some(testutils::LogEntry::new(
+                    &msg.0.to_utf8_string_lossy(),
+                    vals,
+                ))
+            })
+            .collect::<std::vec::Vec<_>>()
     }
 }
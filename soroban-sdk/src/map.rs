@@ -70,6 +70,13 @@ macro_rules! map {
 /// Maps have at most one entry per key. Setting a value for a key in the map
 /// that already has a value for that key replaces the value.
 ///
+/// Keys are compared by value using the Host's comparison of [Val]s, which
+/// compares objects such as [Vec], [Map], and [Bytes][crate::Bytes] by their
+/// contents, element by element. Collections, including nested collections,
+/// can therefore be used as keys, and a lookup with an equal but separately
+/// constructed key finds the entry. The [Eq] and [Ord] implementations of
+/// [Map] and [Vec] use the same comparison.
+///
 /// Map values can be stored as [Storage], or in other types like [Vec], [Map],
 /// etc.
 ///
@@ -98,6 +105,20 @@ macro_rules! map {
 ///     map![&env, (2, 20), (1, 10)],
 /// )
 /// ```
+///
+/// The entries of a map are always held in key order, so a map serializes to
+/// the same XDR regardless of the order its entries were inserted in, and a
+/// stored map is read back with the same ordering.
+///
+/// ```
+/// use soroban_sdk::{Env, Map, map, xdr::ToXdr};
+///
+/// let env = Env::default();
+/// assert_eq!(
+///     map![&env, (1, 10), (2, 20)].to_xdr(&env),
+///     map![&env, (2, 20), (1, 10)].to_xdr(&env),
+/// )
+/// ```
 #[derive(Clone)]
 pub struct Map<K, V> {
     env: Env,
@@ -343,6 +364,47 @@ where
         self.try_get(k).unwrap_optimized()
     }
 
+    /// Returns the value corresponding to the key, or the value computed by
+    /// `f` if the map does not contain a value with the specified key.
+    ///
+    /// `f` is only called when the key is missing.
+    ///
+    /// ### Panics
+    ///
+    /// If the value corresponding to the key cannot be converted to type V.
+    #[inline(always)]
+    pub fn get_or_else<F: FnOnce() -> V>(&self, k: K, f: F) -> V {
+        self.get(k).unwrap_or_else(f)
+    }
+
+    /// Returns the value corresponding to the key, panicking with the message
+    /// if the map does not contain a value with the specified key.
+    ///
+    /// Useful for required entries, such as configuration, where a missing
+    /// key is a bug that the message can describe.
+    ///
+    /// ### Panics
+    ///
+    /// If the map does not contain a value with the specified key. Panic
+    /// messages are not included in contracts built for Wasm, so the message
+    /// is only seen in tests.
+    ///
+    /// If the value corresponding to the key cannot be converted to type V.
+    pub fn get_or_panic_with(&self, k: K, msg: &str) -> V {
+        match self.get(k) {
+            Some(v) => v,
+            None => {
+                #[cfg(not(target_family = "wasm"))]
+                panic!("{msg}");
+                #[cfg(target_family = "wasm")]
+                {
+                    let _ = msg;
+                    sdk_panic!()
+                }
+            }
+        }
+    }
+
     /// Returns the value corresponding to the key or None if the map does not
     /// contain a value with the specified key.
     ///
@@ -445,6 +507,109 @@ where
         let vec = env.map_values(self.obj).unwrap_infallible();
         Vec::<V>::try_from_val(env, &vec).unwrap()
     }
+
+    /// Returns a [Vec] of all key-value pairs in the map, in order of the
+    /// keys.
+    pub fn to_vec(&self) -> Vec<(K, V)> {
+        let keys = self.keys();
+        let values = self.values();
+        let mut vec = Vec::new(self.env());
+        for i in 0..keys.len() {
+            vec.push_back((keys.get_unchecked(i), values.get_unchecked(i)));
+        }
+        vec
+    }
+
+    /// Returns true if any key in the map corresponds to the value.
+    ///
+    /// The values are searched one by one, so this is O(n) in the length of
+    /// the map, unlike looking up a key.
+    ///
+    /// ### Panics
+    ///
+    /// If any value in the map cannot be converted to type V.
+    pub fn contains_value(&self, value: &V) -> bool
+    where
+        V: Eq,
+    {
+        self.find_key(value).is_some()
+    }
+
+    /// Returns the first key, in the order of the keys, that corresponds to
+    /// the value, or None if no key corresponds to the value.
+    ///
+    /// Like [`contains_value`][Self::contains_value], this is O(n) in the
+    /// length of the map.
+    ///
+    /// ### Panics
+    ///
+    /// If any key or value in the map cannot be converted to type K or V.
+    pub fn find_key(&self, value: &V) -> Option<K>
+    where
+        V: Eq,
+    {
+        let i = self.values().iter().position(|v| &v == value)?;
+        Some(self.keys().get_unchecked(i as u32))
+    }
+
+    /// Retains only the key-values for which the predicate returns true,
+    /// removing all others.
+    ///
+    /// ### Panics
+    ///
+    /// If any key or value in the map cannot be converted to type K or V.
+    pub fn retain<F: FnMut(&K, &V) -> bool>(&mut self, mut f: F) {
+        for (k, v) in self.iter() {
+            if !f(&k, &v) {
+                self.remove_unchecked(k);
+            }
+        }
+    }
+
+    /// Returns the keys of the map that are not keys of the other map, in
+    /// order of the keys.
+    ///
+    /// The values of the maps are not compared and may be of different types.
+    ///
+    /// ### Panics
+    ///
+    /// If any key in the map cannot be converted to type K.
+    pub fn key_difference<V2>(&self, other: &Map<K, V2>) -> Vec<K>
+    where
+        K: Clone,
+        V2: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.filter_keys(|k| !other.contains_key(k))
+    }
+
+    /// Returns the keys of the map that are also keys of the other map, in
+    /// order of the keys.
+    ///
+    /// The values of the maps are not compared and may be of different types.
+    ///
+    /// ### Panics
+    ///
+    /// If any key in the map cannot be converted to type K.
+    pub fn key_intersection<V2>(&self, other: &Map<K, V2>) -> Vec<K>
+    where
+        K: Clone,
+        V2: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.filter_keys(|k| other.contains_key(k))
+    }
+
+    fn filter_keys(&self, mut f: impl FnMut(K) -> bool) -> Vec<K>
+    where
+        K: Clone,
+    {
+        let mut keys = Vec::new(self.env());
+        for k in self.keys() {
+            if f(k.clone()) {
+                keys.push_back(k);
+            }
+        }
+        keys
+    }
 }
 
 impl<K, V> Map<K, V> {
@@ -941,6 +1106,126 @@ mod test {
         let _ = map.get(1);
     }
 
+    #[test]
+    fn test_get_or_else() {
+        let env = Env::default();
+
+        let map: Map<u32, u32> = map![&env, (0, 0), (1, 10)];
+
+        let mut calls = 0;
+        assert_eq!(
+            map.get_or_else(1, || {
+                calls += 1;
+                99
+            }),
+            10
+        );
+        assert_eq!(calls, 0);
+
+        assert_eq!(
+            map.get_or_else(2, || {
+                calls += 1;
+                99
+            }),
+            99
+        );
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_get_or_panic_with() {
+        let env = Env::default();
+
+        let map: Map<u32, u32> = map![&env, (0, 0), (1, 10)];
+        assert_eq!(map.get_or_panic_with(1, "missing fee config"), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing fee config")]
+    fn test_get_or_panic_with_absent() {
+        let env = Env::default();
+
+        let map: Map<u32, u32> = map![&env, (0, 0), (1, 10)];
+        map.get_or_panic_with(2, "missing fee config");
+    }
+
+    #[test]
+    fn test_contains_value_and_find_key() {
+        let env = Env::default();
+
+        let map: Map<u32, u32> = map![&env];
+        assert!(!map.contains_value(&0));
+        assert_eq!(map.find_key(&0), None);
+
+        let map: Map<u32, u32> = map![&env, (3, 30), (1, 10), (2, 20)];
+        assert!(map.contains_value(&20));
+        assert_eq!(map.find_key(&20), Some(2));
+        assert!(!map.contains_value(&2));
+        assert_eq!(map.find_key(&2), None);
+
+        // The first key in key order is found when several share the value.
+        let map: Map<u32, u32> = map![&env, (7, 1), (5, 0), (4, 1), (6, 1)];
+        assert!(map.contains_value(&1));
+        assert_eq!(map.find_key(&1), Some(4));
+        assert_eq!(map.find_key(&0), Some(5));
+    }
+
+    #[test]
+    fn test_xdr_independent_of_insertion_order() {
+        use crate::{xdr::ToXdr, Symbol};
+
+        let env = Env::default();
+
+        let mut ascending = Map::<Symbol, u32>::new(&env);
+        for (k, v) in [("a", 1), ("b", 2), ("c", 3), ("d", 4)] {
+            ascending.set(Symbol::new(&env, k), v);
+        }
+        let mut mixed = Map::<Symbol, u32>::new(&env);
+        for (k, v) in [("c", 3), ("a", 10), ("d", 4), ("b", 2), ("a", 1)] {
+            mixed.set(Symbol::new(&env, k), v);
+        }
+
+        assert_eq!(ascending.clone().to_xdr(&env), mixed.clone().to_xdr(&env));
+        assert_eq!(ascending.keys(), mixed.keys());
+    }
+
+    #[test]
+    fn test_retain() {
+        let env = Env::default();
+
+        let mut map: Map<u32, u32> = map![&env, (1, 10), (2, 20), (3, 30)];
+        map.retain(|_, _| true);
+        assert_eq!(map, map![&env, (1, 10), (2, 20), (3, 30)]);
+
+        map.retain(|_, v| *v != 20);
+        assert_eq!(map, map![&env, (1, 10), (3, 30)]);
+        assert!(!map.contains_key(2));
+
+        map.retain(|_, _| false);
+        assert!(map.is_empty());
+        assert!(!map.contains_key(1));
+        assert!(!map.contains_key(3));
+    }
+
+    #[test]
+    fn test_key_difference_and_intersection() {
+        let env = Env::default();
+
+        let a: Map<u32, u32> = map![&env, (3, 30), (1, 10), (2, 20)];
+        let disjoint: Map<u32, bool> = map![&env, (4, true), (5, false)];
+        let overlapping: Map<u32, bool> = map![&env, (2, true), (4, true), (1, false)];
+
+        assert_eq!(a.key_difference(&disjoint), vec![&env, 1, 2, 3]);
+        assert_eq!(a.key_intersection(&disjoint), vec![&env]);
+
+        assert_eq!(a.key_difference(&a), vec![&env]);
+        assert_eq!(a.key_intersection(&a), vec![&env, 1, 2, 3]);
+
+        assert_eq!(a.key_difference(&overlapping), vec![&env, 3]);
+        assert_eq!(a.key_intersection(&overlapping), vec![&env, 1, 2]);
+        assert_eq!(overlapping.key_difference(&a), vec![&env, 4]);
+    }
+
     #[test]
     fn test_try_get() {
         let env = Env::default();
@@ -1099,4 +1384,64 @@ mod test {
         let mut map: Map<u32, u32> = map![&env, (0, 0), (1, 10), (2, 20), (3, 30), (4, 40)];
         map.remove_unchecked(100); // key does not exist
     }
+
+    #[test]
+    fn test_vec_as_key() {
+        let env = Env::default();
+
+        let mut map: Map<Vec<u32>, u32> = Map::new(&env);
+        map.set(vec![&env, 1, 2], 12);
+        map.set(vec![&env, 1], 1);
+        map.set(vec![&env], 0);
+        map.set(vec![&env, 1, 2], 120);
+
+        assert_eq!(map.len(), 3);
+        let mut key = Vec::new(&env);
+        key.push_back(1);
+        key.push_back(2);
+        assert_eq!(map.get(key.clone()), Some(120));
+        assert!(map.contains_key(key));
+        assert_eq!(map.get(vec![&env, 2, 1]), None);
+        assert_eq!(
+            map.keys(),
+            vec![&env, vec![&env], vec![&env, 1], vec![&env, 1, 2]]
+        );
+    }
+
+    #[test]
+    fn test_nested_collection_as_key() {
+        let env = Env::default();
+
+        let mut map: Map<Map<u32, Vec<u32>>, u32> = Map::new(&env);
+        map.set(map![&env, (1, vec![&env, 10]), (2, vec![&env, 20])], 1);
+
+        let key = map![&env, (2, vec![&env, 20]), (1, vec![&env, 10])];
+        assert_eq!(map.get(key), Some(1));
+        assert_eq!(map.get(map![&env, (1, vec![&env, 10])]), None);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let env = Env::default();
+
+        let map: Map<u32, i64> = map![&env, (3, 30), (1, 10), (2, 20)];
+        assert_eq!(map.to_vec(), vec![&env, (1, 10), (2, 20), (3, 30)]);
+
+        let empty: Map<u32, i64> = Map::new(&env);
+        assert_eq!(empty.to_vec(), Vec::<(u32, i64)>::new(&env));
+    }
+
+    #[test]
+    fn test_vec_to_map() {
+        let env = Env::default();
+
+        let pairs: Vec<(u32, i64)> = vec![&env, (3, 30), (1, 10), (3, 33), (2, 20)];
+        let map = pairs.to_map();
+        // The last value of a duplicate key wins.
+        assert_eq!(map, map![&env, (1, 10), (2, 20), (3, 33)]);
+        assert_eq!(map.to_vec(), vec![&env, (1, 10), (2, 20), (3, 33)]);
+
+        let empty: Vec<(u32, i64)> = Vec::new(&env);
+        assert_eq!(empty.to_map(), Map::<u32, i64>::new(&env));
+    }
 }
@@ -0,0 +1,267 @@
+//! Math contains helpers for integer arithmetic with explicit rounding.
+//!
+//! The `/` operator on `i128` truncates toward zero, which rounds positive
+//! and negative quotients in opposite directions. The functions in this module
+//! round the quotient of two `i128` values in a single direction regardless of
+//! the signs of the operands.
+//!
+//! All of the division functions panic in the same cases as `/`, when the
+//! divisor is zero or when dividing [`i128::MIN`] by `-1` overflows.
+//!
+//! The module also has [`bps`] for taking a fraction of an amount in basis
+//! points without overflowing on large amounts.
+
+/// Divides `a` by `b`, rounding the quotient toward negative infinity.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::math::div_floor;
+///
+/// assert_eq!(div_floor(7, 2), 3);
+/// assert_eq!(div_floor(-7, 2), -4);
+/// assert_eq!(div_floor(7, -2), -4);
+/// assert_eq!(div_floor(-7, -2), 3);
+/// assert_eq!(div_floor(-6, 2), -3);
+/// ```
+///
+/// ### Panics
+///
+/// If `b` is zero or the quotient overflows.
+pub fn div_floor(a: i128, b: i128) -> i128 {
+    floor_div_rem(a, b).0
+}
+
+/// Divides `a` by `b`, rounding the quotient toward positive infinity.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::math::div_ceil;
+///
+/// assert_eq!(div_ceil(7, 2), 4);
+/// assert_eq!(div_ceil(-7, 2), -3);
+/// assert_eq!(div_ceil(7, -2), -3);
+/// assert_eq!(div_ceil(-7, -2), 4);
+/// assert_eq!(div_ceil(-6, 2), -3);
+/// ```
+///
+/// ### Panics
+///
+/// If `b` is zero or the quotient overflows.
+pub fn div_ceil(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    let r = a % b;
+    if r != 0 && ((r < 0) == (b < 0)) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+/// Divides `a` by `b`, rounding the quotient to the nearest integer, with
+/// quotients exactly halfway between two integers rounded toward positive
+/// infinity (round half up).
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::math::div_round;
+///
+/// assert_eq!(div_round(7, 3), 2);
+/// assert_eq!(div_round(8, 3), 3);
+/// assert_eq!(div_round(5, 2), 3);
+/// assert_eq!(div_round(-5, 2), -2);
+/// assert_eq!(div_round(-7, 2), -3);
+/// assert_eq!(div_round(-8, 3), -3);
+/// assert_eq!(div_round(5, -2), -2);
+/// ```
+///
+/// ### Panics
+///
+/// If `b` is zero or the quotient overflows.
+pub fn div_round(a: i128, b: i128) -> i128 {
+    // The floored remainder has the sign of the divisor, so r / b is the
+    // fraction in [0, 1) that was dropped from the quotient.
+    let (q, r) = floor_div_rem(a, b);
+    let r = r.unsigned_abs();
+    if r >= b.unsigned_abs() - r {
+        q + 1
+    } else {
+        q
+    }
+}
+
+/// The number of basis points in a whole, 100%.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Returns `amount * basis_points / 10000`, rounded toward negative infinity,
+/// for computing fees and shares in basis points.
+///
+/// The result is exact, as if computed with a 256-bit intermediate product,
+/// so it does not overflow for any amount even though `amount * basis_points`
+/// may not fit in an `i128`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::math::bps;
+///
+/// assert_eq!(bps(1_000_000, 30), 3_000);
+/// assert_eq!(bps(999, 30), 2);
+/// assert_eq!(bps(-999, 30), -3);
+/// assert_eq!(bps(i128::MAX, 10_000), i128::MAX);
+/// ```
+///
+/// ### Panics
+///
+/// If `basis_points` is greater than 10000.
+pub fn bps(amount: i128, basis_points: u32) -> i128 {
+    if basis_points > BPS_DENOMINATOR {
+        sdk_panic!("basis points must not exceed 10000");
+    }
+    // With amount = q * 10000 + r where 0 <= r < 10000, the product is
+    // q * basis_points plus r * basis_points / 10000, and neither part can
+    // overflow because basis_points is at most 10000.
+    let (q, r) = floor_div_rem(amount, BPS_DENOMINATOR as i128);
+    let basis_points = basis_points as i128;
+    q * basis_points + r * basis_points / BPS_DENOMINATOR as i128
+}
+
+/// Returns the quotient rounded toward negative infinity, and the remainder
+/// that has the sign of `b`.
+fn floor_div_rem(a: i128, b: i128) -> (i128, i128) {
+    let q = a / b;
+    let r = a % b;
+    if r != 0 && ((r < 0) != (b < 0)) {
+        (q - 1, r + b)
+    } else {
+        (q, r)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_div_floor() {
+        assert_eq!(div_floor(7, 2), 3);
+        assert_eq!(div_floor(-7, 2), -4);
+        assert_eq!(div_floor(7, -2), -4);
+        assert_eq!(div_floor(-7, -2), 3);
+        assert_eq!(div_floor(6, 2), 3);
+        assert_eq!(div_floor(-6, 2), -3);
+        assert_eq!(div_floor(6, -2), -3);
+        assert_eq!(div_floor(-6, -2), 3);
+        assert_eq!(div_floor(0, -5), 0);
+        assert_eq!(div_floor(i128::MIN, 1), i128::MIN);
+        assert_eq!(div_floor(i128::MAX, -1), -i128::MAX);
+        assert_eq!(div_floor(i128::MIN, 2), i128::MIN / 2);
+        assert_eq!(div_floor(i128::MIN + 1, i128::MAX), -1);
+        assert_eq!(div_floor(1, i128::MIN), -1);
+    }
+
+    #[test]
+    fn test_div_ceil() {
+        assert_eq!(div_ceil(7, 2), 4);
+        assert_eq!(div_ceil(-7, 2), -3);
+        assert_eq!(div_ceil(7, -2), -3);
+        assert_eq!(div_ceil(-7, -2), 4);
+        assert_eq!(div_ceil(6, 2), 3);
+        assert_eq!(div_ceil(-6, 2), -3);
+        assert_eq!(div_ceil(6, -2), -3);
+        assert_eq!(div_ceil(-6, -2), 3);
+        assert_eq!(div_ceil(0, -5), 0);
+        assert_eq!(div_ceil(i128::MAX, 1), i128::MAX);
+        assert_eq!(div_ceil(i128::MAX, 2), i128::MAX / 2 + 1);
+        assert_eq!(div_ceil(1, i128::MIN), 0);
+    }
+
+    #[test]
+    fn test_div_round() {
+        // Below halfway.
+        assert_eq!(div_round(7, 3), 2);
+        assert_eq!(div_round(-7, 3), -2);
+        assert_eq!(div_round(7, -3), -2);
+        assert_eq!(div_round(-7, -3), 2);
+        // Above halfway.
+        assert_eq!(div_round(8, 3), 3);
+        assert_eq!(div_round(-8, 3), -3);
+        assert_eq!(div_round(8, -3), -3);
+        assert_eq!(div_round(-8, -3), 3);
+        // Halfway.
+        assert_eq!(div_round(5, 2), 3);
+        assert_eq!(div_round(-5, 2), -2);
+        assert_eq!(div_round(5, -2), -2);
+        assert_eq!(div_round(-5, -2), 3);
+        assert_eq!(div_round(-7, 2), -3);
+        // Exact.
+        assert_eq!(div_round(6, 3), 2);
+        assert_eq!(div_round(-6, 3), -2);
+        assert_eq!(div_round(0, 3), 0);
+        // Extremes.
+        assert_eq!(div_round(i128::MAX, i128::MAX), 1);
+        assert_eq!(div_round(i128::MIN, i128::MIN), 1);
+        assert_eq!(div_round(i128::MAX, 2), i128::MAX / 2 + 1);
+        assert_eq!(div_round(i128::MIN, 3), i128::MIN / 3 - 1);
+        assert_eq!(div_round(1, i128::MIN), 0);
+        assert_eq!(div_round(-1, i128::MIN), 0);
+    }
+
+    #[test]
+    fn test_bps() {
+        // Zero basis points.
+        assert_eq!(bps(0, 0), 0);
+        assert_eq!(bps(12345, 0), 0);
+        assert_eq!(bps(i128::MAX, 0), 0);
+        assert_eq!(bps(i128::MIN, 0), 0);
+        // 10000 basis points is the identity.
+        assert_eq!(bps(0, 10_000), 0);
+        assert_eq!(bps(12345, 10_000), 12345);
+        assert_eq!(bps(-12345, 10_000), -12345);
+        assert_eq!(bps(i128::MAX, 10_000), i128::MAX);
+        assert_eq!(bps(i128::MIN, 10_000), i128::MIN);
+        // Rounding toward negative infinity.
+        assert_eq!(bps(1_000_000, 30), 3_000);
+        assert_eq!(bps(999, 30), 2);
+        assert_eq!(bps(-999, 30), -3);
+        assert_eq!(bps(1, 9_999), 0);
+        assert_eq!(bps(-1, 1), -1);
+        // Amounts near the limits, where amount * basis_points overflows.
+        assert_eq!(bps(i128::MAX, 5_000), i128::MAX / 2);
+        assert_eq!(bps(i128::MAX, 9_999), i128::MAX - i128::MAX / 10_000 - 1);
+        assert_eq!(bps(i128::MIN, 5_000), i128::MIN / 2);
+        assert_eq!(bps(i128::MAX - 1, 1), (i128::MAX - 1) / 10_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "basis points must not exceed 10000")]
+    fn test_bps_above_whole() {
+        let _ = bps(1, 10_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_floor_by_zero() {
+        let _ = div_floor(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_ceil_by_zero() {
+        let _ = div_ceil(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_round_by_zero() {
+        let _ = div_round(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_div_floor_overflow() {
+        let _ = div_floor(i128::MIN, -1);
+    }
+}
@@ -5,7 +5,7 @@ use super::{
         DurationSmall, DurationVal, Env as _, EnvBase as _, I256Small, I256Val, TimepointSmall,
         TimepointVal, U256Small, U256Val,
     },
-    Bytes, ConversionError, Env, TryFromVal, TryIntoVal, Val,
+    Bytes, ConversionError, Env, String, TryFromVal, TryIntoVal, Val,
 };
 
 #[cfg(not(target_family = "wasm"))]
@@ -168,8 +168,68 @@ macro_rules! impl_num_wrapping_val_type {
     };
 }
 
+macro_rules! impl_num_wrapping_val_type_ops {
+    ($wrapper:ident, $rem:ident) => {
+        impl_num_wrapping_val_type_ops!($wrapper, Add, add, AddAssign, add_assign, add);
+        impl_num_wrapping_val_type_ops!($wrapper, Sub, sub, SubAssign, sub_assign, sub);
+        impl_num_wrapping_val_type_ops!($wrapper, Mul, mul, MulAssign, mul_assign, mul);
+        impl_num_wrapping_val_type_ops!($wrapper, Div, div, DivAssign, div_assign, div);
+        impl_num_wrapping_val_type_ops!($wrapper, Rem, rem, RemAssign, rem_assign, $rem);
+    };
+    ($wrapper:ident, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $method:ident) => {
+        impl core::ops::$op<&$wrapper> for &$wrapper {
+            type Output = $wrapper;
+
+            fn $op_fn(self, rhs: &$wrapper) -> $wrapper {
+                $wrapper::$method(self, rhs)
+            }
+        }
+
+        impl core::ops::$op<$wrapper> for &$wrapper {
+            type Output = $wrapper;
+
+            fn $op_fn(self, rhs: $wrapper) -> $wrapper {
+                $wrapper::$method(self, &rhs)
+            }
+        }
+
+        impl core::ops::$op<&$wrapper> for $wrapper {
+            type Output = $wrapper;
+
+            fn $op_fn(self, rhs: &$wrapper) -> $wrapper {
+                $wrapper::$method(&self, rhs)
+            }
+        }
+
+        impl core::ops::$op<$wrapper> for $wrapper {
+            type Output = $wrapper;
+
+            fn $op_fn(self, rhs: $wrapper) -> $wrapper {
+                $wrapper::$method(&self, &rhs)
+            }
+        }
+
+        impl core::ops::$op_assign<&$wrapper> for $wrapper {
+            fn $op_assign_fn(&mut self, rhs: &$wrapper) {
+                *self = $wrapper::$method(self, rhs);
+            }
+        }
+
+        impl core::ops::$op_assign<$wrapper> for $wrapper {
+            fn $op_assign_fn(&mut self, rhs: $wrapper) {
+                *self = $wrapper::$method(self, &rhs);
+            }
+        }
+    };
+}
+
 /// U256 holds a 256-bit unsigned integer.
 ///
+/// The arithmetic operators `+`, `-`, `*`, `/` and `%` (and their assigning
+/// forms) are implemented for owned values and references, and are
+/// equivalent to the named methods. They panic on overflow, underflow, and
+/// division by zero.
+///
 /// ### Examples
 ///
 /// ```
@@ -179,6 +239,7 @@ macro_rules! impl_num_wrapping_val_type {
 /// let u1 = U256::from_u32(&env, 6);
 /// let u2 = U256::from_u32(&env, 3);
 /// assert_eq!(u1.add(&u2), U256::from_u32(&env, 9));
+/// assert_eq!(&u1 + &u2, U256::from_u32(&env, 9));
 /// ```
 #[derive(Clone)]
 pub struct U256 {
@@ -187,6 +248,7 @@ pub struct U256 {
 }
 
 impl_num_wrapping_val_type!(U256, U256Val, U256Small);
+impl_num_wrapping_val_type_ops!(U256, rem_euclid);
 
 impl U256 {
     pub fn from_u32(env: &Env, u: u32) -> Self {
@@ -314,10 +376,34 @@ impl U256 {
             val,
         }
     }
+
+    /// Parses a U256 from its base-10 representation.
+    ///
+    /// Returns an error if the string is empty, contains anything other than
+    /// the digits `0` to `9`, or holds a value larger than the maximum U256.
+    pub fn from_string(env: &Env, s: &String) -> Result<U256, ConversionError> {
+        let limbs = parse_decimal_limbs(s.contents().iter()).ok_or(ConversionError)?;
+        Ok(U256::from_parts(
+            env, limbs[3], limbs[2], limbs[1], limbs[0],
+        ))
+    }
+
+    /// Returns the base-10 representation of the U256.
+    pub fn to_string(&self) -> String {
+        let mut buf = [0u8; 78];
+        let start = format_decimal_limbs(limbs_from_be_bytes(&self.to_be_bytes()), &mut buf);
+        String::from_bytes(&self.env, &buf[start..])
+    }
 }
 
 /// I256 holds a 256-bit signed integer.
 ///
+/// The arithmetic operators `+`, `-`, `*`, `/` and `%` (and their assigning
+/// forms) are implemented for owned values and references. They panic on
+/// overflow and division by zero. As with the primitive integer types, `/`
+/// rounds towards zero and the result of `%` has the sign of the dividend,
+/// which differs from [`I256::rem_euclid`] for negative dividends.
+///
 /// ### Examples
 ///
 /// ```
@@ -328,6 +414,7 @@ impl U256 {
 /// let i1 = I256::from_i32(&env, -6);
 /// let i2 = I256::from_i32(&env, 3);
 /// assert_eq!(i1.add(&i2), I256::from_i32(&env, -3));
+/// assert_eq!(&i1 + &i2, I256::from_i32(&env, -3));
 /// ```
 #[derive(Clone)]
 pub struct I256 {
@@ -336,6 +423,7 @@ pub struct I256 {
 }
 
 impl_num_wrapping_val_type!(I256, I256Val, I256Small);
+impl_num_wrapping_val_type_ops!(I256, rem);
 
 impl I256 {
     pub fn from_i32(env: &Env, i: i32) -> Self {
@@ -448,6 +536,20 @@ impl I256 {
         }
     }
 
+    /// Remainder with the sign of the dividend, matching `%` on primitive
+    /// integers.
+    fn rem(&self, other: &I256) -> I256 {
+        let r = self.rem_euclid(other);
+        let zero = I256::from_i32(&self.env, 0);
+        if *self >= zero || r == zero {
+            r
+        } else if *other < zero {
+            r.add(other)
+        } else {
+            r.sub(other)
+        }
+    }
+
     pub fn pow(&self, pow: u32) -> I256 {
         let val = self.env.i256_pow(self.val, pow.into()).unwrap_infallible();
         I256 {
@@ -471,6 +573,121 @@ impl I256 {
             val,
         }
     }
+
+    /// Parses an I256 from its base-10 representation, with an optional
+    /// leading `-` for negative values.
+    ///
+    /// Returns an error if there are no digits, if anything other than the
+    /// digits `0` to `9` follows the sign, or if the value is outside the
+    /// range of I256.
+    pub fn from_string(env: &Env, s: &String) -> Result<I256, ConversionError> {
+        let contents = s.contents();
+        let negative = contents.first() == Some(b'-');
+        let digits = if negative {
+            contents.slice(1..)
+        } else {
+            contents
+        };
+        let magnitude = parse_decimal_limbs(digits.iter()).ok_or(ConversionError)?;
+        // The magnitude of I256::MIN is one larger than that of I256::MAX.
+        let limbs = if magnitude[3] < 1 << 63 {
+            if negative {
+                negate_limbs(magnitude)
+            } else {
+                magnitude
+            }
+        } else if negative && magnitude == [0, 0, 0, 1 << 63] {
+            magnitude
+        } else {
+            return Err(ConversionError);
+        };
+        Ok(I256::from_parts(
+            env,
+            limbs[3] as i64,
+            limbs[2],
+            limbs[1],
+            limbs[0],
+        ))
+    }
+
+    /// Returns the base-10 representation of the I256, with a leading `-` if
+    /// it is negative.
+    pub fn to_string(&self) -> String {
+        let limbs = limbs_from_be_bytes(&self.to_be_bytes());
+        let negative = limbs[3] >> 63 == 1;
+        // Negating I256::MIN leaves its bits unchanged, which read as an
+        // unsigned magnitude is the correct value.
+        let magnitude = if negative { negate_limbs(limbs) } else { limbs };
+        let mut buf = [0u8; 78];
+        let mut start = format_decimal_limbs(magnitude, &mut buf);
+        if negative {
+            start -= 1;
+            buf[start] = b'-';
+        }
+        String::from_bytes(&self.env, &buf[start..])
+    }
+}
+
+/// Parses base-10 digits into little-endian 64-bit limbs, returning None if
+/// there are no digits, a byte is not a digit, or the value overflows 256 bits.
+fn parse_decimal_limbs(digits: impl Iterator<Item = u8>) -> Option<[u64; 4]> {
+    let mut limbs = [0u64; 4];
+    let mut any = false;
+    for c in digits {
+        let digit = c.checked_sub(b'0').filter(|d| *d < 10)?;
+        let mut carry = digit as u128;
+        for limb in limbs.iter_mut() {
+            let v = (*limb as u128) * 10 + carry;
+            *limb = v as u64;
+            carry = v >> 64;
+        }
+        if carry != 0 {
+            return None;
+        }
+        any = true;
+    }
+    any.then_some(limbs)
+}
+
+/// Writes the base-10 digits of the little-endian limbs to the end of the
+/// buffer, returning the index of the first digit.
+fn format_decimal_limbs(mut limbs: [u64; 4], buf: &mut [u8]) -> usize {
+    let mut start = buf.len();
+    loop {
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let v = (rem << 64) | *limb as u128;
+            *limb = (v / 10) as u64;
+            rem = v % 10;
+        }
+        start -= 1;
+        buf[start] = b'0' + rem as u8;
+        if limbs == [0; 4] {
+            return start;
+        }
+    }
+}
+
+/// Splits 32 big-endian bytes into little-endian 64-bit limbs.
+fn limbs_from_be_bytes(bytes: &Bytes) -> [u64; 4] {
+    let be: [u8; 32] = bytes.try_into().unwrap_optimized();
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(be.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap_optimized());
+    }
+    limbs
+}
+
+/// Returns the two's complement negation of the little-endian limbs.
+fn negate_limbs(limbs: [u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut carry = true;
+    for (o, limb) in out.iter_mut().zip(limbs) {
+        let (v, c) = (!limb).overflowing_add(carry as u64);
+        *o = v;
+        carry = c;
+    }
+    out
 }
 
 #[doc = "Timepoint holds a 64-bit unsigned integer."]
@@ -656,4 +873,208 @@ mod test {
         let u4 = I256::from_i32(&env, 4);
         assert_eq!(u3.rem_euclid(&u4), I256::from_i32(&env, 1));
     }
+
+    #[test]
+    fn test_u256_ops() {
+        let env = Env::default();
+
+        let u1 = U256::from_u32(&env, 7);
+        let u2 = U256::from_u32(&env, 4);
+        assert_eq!(&u1 + &u2, u1.add(&u2));
+        assert_eq!(&u1 - &u2, u1.sub(&u2));
+        assert_eq!(&u1 * &u2, u1.mul(&u2));
+        assert_eq!(&u1 / &u2, u1.div(&u2));
+        assert_eq!(&u1 % &u2, u1.rem_euclid(&u2));
+        assert_eq!(u1.clone() + u2.clone(), U256::from_u32(&env, 11));
+        assert_eq!(u1.clone() - &u2, U256::from_u32(&env, 3));
+        assert_eq!(&u1 * u2.clone(), U256::from_u32(&env, 28));
+
+        let mut u = u1.clone();
+        u += &u2;
+        assert_eq!(u, U256::from_u32(&env, 11));
+        u -= u2.clone();
+        assert_eq!(u, u1);
+        u *= &u2;
+        assert_eq!(u, U256::from_u32(&env, 28));
+        u /= &u2;
+        assert_eq!(u, u1);
+        u %= &u2;
+        assert_eq!(u, U256::from_u32(&env, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_u256_add_overflow() {
+        let env = Env::default();
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        let _ = max + U256::from_u32(&env, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_u256_sub_underflow() {
+        let env = Env::default();
+        let _ = U256::from_u32(&env, 1) - U256::from_u32(&env, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_u256_div_by_zero() {
+        let env = Env::default();
+        let _ = U256::from_u32(&env, 1) / U256::from_u32(&env, 0);
+    }
+
+    #[test]
+    fn test_i256_ops() {
+        let env = Env::default();
+
+        let i1 = I256::from_i32(&env, -7);
+        let i2 = I256::from_i32(&env, 4);
+        assert_eq!(&i1 + &i2, i1.add(&i2));
+        assert_eq!(&i1 - &i2, i1.sub(&i2));
+        assert_eq!(&i1 * &i2, i1.mul(&i2));
+        assert_eq!(&i1 / &i2, i1.div(&i2));
+        assert_eq!(i1.clone() + i2.clone(), I256::from_i32(&env, -3));
+
+        let mut i = i1.clone();
+        i += &i2;
+        assert_eq!(i, I256::from_i32(&env, -3));
+        i -= i2.clone();
+        assert_eq!(i, i1);
+        i *= &i2;
+        assert_eq!(i, I256::from_i32(&env, -28));
+        i /= &i2;
+        assert_eq!(i, i1);
+        i %= &i2;
+        assert_eq!(i, I256::from_i32(&env, -3));
+    }
+
+    #[test]
+    fn test_i256_rem_matches_primitive() {
+        let env = Env::default();
+
+        for (a, b) in [(7, 4), (-7, 4), (7, -4), (-7, -4), (8, 4), (-8, 4), (0, -3)] {
+            let ia = I256::from_i32(&env, a);
+            let ib = I256::from_i32(&env, b);
+            assert_eq!(&ia % &ib, I256::from_i32(&env, a % b), "{a} % {b}");
+            assert_eq!(&ia / &ib, I256::from_i32(&env, a / b), "{a} / {b}");
+            assert_eq!(
+                ia.rem_euclid(&ib),
+                I256::from_i32(&env, a.rem_euclid(b)),
+                "{a} rem_euclid {b}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_i256_add_overflow() {
+        let env = Env::default();
+        let max = I256::from_parts(&env, i64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        let _ = max + I256::from_i32(&env, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_i256_mul_overflow() {
+        let env = Env::default();
+        let min = I256::from_parts(&env, i64::MIN, 0, 0, 0);
+        let _ = min * I256::from_i32(&env, -1);
+    }
+
+    #[test]
+    fn test_u256_string_roundtrip() {
+        let env = Env::default();
+
+        let zero = U256::from_string(&env, &String::from_str(&env, "0")).unwrap();
+        assert_eq!(zero, U256::from_u32(&env, 0));
+        assert_eq!(zero.to_string(), String::from_str(&env, "0"));
+
+        let n = U256::from_string(&env, &String::from_str(&env, "00012345")).unwrap();
+        assert_eq!(n, U256::from_u32(&env, 12345));
+        assert_eq!(n.to_string(), String::from_str(&env, "12345"));
+
+        let max_str = String::from_str(
+            &env,
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        );
+        let max = U256::from_string(&env, &max_str).unwrap();
+        assert_eq!(
+            max,
+            U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX)
+        );
+        assert_eq!(max.to_string(), max_str);
+    }
+
+    #[test]
+    fn test_u256_from_string_errors() {
+        let env = Env::default();
+        for s in [
+            "",
+            "-1",
+            "+1",
+            "12a3",
+            " 1",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        ] {
+            assert_eq!(
+                U256::from_string(&env, &String::from_str(&env, s)),
+                Err(ConversionError),
+                "{s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_i256_string_roundtrip() {
+        let env = Env::default();
+
+        let zero = I256::from_string(&env, &String::from_str(&env, "-0")).unwrap();
+        assert_eq!(zero, I256::from_i32(&env, 0));
+        assert_eq!(zero.to_string(), String::from_str(&env, "0"));
+
+        let n = I256::from_string(&env, &String::from_str(&env, "-98765")).unwrap();
+        assert_eq!(n, I256::from_i32(&env, -98765));
+        assert_eq!(n.to_string(), String::from_str(&env, "-98765"));
+
+        let max_str = String::from_str(
+            &env,
+            "57896044618658097711785492504343953926634992332820282019728792003956564819967",
+        );
+        let max = I256::from_string(&env, &max_str).unwrap();
+        assert_eq!(
+            max,
+            I256::from_parts(&env, i64::MAX, u64::MAX, u64::MAX, u64::MAX)
+        );
+        assert_eq!(max.to_string(), max_str);
+
+        let min_str = String::from_str(
+            &env,
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+        );
+        let min = I256::from_string(&env, &min_str).unwrap();
+        assert_eq!(min, I256::from_parts(&env, i64::MIN, 0, 0, 0));
+        assert_eq!(min.to_string(), min_str);
+    }
+
+    #[test]
+    fn test_i256_from_string_errors() {
+        let env = Env::default();
+        for s in [
+            "",
+            "-",
+            "--1",
+            "1-",
+            "+1",
+            "1.5",
+            "57896044618658097711785492504343953926634992332820282019728792003956564819968",
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819969",
+        ] {
+            assert_eq!(
+                I256::from_string(&env, &String::from_str(&env, s)),
+                Err(ConversionError),
+                "{s:?}"
+            );
+        }
+    }
 }
@@ -283,6 +283,34 @@ impl Prng {
         T::gen_len(self, len)
     }
 
+    /// Returns random [`Bytes`] of the given length.
+    ///
+    /// Equivalent to `gen_len::<Bytes>(len)`.
+    ///
+    /// # Warning
+    ///
+    /// **The PRNG is unsuitable for generating secrets or use in applications with
+    /// low risk tolerance, see the module-level comment.**
+    pub fn gen_bytes(&self, len: u32) -> Bytes {
+        self.gen_len(len)
+    }
+
+    /// Returns random [`BytesN`] of length `N`.
+    ///
+    /// Equivalent to `gen::<BytesN<N>>()`.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is greater than u32::MAX.
+    ///
+    /// # Warning
+    ///
+    /// **The PRNG is unsuitable for generating secrets or use in applications with
+    /// low risk tolerance, see the module-level comment.**
+    pub fn gen_bytesn<const N: usize>(&self) -> BytesN<N> {
+        self.gen()
+    }
+
     /// Returns a random value of the given type in the range specified.
     ///
     /// # Panics
@@ -4,9 +4,26 @@ use core::fmt::Debug;
 use crate::{
     env::internal::{self, StorageType, Val},
     unwrap::{UnwrapInfallible, UnwrapOptimized},
-    Env, IntoVal, TryFromVal,
+    Env, IntoVal, TryFromVal, Vec,
 };
 
+mod access_control;
+mod idempotency;
+mod list;
+mod ownable;
+mod queue;
+mod schema_version;
+mod supply;
+mod symbol_registry;
+pub use access_control::AccessControl;
+pub use idempotency::Idempotency;
+pub use list::List;
+pub use ownable::Ownable;
+pub use queue::Queue;
+pub use schema_version::SchemaVersion;
+pub use supply::Supply;
+pub use symbol_registry::SymbolRegistry;
+
 /// Storage stores and retrieves data for the currently executing contract.
 ///
 /// All data stored can only be queried and modified by the contract that stores
@@ -85,6 +102,12 @@ impl Storage {
     ///
     /// This should be used for data that requires persistency, such as token
     /// balances, user properties etc.
+    ///
+    /// In tests, advancing the ledger sequence past an entry's live-until
+    /// ledger archives it, and any access to an archived entry panics. This
+    /// mirrors the network, where a transaction cannot touch an archived
+    /// entry until it has been restored, so contracts never observe an
+    /// expired persistent entry as missing.
     pub fn persistent(&self) -> Persistent {
         assert_in_contract!(self.env);
 
@@ -104,6 +127,12 @@ impl Storage {
     ///
     /// This should be used for data that needs to only exist for a limited
     /// period of time, such as oracle data, claimable balances, offer, etc.
+    ///
+    /// In tests, advancing the ledger sequence past an entry's live-until
+    /// ledger, e.g. with
+    /// [`Ledger::set_sequence_number`][crate::testutils::Ledger::set_sequence_number],
+    /// makes the entry behave as if it was removed: `has` returns `false` and
+    /// `get` returns `None`.
     pub fn temporary(&self) -> Temporary {
         assert_in_contract!(self.env);
 
@@ -254,6 +283,40 @@ impl Storage {
         Ok(val)
     }
 
+    /// Sets the value stored against a key to `new` only if the currently
+    /// stored value equals `expected`, where None means that no value is
+    /// stored.
+    ///
+    /// Returns true if the value was set, or false if the stored value did not
+    /// match and storage was left unchanged. The comparison and the write
+    /// happen within the one invocation, so no other invocation can change
+    /// the value in between.
+    ///
+    /// ### Panics
+    ///
+    /// When the stored value cannot be converted into the type V.
+    pub(crate) fn compare_and_set<K, V>(
+        &self,
+        key: &K,
+        storage_type: StorageType,
+        expected: Option<V>,
+        new: V,
+    ) -> bool
+    where
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+        V: Eq,
+    {
+        let key = key.into_val(&self.env);
+        let current: Option<V> = self.get(&key, storage_type);
+        if current != expected {
+            return false;
+        }
+        self.set(&key, &new, storage_type);
+        true
+    }
+
     pub(crate) fn extend_ttl<K>(
         &self,
         key: &K,
@@ -287,6 +350,12 @@ impl Storage {
         internal::Env::del_contract_data(env, key.into_val(env), storage_type).unwrap_infallible();
     }
 
+    pub(crate) fn remove_many<K>(&self, keys: &Vec<K>, storage_type: StorageType) {
+        for key in keys.to_vals() {
+            self.remove(&key, storage_type);
+        }
+    }
+
     fn has_internal(&self, key: Val, storage_type: StorageType) -> bool {
         internal::Env::has_contract_data(&self.env, key, storage_type)
             .unwrap_infallible()
@@ -364,6 +433,24 @@ impl Persistent {
         self.storage.try_update(key, StorageType::Persistent, f)
     }
 
+    /// Sets the persistent value stored against a key to `new` only if the
+    /// stored value equals `expected`, where None means that no value is
+    /// stored. Returns true if the value was set.
+    ///
+    /// ### Panics
+    ///
+    /// When the stored value cannot be converted into the type V.
+    pub fn compare_and_set<K, V>(&self, key: &K, expected: Option<V>, new: V) -> bool
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+        V: Eq,
+    {
+        self.storage
+            .compare_and_set(key, StorageType::Persistent, expected, new)
+    }
+
     /// Extend the TTL of the data under the key.
     ///
     /// Extends the TTL only if the TTL for the provided data is below `threshold` ledgers.
@@ -378,6 +465,22 @@ impl Persistent {
             .extend_ttl(key, StorageType::Persistent, threshold, extend_to)
     }
 
+    /// Returns the value stored against the key, and if there is one, extends
+    /// its TTL as [`extend_ttl`][Self::extend_ttl] does, for keeping
+    /// frequently read data alive.
+    ///
+    /// Returns None, without extending anything, if no value is stored.
+    pub fn get_and_extend<K, V>(&self, key: &K, threshold: u32, extend_to: u32) -> Option<V>
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+    {
+        let value = self.get(key)?;
+        self.extend_ttl(key, threshold, extend_to);
+        Some(value)
+    }
+
     #[inline(always)]
     pub fn remove<K>(&self, key: &K)
     where
@@ -385,6 +488,23 @@ impl Persistent {
     {
         self.storage.remove(key, StorageType::Persistent)
     }
+
+    /// Removes the values stored against each of the keys, skipping keys
+    /// that have no value stored.
+    pub fn remove_many<K>(&self, keys: &Vec<K>) {
+        self.storage.remove_many(keys, StorageType::Persistent)
+    }
+
+    /// Returns a read-only view of persistent storage.
+    ///
+    /// The view can only read data, and can be passed to functions that
+    /// should not be able to write to storage.
+    pub fn as_readonly(&self) -> ReadOnlyStorage {
+        ReadOnlyStorage {
+            storage: self.storage.clone(),
+            storage_type: StorageType::Persistent,
+        }
+    }
 }
 
 pub struct Temporary {
@@ -453,6 +573,24 @@ impl Temporary {
         self.storage.try_update(key, StorageType::Temporary, f)
     }
 
+    /// Sets the temporary value stored against a key to `new` only if the
+    /// stored value equals `expected`, where None means that no value is
+    /// stored. Returns true if the value was set.
+    ///
+    /// ### Panics
+    ///
+    /// When the stored value cannot be converted into the type V.
+    pub fn compare_and_set<K, V>(&self, key: &K, expected: Option<V>, new: V) -> bool
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+        V: Eq,
+    {
+        self.storage
+            .compare_and_set(key, StorageType::Temporary, expected, new)
+    }
+
     /// Extend the TTL of the data under the key.
     ///
     /// Extends the TTL only if the TTL for the provided data is below `threshold` ledgers.
@@ -474,6 +612,23 @@ impl Temporary {
     {
         self.storage.remove(key, StorageType::Temporary)
     }
+
+    /// Removes the values stored against each of the keys, skipping keys
+    /// that have no value stored.
+    pub fn remove_many<K>(&self, keys: &Vec<K>) {
+        self.storage.remove_many(keys, StorageType::Temporary)
+    }
+
+    /// Returns a read-only view of temporary storage.
+    ///
+    /// The view can only read data, and can be passed to functions that
+    /// should not be able to write to storage.
+    pub fn as_readonly(&self) -> ReadOnlyStorage {
+        ReadOnlyStorage {
+            storage: self.storage.clone(),
+            storage_type: StorageType::Temporary,
+        }
+    }
 }
 
 pub struct Instance {
@@ -542,6 +697,24 @@ impl Instance {
         self.storage.try_update(key, StorageType::Instance, f)
     }
 
+    /// Sets the instance value stored against a key to `new` only if the
+    /// stored value equals `expected`, where None means that no value is
+    /// stored. Returns true if the value was set.
+    ///
+    /// ### Panics
+    ///
+    /// When the stored value cannot be converted into the type V.
+    pub fn compare_and_set<K, V>(&self, key: &K, expected: Option<V>, new: V) -> bool
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+        V: Eq,
+    {
+        self.storage
+            .compare_and_set(key, StorageType::Instance, expected, new)
+    }
+
     #[inline(always)]
     pub fn remove<K>(&self, key: &K)
     where
@@ -566,6 +739,100 @@ impl Instance {
         )
         .unwrap_infallible();
     }
+
+    /// Returns the value stored against the key, and if there is one, extends
+    /// the TTL of the instance and code as [`extend_ttl`][Self::extend_ttl]
+    /// does.
+    ///
+    /// Instance storage shares a single TTL with the contract instance, so
+    /// the extension keeps every instance value alive, not only the one read.
+    /// Returns None, without extending anything, if no value is stored.
+    pub fn get_and_extend<K, V>(&self, key: &K, threshold: u32, extend_to: u32) -> Option<V>
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+    {
+        let value = self.get(key)?;
+        self.extend_ttl(threshold, extend_to);
+        Some(value)
+    }
+}
+
+/// A read-only view of [Persistent] or [Temporary] storage.
+///
+/// Obtained with [`Persistent::as_readonly`] or [`Temporary::as_readonly`].
+/// The view exposes only the functions that read data, so a function that
+/// receives a `ReadOnlyStorage` cannot write to, extend, or remove entries.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::ReadOnlyStorage, symbol_short, Env};
+///
+/// fn balance(storage: &ReadOnlyStorage) -> i128 {
+///     storage.get(&symbol_short!("balance")).unwrap_or(0)
+/// }
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let persistent = env.storage().persistent();
+/// persistent.set(&symbol_short!("balance"), &10i128);
+/// assert_eq!(balance(&persistent.as_readonly()), 10);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct ReadOnlyStorage {
+    storage: Storage,
+    storage_type: StorageType,
+}
+
+impl Debug for ReadOnlyStorage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReadOnlyStorage")
+    }
+}
+
+impl ReadOnlyStorage {
+    /// Returns if there is a value stored for the given key in the storage
+    /// the view was created from.
+    pub fn has<K>(&self, key: &K) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        self.storage.has(key, self.storage_type)
+    }
+
+    /// Returns the value stored for the given key in the storage the view was
+    /// created from, or `None` when the value is missing.
+    ///
+    /// ### Panics
+    ///
+    /// When the stored value cannot be converted into the type V.
+    pub fn get<K, V>(&self, key: &K) -> Option<V>
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+    {
+        self.storage.get(key, self.storage_type)
+    }
 }
 
 #[cfg(any(test, feature = "testutils"))]
@@ -574,6 +841,50 @@ mod testutils {
     use super::*;
     use crate::{testutils, xdr, Map, TryIntoVal};
 
+    extern crate std;
+    use std::rc::Rc;
+
+    impl testutils::storage::Storage for Storage {
+        fn dump(&self) -> testutils::storage::StorageDump {
+            testutils::storage::StorageDump {
+                instance: self.instance().all(),
+                persistent: all(&self.env, xdr::ContractDataDurability::Persistent),
+                temporary: all(&self.env, xdr::ContractDataDurability::Temporary),
+            }
+        }
+
+        fn modified_keys(&self) -> Vec<Val> {
+            let env = &self.env;
+            let footprint = env
+                .host()
+                .with_mut_storage(|s| Ok(s.footprint.0.clone()))
+                .unwrap();
+            let address: xdr::ScAddress = env.current_contract_address().try_into().unwrap();
+            let mut keys = Vec::<Val>::new(env);
+            for (k, access) in footprint {
+                if !matches!(access, internal::storage::AccessType::ReadWrite) {
+                    continue;
+                }
+                let xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+                    ref contract,
+                    ref key,
+                    ..
+                }) = *k
+                else {
+                    continue;
+                };
+                if contract != &address || key == &xdr::ScVal::LedgerKeyContractInstance {
+                    continue;
+                }
+                let Ok(key) = Val::try_from_val(env, key) else {
+                    continue;
+                };
+                keys.push_back(key);
+            }
+            keys
+        }
+    }
+
     impl testutils::storage::Instance for Instance {
         fn all(&self) -> Map<Val, Val> {
             let env = &self.storage.env;
@@ -643,6 +954,43 @@ mod testutils {
                 .checked_sub(env.ledger().sequence())
                 .unwrap()
         }
+
+        fn simulate_archive<K: IntoVal<Env, Val>>(&self, key: &K) {
+            let env = &self.storage.env;
+            let live_until = env
+                .ledger()
+                .sequence()
+                .checked_sub(1)
+                .expect("cannot archive an entry at ledger sequence 0");
+            set_persistent_live_until(env, key.into_val(env), live_until);
+        }
+
+        fn simulate_restore<K: IntoVal<Env, Val>>(&self, key: &K) {
+            let env = &self.storage.env;
+            let min_ttl = env
+                .host()
+                .with_ledger_info(|li| Ok(li.min_persistent_entry_ttl))
+                .unwrap();
+            let live_until = env.ledger().sequence() + min_ttl - 1;
+            set_persistent_live_until(env, key.into_val(env), live_until);
+        }
+
+        fn is_archived<K: IntoVal<Env, Val>>(&self, key: &K) -> bool {
+            let env = &self.storage.env;
+            let (_, live_until) = persistent_entry(env, key.into_val(env));
+            live_until < env.ledger().sequence()
+        }
+    }
+
+    impl testutils::storage::ReadOnlyStorage for ReadOnlyStorage {
+        fn get_ttl<K: IntoVal<Env, Val>>(&self, key: &K) -> u32 {
+            let env = &self.storage.env;
+            env.host()
+                .get_contract_data_live_until_ledger(key.into_val(env), self.storage_type)
+                .unwrap()
+                .checked_sub(env.ledger().sequence())
+                .unwrap()
+        }
     }
 
     impl testutils::storage::Temporary for Temporary {
@@ -660,8 +1008,46 @@ mod testutils {
         }
     }
 
+    fn persistent_ledger_key(env: &Env, key: Val) -> Rc<xdr::LedgerKey> {
+        Rc::new(xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+            contract: env.current_contract_address().try_into().unwrap(),
+            key: xdr::ScVal::try_from_val(env, &key).unwrap(),
+            durability: xdr::ContractDataDurability::Persistent,
+        }))
+    }
+
+    /// Returns the persistent entry for the key and the ledger it lives until.
+    fn persistent_entry(env: &Env, key: Val) -> (Rc<xdr::LedgerEntry>, u32) {
+        let ledger_key = persistent_ledger_key(env, key);
+        let storage = env.host().with_mut_storage(|s| Ok(s.map.clone())).unwrap();
+        for (k, v) in storage {
+            if k == ledger_key {
+                if let Some((entry, Some(live_until))) = v {
+                    return (entry, live_until);
+                }
+            }
+        }
+        panic!("no persistent entry for the key");
+    }
+
+    fn set_persistent_live_until(env: &Env, key: Val, live_until: u32) {
+        let (entry, _) = persistent_entry(env, key);
+        let ledger_key = persistent_ledger_key(env, key);
+        let budget = env.host().budget_cloned();
+        env.host()
+            .with_mut_storage(|s| {
+                s.map =
+                    s.map
+                        .clone()
+                        .insert(ledger_key, Some((entry, Some(live_until))), &budget)?;
+                Ok(())
+            })
+            .unwrap();
+    }
+
     fn all(env: &Env, d: xdr::ContractDataDurability) -> Map<Val, Val> {
         let storage = env.host().with_mut_storage(|s| Ok(s.map.clone())).unwrap();
+        let address: xdr::ScAddress = env.current_contract_address().try_into().unwrap();
         let mut map = Map::<Val, Val>::new(env);
         for entry in storage {
             let (_, Some((v, _))) = entry else {
@@ -670,6 +1056,7 @@ mod testutils {
             let xdr::LedgerEntry {
                 data:
                     xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+                        ref contract,
                         ref key,
                         ref val,
                         durability,
@@ -680,7 +1067,7 @@ mod testutils {
             else {
                 continue;
             };
-            if d != durability {
+            if d != durability || contract != &address {
                 continue;
             }
             let Ok(key) = Val::try_from_val(env, key) else {
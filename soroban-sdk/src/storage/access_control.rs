@@ -0,0 +1,171 @@
+use crate::{Address, Env, Symbol};
+
+const ROLE: Symbol = soroban_sdk_macros::internal_symbol_short!("Role");
+const ROLE_ADMIN: Symbol = soroban_sdk_macros::internal_symbol_short!("RoleAdmin");
+
+/// Role-based access control in the current contract's persistent storage,
+/// for restricting functions to accounts that hold a role such as a minter or
+/// pauser.
+///
+/// Roles are named by symbols. Every role has an admin role, whose holders can
+/// grant and revoke the role. A role's admin role is
+/// [`AccessControl::DEFAULT_ADMIN_ROLE`] unless changed with
+/// [`AccessControl::set_role_admin`], and the default admin role is its own
+/// admin, so roles can be managed hierarchically with the default admin at
+/// the top. Initial roles are set up with
+/// [`AccessControl::grant_role_unchecked`], typically in the contract's
+/// constructor.
+///
+/// ### Storage keys
+///
+/// Each role held by an account is stored under the key
+/// `("Role", role, account)`, and a role's admin role, if it is not the
+/// default, is stored under the key `("RoleAdmin", role)`, where `"Role"` and
+/// `"RoleAdmin"` are symbols. These keys are reserved: a contract using
+/// access control must not store its own data under a tuple or enum key whose
+/// first element is either symbol, or it may overwrite, or be overwritten by,
+/// the roles.
+///
+/// ### TTL
+///
+/// A role entry is written when the role is granted and not touched again
+/// until it is revoked, so its TTL runs from the grant, and checking a role
+/// does not extend it. Once an entry is archived, every check of that role for
+/// that account fails, including through [`AccessControl::has_role`], until
+/// the entry is restored. An archived role is never treated as revoked, so
+/// archival makes a contract unusable for the role holder rather than open to
+/// others. Contracts should extend the role entries of active accounts, along
+/// with any `("RoleAdmin", role)` entries, with
+/// [`Persistent::extend_ttl`][crate::storage::Persistent::extend_ttl].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::AccessControl, symbol_short, Address, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env, admin: Address, minter: Address) {
+/// let access = AccessControl::new(&env);
+/// access.grant_role_unchecked(&AccessControl::DEFAULT_ADMIN_ROLE, &admin);
+/// access.grant_role(&admin, &symbol_short!("minter"), &minter);
+/// assert!(access.has_role(&symbol_short!("minter"), &minter));
+/// access.require_role(&symbol_short!("minter"), &minter);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::Address as _;
+/// #     let env = Env::default();
+/// #     env.mock_all_auths();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f(&Address::generate(&env), &Address::generate(&env));
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct AccessControl {
+    env: Env,
+}
+
+impl AccessControl {
+    /// The role that is the admin of every role whose admin has not been
+    /// changed, including itself.
+    pub const DEFAULT_ADMIN_ROLE: Symbol = soroban_sdk_macros::internal_symbol_short!("admin");
+
+    /// Returns the access control for the current contract.
+    pub fn new(env: &Env) -> AccessControl {
+        AccessControl { env: env.clone() }
+    }
+
+    /// Returns true if the account holds the role.
+    pub fn has_role(&self, role: &Symbol, account: &Address) -> bool {
+        self.env
+            .storage()
+            .persistent()
+            .has(&(ROLE, role.clone(), account.clone()))
+    }
+
+    /// Ensures that the account has authorized the invocation and holds the
+    /// role.
+    ///
+    /// ### Panics
+    ///
+    /// If the account has not authorized the invocation or does not hold the
+    /// role.
+    pub fn require_role(&self, role: &Symbol, account: &Address) {
+        account.require_auth();
+        if !self.has_role(role, account) {
+            sdk_panic!("account does not hold the role");
+        }
+    }
+
+    /// Returns the role whose holders can grant and revoke the role.
+    pub fn role_admin(&self, role: &Symbol) -> Symbol {
+        self.env
+            .storage()
+            .persistent()
+            .get(&(ROLE_ADMIN, role.clone()))
+            .unwrap_or(Self::DEFAULT_ADMIN_ROLE)
+    }
+
+    /// Grants the role to the account on behalf of the caller.
+    ///
+    /// ### Panics
+    ///
+    /// If the caller has not authorized the invocation or does not hold the
+    /// admin role of the role.
+    pub fn grant_role(&self, caller: &Address, role: &Symbol, account: &Address) {
+        self.require_role(&self.role_admin(role), caller);
+        self.grant_role_unchecked(role, account);
+    }
+
+    /// Grants the role to the account without any authorization, for setting
+    /// up the initial roles of a contract, such as in its constructor.
+    pub fn grant_role_unchecked(&self, role: &Symbol, account: &Address) {
+        self.env
+            .storage()
+            .persistent()
+            .set(&(ROLE, role.clone(), account.clone()), &());
+    }
+
+    /// Revokes the role from the account on behalf of the caller. Revoking a
+    /// role the account does not hold has no effect.
+    ///
+    /// ### Panics
+    ///
+    /// If the caller has not authorized the invocation or does not hold the
+    /// admin role of the role.
+    pub fn revoke_role(&self, caller: &Address, role: &Symbol, account: &Address) {
+        self.require_role(&self.role_admin(role), caller);
+        self.env
+            .storage()
+            .persistent()
+            .remove(&(ROLE, role.clone(), account.clone()));
+    }
+
+    /// Sets the role whose holders can grant and revoke the role, on behalf of
+    /// the caller.
+    ///
+    /// ### Panics
+    ///
+    /// If the caller has not authorized the invocation or does not hold the
+    /// current admin role of the role.
+    pub fn set_role_admin(&self, caller: &Address, role: &Symbol, admin_role: &Symbol) {
+        self.require_role(&self.role_admin(role), caller);
+        let storage = self.env.storage().persistent();
+        let key = (ROLE_ADMIN, role.clone());
+        if admin_role == &Self::DEFAULT_ADMIN_ROLE {
+            storage.remove(&key);
+        } else {
+            storage.set(&key, admin_role);
+        }
+    }
+}
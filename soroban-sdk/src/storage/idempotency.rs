@@ -0,0 +1,89 @@
+use crate::{BytesN, Env, Symbol};
+
+const IDEMPOTENCY: Symbol = soroban_sdk_macros::internal_symbol_short!("Idempotcy");
+
+/// Guards against processing the same operation twice, such as a relayed call
+/// that is submitted more than once.
+///
+/// Each operation is identified by a 32-byte key, typically a hash of the
+/// operation's contents or a nonce chosen by the caller. The first time a key
+/// is guarded it is recorded, and guarding the same key again fails until the
+/// record expires.
+///
+/// Keys are recorded in the current contract's temporary storage under keys of
+/// the form `("Idempotcy", key)`, so each guarded key costs the fees of writing
+/// one small temporary entry and its rent for the TTL. A record lives for the
+/// TTL given to [`Idempotency::new`], counted in ledgers from the ledger it was
+/// recorded in, or for the network's minimum temporary entry TTL if that is
+/// longer. A TTL beyond the network's maximum entry TTL is clamped to the
+/// maximum, so the record may expire sooner than requested. Once the record
+/// expires the key can be guarded again, so the TTL
+/// should be longer than the window in which an operation may be repeated.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::Idempotency, BytesN, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let idempotency = Idempotency::new(&env, 1000);
+/// let key = BytesN::from_array(&env, &[1; 32]);
+/// assert!(idempotency.guard(&key));
+/// assert!(!idempotency.guard(&key));
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct Idempotency {
+    env: Env,
+    ttl: u32,
+}
+
+impl Idempotency {
+    /// Returns a guard that records keys for the TTL, in ledgers.
+    pub fn new(env: &Env, ttl: u32) -> Idempotency {
+        Idempotency {
+            env: env.clone(),
+            ttl,
+        }
+    }
+
+    /// Returns true and records the key if it has not been recorded, or false
+    /// if it has been recorded and the record has not expired.
+    pub fn guard(&self, key: &BytesN<32>) -> bool {
+        let key = (IDEMPOTENCY, key.clone());
+        let storage = self.env.storage().temporary();
+        if storage.has(&key) {
+            return false;
+        }
+        storage.set(&key, &());
+        let live_for = self.ttl.min(self.env.storage().max_ttl());
+        storage.extend_ttl(&key, live_for, live_for);
+        true
+    }
+
+    /// Returns true if the key has been recorded and the record has not
+    /// expired.
+    pub fn is_recorded(&self, key: &BytesN<32>) -> bool {
+        self.env
+            .storage()
+            .temporary()
+            .has(&(IDEMPOTENCY, key.clone()))
+    }
+}
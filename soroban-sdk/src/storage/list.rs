@@ -0,0 +1,123 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{unwrap::UnwrapOptimized, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+const LIST: Symbol = soroban_sdk_macros::internal_symbol_short!("List");
+
+/// An append-only list of items in the current contract's persistent storage
+/// that can be read a page at a time, for exposing long lists such as orders
+/// or positions to off-chain indexers.
+///
+/// Storing a list as a single [`Vec`] means every read loads the whole list,
+/// so the cost of reading a page grows with the length of the list. Instead,
+/// each list is identified by a name, its length is stored under the key
+/// `("List", name)`, and every item is stored in its own entry under the key
+/// `("List", name, index)`. Reading a page only loads the entries in the page.
+///
+/// ### TTL
+///
+/// Items are written once and never rewritten, so each item keeps the TTL it
+/// was pushed with, and on a long-lived list the oldest items are archived
+/// first. A page that covers an archived item cannot be read until the item
+/// is restored, while pages of newer items are unaffected. The length entry is
+/// read by every operation, so it is the one entry that has to stay live for
+/// the list to be usable at all. Contracts that keep a list for a long time
+/// should extend the length entry and the items that are still read with
+/// [`Persistent::extend_ttl`][crate::storage::Persistent::extend_ttl].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::List, symbol_short, vec, Env};
+/// # use soroban_sdk::contract;
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # let env = Env::default();
+/// # let contract_id = env.register(Contract, ());
+/// # env.as_contract(&contract_id, || {
+///
+/// let orders = List::<u32>::new(&env, symbol_short!("orders"));
+/// for order in 10..15 {
+///     orders.push_back(&order);
+/// }
+/// assert_eq!(orders.page(0, 2), vec![&env, 10, 11]);
+/// assert_eq!(orders.page(4, 2), vec![&env, 14]);
+/// assert!(orders.page(5, 2).is_empty());
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct List<T> {
+    env: Env,
+    name: Symbol,
+    _t: PhantomData<T>,
+}
+
+impl<T> List<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    T::Error: Debug,
+{
+    /// Returns the list with the name.
+    pub fn new(env: &Env, name: Symbol) -> List<T> {
+        List {
+            env: env.clone(),
+            name,
+            _t: PhantomData,
+        }
+    }
+
+    /// Returns the number of items in the list.
+    pub fn len(&self) -> u32 {
+        self.env
+            .storage()
+            .persistent()
+            .get(&(LIST, self.name.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the list has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the item to the end of the list.
+    pub fn push_back(&self, item: &T) {
+        let len = self.len();
+        let storage = self.env.storage().persistent();
+        storage.set(&(LIST, self.name.clone(), len), item);
+        storage.set(&(LIST, self.name.clone()), &(len + 1));
+    }
+
+    /// Returns the item at the index, or None if the index is out of bounds.
+    pub fn get(&self, i: u32) -> Option<T> {
+        if i >= self.len() {
+            return None;
+        }
+        Some(self.get_unchecked(i))
+    }
+
+    /// Returns up to `limit` items starting at `offset`, in the order they
+    /// were added.
+    ///
+    /// The page is clamped to the length of the list, so the last page may
+    /// have fewer than `limit` items and a page starting at or past the end of
+    /// the list is empty.
+    pub fn page(&self, offset: u32, limit: u32) -> Vec<T> {
+        let end = self.len().min(offset.saturating_add(limit));
+        let mut items = Vec::new(&self.env);
+        for i in offset..end {
+            items.push_back(self.get_unchecked(i));
+        }
+        items
+    }
+
+    fn get_unchecked(&self, i: u32) -> T {
+        self.env
+            .storage()
+            .persistent()
+            .get(&(LIST, self.name.clone(), i))
+            .unwrap_optimized()
+    }
+}
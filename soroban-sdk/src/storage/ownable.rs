@@ -0,0 +1,128 @@
+use crate::{Address, Env, Symbol};
+
+const OWNER: Symbol = soroban_sdk_macros::internal_symbol_short!("Owner");
+const PENDING_OWNER: Symbol = soroban_sdk_macros::internal_symbol_short!("PendOwner");
+
+/// Single-owner access control in the current contract's instance storage,
+/// with ownership transferred in two steps.
+///
+/// The owner proposes a new owner with [`Ownable::transfer_ownership`], and
+/// the transfer only completes when the proposed owner calls
+/// [`Ownable::accept_ownership`]. Ownership therefore cannot be handed to an
+/// address nobody controls, such as a mistyped one, because such an address
+/// can never accept. Until the transfer is accepted the current owner remains
+/// the owner and can propose a different owner instead. The initial owner is
+/// set with [`Ownable::set_owner_unchecked`], typically in the contract's
+/// constructor.
+///
+/// The owner is stored under the key `("Owner",)`, and the proposed owner
+/// under the key `("PendOwner",)`. Instance storage is used so that the owner
+/// lives as long as the contract instance.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::Ownable, Address, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env, owner: Address, new_owner: Address) {
+/// let ownable = Ownable::new(&env);
+/// ownable.set_owner_unchecked(&owner);
+/// ownable.transfer_ownership(&new_owner);
+/// assert_eq!(ownable.owner(), Some(owner));
+/// ownable.accept_ownership();
+/// assert_eq!(ownable.owner(), Some(new_owner));
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::Address as _;
+/// #     let env = Env::default();
+/// #     env.mock_all_auths();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f(&Address::generate(&env), &Address::generate(&env));
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct Ownable {
+    env: Env,
+}
+
+impl Ownable {
+    /// Returns the ownership of the current contract.
+    pub fn new(env: &Env) -> Ownable {
+        Ownable { env: env.clone() }
+    }
+
+    /// Returns the owner, or None if no owner has been set.
+    pub fn owner(&self) -> Option<Address> {
+        self.env.storage().instance().get(&(OWNER,))
+    }
+
+    /// Returns the proposed owner of a transfer that has not been accepted,
+    /// or None if no transfer is pending.
+    pub fn pending_owner(&self) -> Option<Address> {
+        self.env.storage().instance().get(&(PENDING_OWNER,))
+    }
+
+    /// Ensures that the owner has authorized the invocation.
+    ///
+    /// ### Panics
+    ///
+    /// If no owner has been set, or if the owner has not authorized the
+    /// invocation.
+    pub fn require_owner(&self) {
+        let Some(owner) = self.owner() else {
+            sdk_panic!("contract has no owner");
+        };
+        owner.require_auth();
+    }
+
+    /// Sets the owner without any authorization, for setting up the initial
+    /// owner of a contract, such as in its constructor. Cancels any pending
+    /// transfer.
+    pub fn set_owner_unchecked(&self, owner: &Address) {
+        let instance = self.env.storage().instance();
+        instance.set(&(OWNER,), owner);
+        instance.remove(&(PENDING_OWNER,));
+    }
+
+    /// Proposes the new owner, who becomes the owner once they call
+    /// [`accept_ownership`][Ownable::accept_ownership]. Replaces any transfer
+    /// that is already pending.
+    ///
+    /// ### Panics
+    ///
+    /// If no owner has been set, or if the owner has not authorized the
+    /// invocation.
+    pub fn transfer_ownership(&self, new_owner: &Address) {
+        self.require_owner();
+        self.env
+            .storage()
+            .instance()
+            .set(&(PENDING_OWNER,), new_owner);
+    }
+
+    /// Completes the pending transfer, making the proposed owner the owner.
+    ///
+    /// ### Panics
+    ///
+    /// If no transfer is pending, or if the proposed owner has not authorized
+    /// the invocation.
+    pub fn accept_ownership(&self) {
+        let Some(new_owner) = self.pending_owner() else {
+            sdk_panic!("no ownership transfer is pending");
+        };
+        new_owner.require_auth();
+        self.set_owner_unchecked(&new_owner);
+    }
+}
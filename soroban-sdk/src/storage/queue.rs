@@ -0,0 +1,176 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{unwrap::UnwrapOptimized, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+const QUEUE: Symbol = soroban_sdk_macros::internal_symbol_short!("Queue");
+
+/// A first-in first-out queue of items in the current contract's persistent
+/// storage, for processing a bounded number of items per invocation.
+///
+/// Each queue is identified by a name, so a contract can keep several queues
+/// side by side. The queue's head and tail indices are stored under the key
+/// `("Queue", name)` and every item is stored in its own entry under the key
+/// `("Queue", name, index)`. Consuming an item removes its entry, and the index
+/// entry is removed once the queue is empty, so a drained queue leaves nothing
+/// behind in storage.
+///
+/// ### TTL
+///
+/// Writing an entry does not extend its TTL, so the index entry keeps the TTL
+/// it was created with for as long as the queue is non-empty, and each item
+/// keeps the TTL it was pushed with. A queue that is drained more slowly than
+/// the network's minimum persistent TTL therefore has entries archived from
+/// under it: an archived item stops the queue from being popped past it, and
+/// an archived index entry stops every operation on the queue, until the
+/// entry is restored. Contracts that let items wait should call
+/// [`Queue::extend_ttl`] regularly, such as on every push, keeping in mind
+/// that its cost grows with the length of the queue.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::Queue, symbol_short, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let queue = Queue::<u32>::new(&env, symbol_short!("jobs"));
+/// queue.push_back(&1);
+/// queue.push_back(&2);
+/// queue.push_back(&3);
+/// assert_eq!(queue.drain(2).len(), 2);
+/// assert_eq!(queue.pop_front(), Some(3));
+/// assert!(queue.is_empty());
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct Queue<T> {
+    env: Env,
+    name: Symbol,
+    _t: PhantomData<T>,
+}
+
+impl<T> Queue<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    T::Error: Debug,
+{
+    /// Returns the queue with the name.
+    pub fn new(env: &Env, name: Symbol) -> Queue<T> {
+        Queue {
+            env: env.clone(),
+            name,
+            _t: PhantomData,
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    ///
+    /// ### Panics
+    ///
+    /// If the queue holds more than [`u32::MAX`] items.
+    pub fn len(&self) -> u32 {
+        let (head, tail) = self.bounds();
+        u32::try_from(tail - head).unwrap_optimized()
+    }
+
+    /// Returns true if the queue has no items.
+    pub fn is_empty(&self) -> bool {
+        let (head, tail) = self.bounds();
+        head == tail
+    }
+
+    /// Adds the item to the back of the queue.
+    pub fn push_back(&self, item: &T) {
+        let (head, tail) = self.bounds();
+        self.env
+            .storage()
+            .persistent()
+            .set(&(QUEUE, self.name.clone(), tail), item);
+        self.set_bounds(head, tail + 1);
+    }
+
+    /// Removes and returns the item at the front of the queue, or None if the
+    /// queue is empty.
+    pub fn pop_front(&self) -> Option<T> {
+        let (head, tail) = self.bounds();
+        if head == tail {
+            return None;
+        }
+        let item = self.take(head);
+        self.set_bounds(head + 1, tail);
+        Some(item)
+    }
+
+    /// Removes and returns up to `max` items from the front of the queue, in
+    /// the order they were added.
+    pub fn drain(&self, max: u32) -> Vec<T> {
+        let (head, tail) = self.bounds();
+        let end = tail.min(head + max as u64);
+        let mut items = Vec::new(&self.env);
+        for i in head..end {
+            items.push_back(self.take(i));
+        }
+        if end != head {
+            self.set_bounds(end, tail);
+        }
+        items
+    }
+
+    /// Extend the TTL of the queue's index entry and every item in the queue.
+    ///
+    /// See [`Persistent::extend_ttl`][crate::storage::Persistent::extend_ttl]
+    /// for the meaning of `threshold` and `extend_to`.
+    pub fn extend_ttl(&self, threshold: u32, extend_to: u32) {
+        let (head, tail) = self.bounds();
+        if head == tail {
+            return;
+        }
+        let storage = self.env.storage().persistent();
+        storage.extend_ttl(&(QUEUE, self.name.clone()), threshold, extend_to);
+        for i in head..tail {
+            storage.extend_ttl(&(QUEUE, self.name.clone(), i), threshold, extend_to);
+        }
+    }
+
+    fn bounds(&self) -> (u64, u64) {
+        self.env
+            .storage()
+            .persistent()
+            .get(&(QUEUE, self.name.clone()))
+            .unwrap_or((0, 0))
+    }
+
+    fn set_bounds(&self, head: u64, tail: u64) {
+        let storage = self.env.storage().persistent();
+        let key = (QUEUE, self.name.clone());
+        if head == tail {
+            storage.remove(&key);
+        } else {
+            storage.set(&key, &(head, tail));
+        }
+    }
+
+    fn take(&self, i: u64) -> T {
+        let storage = self.env.storage().persistent();
+        let key = (QUEUE, self.name.clone(), i);
+        let item = storage.get(&key).unwrap_optimized();
+        storage.remove(&key);
+        item
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Env, Symbol};
+
+const SCHEMA_VERSION: Symbol = soroban_sdk_macros::internal_symbol_short!("SchemaVer");
+
+/// The version of the layout of the data the current contract stores, kept in
+/// the contract's instance storage.
+///
+/// Code that reads stored data calls [`SchemaVersion::require_version`] with
+/// the layout version it was written for, so that it never interprets data
+/// stored in another layout. Code that changes the layout, such as a
+/// migration run after an upgrade, calls [`SchemaVersion::set_version`] once
+/// the data has been rewritten.
+///
+/// A contract that has never set a version is at version 0.
+///
+/// The version is stored under the key `("SchemaVer",)`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::SchemaVersion, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let schema = SchemaVersion::new(&env);
+/// assert_eq!(schema.version(), 0);
+/// schema.set_version(2);
+/// schema.require_version(2);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct SchemaVersion {
+    env: Env,
+}
+
+impl SchemaVersion {
+    /// Returns the schema version of the current contract.
+    pub fn new(env: &Env) -> SchemaVersion {
+        SchemaVersion { env: env.clone() }
+    }
+
+    /// Returns the stored version, or 0 if no version has been set.
+    pub fn version(&self) -> u32 {
+        self.env
+            .storage()
+            .instance()
+            .get(&(SCHEMA_VERSION,))
+            .unwrap_or(0)
+    }
+
+    /// Stores the version.
+    pub fn set_version(&self, version: u32) {
+        self.env
+            .storage()
+            .instance()
+            .set(&(SCHEMA_VERSION,), &version);
+    }
+
+    /// Ensures that the stored version is the expected version.
+    ///
+    /// ### Panics
+    ///
+    /// If the stored version is not the expected version.
+    pub fn require_version(&self, expected: u32) {
+        if self.version() != expected {
+            sdk_panic!("storage schema version does not match the expected version");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Env, Symbol};
+
+const SUPPLY: Symbol = soroban_sdk_macros::internal_symbol_short!("Supply");
+
+/// The total supply of a token-like contract, kept in the current contract's
+/// instance storage, for keeping the supply consistent with every mint and
+/// burn.
+///
+/// The supply starts at zero, and every change to it is checked, so it can
+/// never become negative or overflow. The supply is stored under the key
+/// `("Supply",)`. Instance storage is used because the supply is read and
+/// written by most invocations of a token, and it lives as long as the
+/// contract instance.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::Supply, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let supply = Supply::new(&env);
+/// supply.mint(100);
+/// supply.burn(30);
+/// assert_eq!(supply.total(), 70);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct Supply {
+    env: Env,
+}
+
+impl Supply {
+    /// Returns the supply of the current contract.
+    pub fn new(env: &Env) -> Supply {
+        Supply { env: env.clone() }
+    }
+
+    /// Returns the total supply.
+    pub fn total(&self) -> i128 {
+        self.env.storage().instance().get(&(SUPPLY,)).unwrap_or(0)
+    }
+
+    /// Increases the total supply by the amount, returning the new total.
+    ///
+    /// ### Panics
+    ///
+    /// If the amount is negative, or if the total would exceed `i128::MAX`.
+    pub fn mint(&self, amount: i128) -> i128 {
+        self.env.require_nonnegative(amount);
+        let Some(total) = self.total().checked_add(amount) else {
+            sdk_panic!("mint overflows the total supply");
+        };
+        self.set_total(total);
+        total
+    }
+
+    /// Decreases the total supply by the amount, returning the new total.
+    ///
+    /// ### Panics
+    ///
+    /// If the amount is negative, or if it is greater than the total supply.
+    pub fn burn(&self, amount: i128) -> i128 {
+        self.env.require_nonnegative(amount);
+        let total = self.total();
+        if amount > total {
+            sdk_panic!("burn exceeds the total supply");
+        }
+        let total = total - amount;
+        self.set_total(total);
+        total
+    }
+
+    fn set_total(&self, total: i128) {
+        self.env.storage().instance().set(&(SUPPLY,), &total);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Env, Symbol};
+
+const SYMBOL_REGISTRY: Symbol = soroban_sdk_macros::internal_symbol_short!("SymReg");
+
+/// A registry in the current contract's persistent storage that interns
+/// symbols as small sequential ids, for storing a repeated symbol once and
+/// referring to it by id elsewhere.
+///
+/// Each registry is identified by a name, and ids are assigned per registry
+/// starting at zero in the order symbols are first seen. An id is never
+/// reassigned, so ids can be stored and looked up later.
+///
+/// The number of ids assigned is stored under the key `("SymReg", name)`, and
+/// each symbol is stored in both directions, under `("SymReg", name, symbol)`
+/// for its id and under `("SymReg", name, id)` for the symbol.
+///
+/// ### TTL
+///
+/// A symbol's two entries are written when its id is assigned and never
+/// rewritten, so they keep that TTL however often the symbol is looked up.
+/// Because looking up an archived entry fails instead of finding nothing,
+/// archival never leads to a symbol being assigned a second id. It does mean
+/// that [`SymbolRegistry::id_of`] fails for a symbol whose entry is archived,
+/// and so does assigning any new id once the count entry is archived. The two
+/// directions of a mapping are archived independently, so a contract that
+/// relies on both should extend both entries, and the count entry, with
+/// [`Persistent::extend_ttl`][crate::storage::Persistent::extend_ttl].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::SymbolRegistry, symbol_short, Env};
+/// # use soroban_sdk::contract;
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # let env = Env::default();
+/// # let contract_id = env.register(Contract, ());
+/// # env.as_contract(&contract_id, || {
+///
+/// let assets = SymbolRegistry::new(&env, symbol_short!("assets"));
+/// assert_eq!(assets.id_of(&symbol_short!("USDC")), 0);
+/// assert_eq!(assets.id_of(&symbol_short!("EURC")), 1);
+/// assert_eq!(assets.id_of(&symbol_short!("USDC")), 0);
+/// assert_eq!(assets.symbol_of(1), Some(symbol_short!("EURC")));
+/// assert_eq!(assets.symbol_of(2), None);
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct SymbolRegistry {
+    env: Env,
+    name: Symbol,
+}
+
+impl SymbolRegistry {
+    /// Returns the registry with the name.
+    pub fn new(env: &Env, name: Symbol) -> SymbolRegistry {
+        SymbolRegistry {
+            env: env.clone(),
+            name,
+        }
+    }
+
+    /// Returns the number of symbols that have been assigned ids.
+    pub fn len(&self) -> u32 {
+        self.env
+            .storage()
+            .persistent()
+            .get(&(SYMBOL_REGISTRY, self.name.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Returns true if no symbols have been assigned ids.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the id of the symbol, assigning it the next id if the symbol
+    /// has not been seen before.
+    pub fn id_of(&self, symbol: &Symbol) -> u32 {
+        let storage = self.env.storage().persistent();
+        let key = (SYMBOL_REGISTRY, self.name.clone(), symbol.clone());
+        if let Some(id) = storage.get(&key) {
+            return id;
+        }
+        let id = self.len();
+        storage.set(&key, &id);
+        storage.set(&(SYMBOL_REGISTRY, self.name.clone(), id), symbol);
+        storage.set(&(SYMBOL_REGISTRY, self.name.clone()), &(id + 1));
+        id
+    }
+
+    /// Returns the symbol with the id, or None if no symbol has been assigned
+    /// the id.
+    pub fn symbol_of(&self, id: u32) -> Option<Symbol> {
+        self.env
+            .storage()
+            .persistent()
+            .get(&(SYMBOL_REGISTRY, self.name.clone(), id))
+    }
+}
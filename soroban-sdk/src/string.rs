@@ -1,3 +1,6 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{cmp::Ordering, convert::Infallible, fmt::Debug};
 
 use super::{
@@ -5,9 +8,16 @@ use super::{
     ConversionError, Env, TryFromVal, TryIntoVal, Val,
 };
 
-use crate::unwrap::{UnwrapInfallible, UnwrapOptimized};
 #[cfg(doc)]
-use crate::{storage::Storage, Map, Vec};
+use crate::{storage::Storage, Map};
+use crate::{
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+    Bytes, Vec,
+};
+
+/// The largest string, in bytes, that the SDK copies into a buffer in the
+/// contract to operate on its contents.
+pub(crate) const MAX_BUFFERED_LEN: usize = 4096;
 
 #[cfg(not(target_family = "wasm"))]
 use super::xdr::{ScString, ScVal};
@@ -261,6 +271,116 @@ impl String {
         env.string_copy_to_slice(self.to_object(), Val::U32_ZERO, slice)
             .unwrap_optimized();
     }
+
+    /// Returns the substrings of the string separated by `sep`, in order.
+    ///
+    /// Behaves like [`str::split`] with a string pattern. Separators at the
+    /// start or end of the string, and adjacent separators, produce empty
+    /// substrings. A string that does not contain the separator, including the
+    /// empty string, produces a single substring that is the whole string.
+    ///
+    /// The string is copied into a buffer and searched in the contract, so the
+    /// cost grows with the product of the lengths of the string and the
+    /// separator.
+    ///
+    /// ### Panics
+    ///
+    /// If the separator is empty, or if the string is longer than 4096 bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{vec, Env, String};
+    ///
+    /// let env = Env::default();
+    /// let s = String::from_str(&env, ",a,,b");
+    /// let parts = s.split(&String::from_str(&env, ","));
+    /// assert_eq!(
+    ///     parts,
+    ///     vec![
+    ///         &env,
+    ///         String::from_str(&env, ""),
+    ///         String::from_str(&env, "a"),
+    ///         String::from_str(&env, ""),
+    ///         String::from_str(&env, "b"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn split(&self, sep: &String) -> Vec<String> {
+        if sep.is_empty() {
+            sdk_panic!("String::split with empty separator")
+        }
+        if self.len() as usize > MAX_BUFFERED_LEN {
+            sdk_panic!("String::split with string too long")
+        }
+        let env = self.env();
+        let mut parts = Vec::new(env);
+        if sep.len() > self.len() {
+            parts.push_back(self.clone());
+            return parts;
+        }
+        let mut s_buf = [0u8; MAX_BUFFERED_LEN];
+        let mut sep_buf = [0u8; MAX_BUFFERED_LEN];
+        let s = &mut s_buf[..self.len() as usize];
+        let sep_bytes = &mut sep_buf[..sep.len() as usize];
+        self.copy_into_slice(s);
+        sep.copy_into_slice(sep_bytes);
+        let mut start = 0;
+        let mut i = 0;
+        while i + sep_bytes.len() <= s.len() {
+            if s[i..i + sep_bytes.len()] == *sep_bytes {
+                parts.push_back(String::from_bytes(env, &s[start..i]));
+                i += sep_bytes.len();
+                start = i;
+            } else {
+                i += 1;
+            }
+        }
+        parts.push_back(String::from_bytes(env, &s[start..]));
+        parts
+    }
+
+    /// Returns the contents of the string as [Bytes].
+    pub(crate) fn contents(&self) -> Bytes {
+        // Copy in chunks to bound the size of the buffer.
+        let mut buf = [0u8; 64];
+        let mut contents = Bytes::new(self.env());
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(buf.len() as u32);
+            let chunk = &mut buf[..n as usize];
+            self.env()
+                .string_copy_to_slice(self.to_object(), i.into(), chunk)
+                .unwrap_optimized();
+            contents.extend_from_slice(chunk);
+            i += n;
+        }
+        contents
+    }
+
+    /// Creates a string with the contents.
+    ///
+    /// ### Panics
+    ///
+    /// If the contents are longer than [`MAX_BUFFERED_LEN`] bytes.
+    pub(crate) fn from_contents(env: &Env, contents: &Bytes) -> String {
+        let buffer = contents.to_buffer::<MAX_BUFFERED_LEN>();
+        String::from_bytes(env, buffer.as_slice())
+    }
+
+    /// Copy the [String] into a Rust alloc String.
+    ///
+    /// Allocates using the built-in allocator. A [String] is not required to
+    /// hold valid UTF-8, so any invalid sequences are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_alloc_string(&self) -> alloc::string::String {
+        let mut vec = alloc::vec::from_elem(0u8, self.len() as usize);
+        self.copy_into_slice(&mut vec);
+        alloc::string::String::from_utf8_lossy(&vec).into_owned()
+    }
 }
 
 #[cfg(test)]
@@ -289,6 +409,71 @@ mod test {
         assert_eq!(msg, &out)
     }
 
+    #[test]
+    fn string_split() {
+        let env = Env::default();
+        let split = |s: &str, sep: &str| -> Vec<String> {
+            String::from_str(&env, s).split(&String::from_str(&env, sep))
+        };
+        let strings = |parts: &[&str]| -> Vec<String> {
+            let mut v = Vec::new(&env);
+            for p in parts {
+                v.push_back(String::from_str(&env, p));
+            }
+            v
+        };
+
+        // No separator.
+        assert_eq!(split("abc", ","), strings(&["abc"]));
+        // Multiple separators.
+        assert_eq!(split("a,b,c", ","), strings(&["a", "b", "c"]));
+        assert_eq!(split("a,,b", ","), strings(&["a", "", "b"]));
+        assert_eq!(split("a::b::c", "::"), strings(&["a", "b", "c"]));
+        assert_eq!(split("a:::b", "::"), strings(&["a", ":b"]));
+        // Leading and trailing separators.
+        assert_eq!(split(",a,", ","), strings(&["", "a", ""]));
+        assert_eq!(split(",", ","), strings(&["", ""]));
+        // Empty input.
+        assert_eq!(split("", ","), strings(&[""]));
+        // Separator longer than the string.
+        assert_eq!(split("a", "abc"), strings(&["a"]));
+        // Multi-byte characters and contents that need padding.
+        assert_eq!(split("añb→c", "→"), strings(&["añb", "c"]));
+        assert_eq!(split("abcde/fghij", "/"), strings(&["abcde", "fghij"]));
+
+        // Matches str::split.
+        for (s, sep) in [("x-y--z-", "-"), ("--", "-"), ("hello world", "o")] {
+            let expected: std::vec::Vec<&str> = s.split(sep).collect();
+            assert_eq!(split(s, sep), strings(&expected));
+        }
+    }
+
+    #[test]
+    fn string_split_long() {
+        let env = Env::default();
+        let raw = "ab,".repeat(MAX_BUFFERED_LEN / 3);
+        let parts = String::from_str(&env, &raw).split(&String::from_str(&env, ","));
+        assert_eq!(parts.len() as usize, MAX_BUFFERED_LEN / 3 + 1);
+        assert_eq!(parts.get(0), Some(String::from_str(&env, "ab")));
+        assert_eq!(parts.last(), Some(String::from_str(&env, "")));
+    }
+
+    #[test]
+    #[should_panic(expected = "String::split with string too long")]
+    fn string_split_too_long() {
+        let env = Env::default();
+        let raw = "a".repeat(MAX_BUFFERED_LEN + 1);
+        let _ = String::from_str(&env, &raw).split(&String::from_str(&env, ","));
+    }
+
+    #[test]
+    #[should_panic(expected = "String::split with empty separator")]
+    fn string_split_empty_separator() {
+        let env = Env::default();
+        let s = String::from_str(&env, "abc");
+        let _ = s.split(&String::from_str(&env, ""));
+    }
+
     #[test]
     #[should_panic]
     fn string_to_short_slice() {
@@ -26,6 +26,13 @@ use crate::{
 /// While Symbols up to 32 characters long are allowed, Symbols that are 9
 /// characters long or shorter are more efficient at runtime and also can be
 /// computed at compile time.
+///
+/// Symbols are ordered by comparing their characters one by one by ASCII
+/// code, the same as comparing them as strings, regardless of their length
+/// or whether they are stored as small or object symbols. Digits sort before
+/// uppercase letters, uppercase letters before `_`, and `_` before lowercase
+/// letters. The order is the same inside and outside of contracts, so the
+/// keys of a [`Map`][crate::Map] of Symbols are iterated in this order.
 #[derive(Clone)]
 pub struct Symbol {
     env: MaybeEnv,
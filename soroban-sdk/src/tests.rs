@@ -1,18 +1,29 @@
 #![cfg(test)]
 
+mod account_balance;
 mod address;
+mod assert_contract_err;
+mod assert_deterministic;
 mod auth;
+mod bounded_iter;
+mod budget_limits;
 mod bytes_alloc_vec;
 mod bytes_buffer;
 mod contract_add_i32;
 mod contract_assert;
+mod contract_callback;
 mod contract_custom_account_impl;
 mod contract_docs;
 mod contract_duration;
+mod contract_error_codes;
 mod contract_fn;
+mod contract_interface_version;
 mod contract_invoke;
 mod contract_invoke_arg_count;
+mod contract_invoke_strict;
 mod contract_overlapping_type_fn_names;
+mod contract_reentry;
+mod contract_return_tuple;
 mod contract_snapshot;
 mod contract_store;
 mod contract_timepoint;
@@ -22,20 +33,49 @@ mod contract_udt_enum_option;
 mod contract_udt_option;
 mod contract_udt_struct;
 mod contract_udt_struct_tuple;
+mod contractimpl_auto_extend_instance;
 mod contractimport;
 mod contractimport_with_error;
 mod cost_estimate;
 mod crypto_bls12_381;
 mod crypto_ed25519;
 mod crypto_keccak256;
+mod crypto_merkle;
 mod crypto_secp256k1;
 mod crypto_secp256r1;
 mod crypto_sha256;
+mod deployer;
 mod env;
+mod events_assert;
+mod events_order;
+mod fault_injection;
+mod host_trace;
+mod invoke_view;
+mod log_fields;
+mod logs_rendered;
 mod max_ttl;
 mod prng;
 mod proptest_scval_cmp;
 mod proptest_val_cmp;
+mod require_nonnegative;
+mod serialized_size;
+mod storage_access_control;
+mod storage_compare_and_set;
+mod storage_idempotency;
+mod storage_key;
+mod storage_list;
+mod storage_of;
+mod storage_ownable;
+mod storage_queue;
+mod storage_schema_version;
+mod storage_split;
+mod storage_supply;
+mod storage_symbol_registry;
 mod storage_testutils;
+mod string_alloc_string;
+mod symbol_ord;
+mod token_all_balances;
+mod token_allowance;
 mod token_client;
 mod token_spec;
+mod try_as;
@@ -0,0 +1,72 @@
+use crate::{
+    testutils::Address as _,
+    token::TokenClient,
+    xdr::{self, Limits, WriteXdr},
+    Address, Bytes, Env, TryIntoVal,
+};
+
+fn account(e: &Env, key: u8) -> Address {
+    xdr::ScAddress::Account(xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(
+        xdr::Uint256([key; 32]),
+    )))
+    .try_into_val(e)
+    .unwrap()
+}
+
+fn native_asset_contract(e: &Env) -> Address {
+    let asset = xdr::Asset::Native.to_xdr(Limits::none()).unwrap();
+    e.deployer()
+        .with_stellar_asset(Bytes::from_slice(e, &asset))
+        .deploy()
+}
+
+#[test]
+fn test_set_and_read_balance() {
+    let e = Env::default();
+    let a = account(&e, 1);
+
+    e.set_account_balance(&a, 100);
+    assert_eq!(e.account_balance(&a), 100);
+
+    e.set_account_balance(&a, 0);
+    assert_eq!(e.account_balance(&a), 0);
+}
+
+#[test]
+fn test_balance_changes_with_native_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let a = account(&e, 1);
+    let b = account(&e, 2);
+    e.set_account_balance(&a, 10_000_000_000);
+    e.set_account_balance(&b, 0);
+
+    let native = TokenClient::new(&e, &native_asset_contract(&e));
+    assert_eq!(native.balance(&a), 10_000_000_000);
+
+    native.transfer(&a, &b, &2_500_000_000);
+
+    assert_eq!(e.account_balance(&a), 7_500_000_000);
+    assert_eq!(e.account_balance(&b), 2_500_000_000);
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn test_balance_of_missing_account() {
+    let e = Env::default();
+    e.account_balance(&account(&e, 1));
+}
+
+#[test]
+#[should_panic(expected = "is not an account address")]
+fn test_set_balance_of_contract() {
+    let e = Env::default();
+    e.set_account_balance(&Address::generate(&e), 100);
+}
+
+#[test]
+#[should_panic(expected = "account balance -1 is out of range")]
+fn test_set_negative_balance() {
+    let e = Env::default();
+    e.set_account_balance(&account(&e, 1), -1);
+}
@@ -1,4 +1,4 @@
-use crate::{Address, Bytes, Env, String, TryIntoVal};
+use crate::{Address, Bytes, BytesN, Env, String, TryIntoVal};
 
 #[test]
 fn test_account_address_str_conversions() {
@@ -55,3 +55,20 @@ fn test_contract_address_conversions() {
     let address = Address::from_string(&strkey);
     assert_eq!(address.to_string(), strkey);
 }
+
+#[test]
+fn test_contract_id() {
+    let env = Env::default();
+
+    let address = Address::from_contract_id(&env, [7; 32]);
+    assert_eq!(
+        address.contract_id_bytes(),
+        Some(BytesN::from_array(&env, &[7; 32]))
+    );
+
+    let address = Address::from_str(
+        &env,
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+    );
+    assert_eq!(address.contract_id_bytes(), None);
+}
@@ -0,0 +1,57 @@
+use crate::{self as soroban_sdk, testutils::assert_contract_err};
+use soroban_sdk::{contract, contracterror, contractimpl, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Unauthorized = 1,
+    InsufficientBalance = 2,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn withdraw(_env: Env, amount: u32) -> Result<u32, Error> {
+        match amount {
+            0 => Err(Error::Unauthorized),
+            1..=100 => Ok(amount),
+            _ => Err(Error::InsufficientBalance),
+        }
+    }
+}
+
+#[test]
+fn test_matching_error() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_contract_err(client.try_withdraw(&0), Error::Unauthorized);
+    assert_contract_err(client.try_withdraw(&101), Error::InsufficientBalance);
+}
+
+#[test]
+#[should_panic(
+    expected = "expected the call to fail with Unauthorized, but it failed with InsufficientBalance"
+)]
+fn test_wrong_error() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_contract_err(client.try_withdraw(&101), Error::Unauthorized);
+}
+
+#[test]
+#[should_panic(
+    expected = "expected the call to fail with Unauthorized, but it succeeded with Ok(5)"
+)]
+fn test_unexpected_success() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_contract_err(client.try_withdraw(&5), Error::Unauthorized);
+}
@@ -0,0 +1,84 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events as _},
+    Address, Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn deposit(env: Env, from: Address, amount: i128) -> i128 {
+        let balance: i128 = env.storage().persistent().get(&from).unwrap_or(0) + amount;
+        env.storage().persistent().set(&from, &balance);
+        env.events()
+            .publish((symbol_short!("deposit"), from), amount);
+        balance
+    }
+
+    pub fn roll(env: Env) -> u64 {
+        env.prng().gen_range(0..=u64::MAX)
+    }
+
+    pub fn store_roll(env: Env) {
+        let roll: u64 = env.prng().gen_range(0..=u64::MAX);
+        env.storage().persistent().set(&1, &roll);
+    }
+}
+
+#[test]
+fn test_deterministic() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let from = Address::generate(&env);
+    client.deposit(&from, &10);
+
+    let balance = env.assert_deterministic(|_| client.deposit(&from, &5));
+    assert_eq!(balance, 15);
+    // The state of only one run is kept.
+    assert_eq!(client.deposit(&from, &0), 15);
+
+    // Addresses generated inside the function are the same in both runs.
+    let balance = env.assert_deterministic(|env| client.deposit(&Address::generate(env), &1));
+    assert_eq!(balance, 1);
+}
+
+#[test]
+fn test_deterministic_events() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let from = Address::generate(&env);
+    // The second run sees only the events it published itself.
+    let events = env.assert_deterministic(|env| {
+        client.deposit(&from, &5);
+        env.events().all()
+    });
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "function is not deterministic: first run returned")]
+fn test_unseeded_prng_output() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    env.assert_deterministic(|_| client.roll());
+}
+
+#[test]
+#[should_panic(
+    expected = "function is not deterministic: runs left the ledger in different states"
+)]
+fn test_unseeded_prng_state() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    env.assert_deterministic(|_| client.store_roll());
+}
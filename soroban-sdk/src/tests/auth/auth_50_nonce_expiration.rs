@@ -0,0 +1,100 @@
+//! Demonstrates that the nonce and signature expiration of a mocked auth are
+//! enforced.
+
+use crate as soroban_sdk;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn add(a: Address, x: i32, y: i32) -> i32 {
+        a.require_auth();
+        x + y
+    }
+}
+
+#[test]
+fn test_expiration() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(100);
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let a = Address::generate(&e);
+    let auth = MockAuth {
+        address: &a,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "add",
+            args: (&a, 10, 11).into_val(&e),
+            sub_invokes: &[],
+        },
+    };
+
+    // An expiration ledger that has already passed is rejected.
+    e.mock_auth_entries(&[auth.with_signature_expiration_ledger(99)]);
+    assert!(client.try_add(&a, &10, &11).is_err());
+
+    // The current ledger is still in range.
+    e.mock_auth_entries(&[auth.with_signature_expiration_ledger(100)]);
+    assert_eq!(client.add(&a, &10, &11), 21);
+}
+
+#[test]
+fn test_nonce_reuse() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let a = Address::generate(&e);
+    let auth = MockAuth {
+        address: &a,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "add",
+            args: (&a, 10, 11).into_val(&e),
+            sub_invokes: &[],
+        },
+    };
+
+    e.mock_auth_entries(&[auth.with_nonce(1_000_000)]);
+    assert_eq!(client.add(&a, &10, &11), 21);
+
+    // The nonce has been consumed by the first invocation.
+    e.mock_auth_entries(&[auth.with_nonce(1_000_000)]);
+    assert!(client.try_add(&a, &10, &11).is_err());
+
+    // A different nonce is accepted, and so is a generated one.
+    e.mock_auth_entries(&[auth.with_nonce(1_000_001)]);
+    assert_eq!(client.add(&a, &10, &11), 21);
+    client.mock_auths(&[auth.clone()]).add(&a, &10, &11);
+}
+
+#[test]
+fn test_nonce_and_expiration() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(100);
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let a = Address::generate(&e);
+    let auth = MockAuth {
+        address: &a,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "add",
+            args: (&a, 10, 11).into_val(&e),
+            sub_invokes: &[],
+        },
+    };
+
+    e.mock_auth_entries(&[auth.with_nonce(7).with_signature_expiration_ledger(100)]);
+    assert_eq!(client.add(&a, &10, &11), 21);
+}
@@ -0,0 +1,72 @@
+//! Demonstrates caller-based access control. A contract that directly invokes
+//! another contract is implicitly authorized, so the caller can pass its own
+//! address and the callee can require auth from it.
+
+use crate as soroban_sdk;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+#[contract]
+pub struct Guarded;
+
+#[contractimpl]
+impl Guarded {
+    pub fn __constructor(e: Env, owner: Address) {
+        e.storage().instance().set(&(), &owner);
+    }
+
+    pub fn poke(e: Env, caller: Address) -> u32 {
+        caller.require_auth();
+        let owner: Address = e.storage().instance().get(&()).unwrap();
+        if caller != owner {
+            panic!("caller is not the owner");
+        }
+        1
+    }
+}
+
+#[contract]
+pub struct Proxy;
+
+#[contractimpl]
+impl Proxy {
+    pub fn poke(e: Env, guarded: Address) -> u32 {
+        GuardedClient::new(&e, &guarded).poke(&e.current_contract_address())
+    }
+
+    pub fn poke_as(e: Env, guarded: Address, caller: Address) -> u32 {
+        GuardedClient::new(&e, &guarded).poke(&caller)
+    }
+}
+
+#[test]
+fn test_direct_caller_is_authorized() {
+    let e = Env::default();
+    let proxy_id = e.register(Proxy, ());
+    let guarded_id = e.register(Guarded, (&proxy_id,));
+
+    let c = ProxyClient::new(&e, &proxy_id).poke(&guarded_id);
+    assert_eq!(c, 1);
+    assert!(e.auths().is_empty());
+}
+
+#[test]
+fn test_top_level_cannot_claim_contract_caller() {
+    let e = Env::default();
+    let proxy_id = e.register(Proxy, ());
+    let guarded_id = e.register(Guarded, (&proxy_id,));
+
+    let r = GuardedClient::new(&e, &guarded_id).try_poke(&proxy_id);
+    assert!(r.is_err());
+}
+
+#[test]
+fn test_direct_caller_cannot_claim_other_address() {
+    let e = Env::default();
+    let proxy_id = e.register(Proxy, ());
+    let other = Address::generate(&e);
+    let guarded_id = e.register(Guarded, (&other,));
+
+    let r = ProxyClient::new(&e, &proxy_id).try_poke_as(&guarded_id, &other);
+    assert!(r.is_err());
+}
@@ -0,0 +1,98 @@
+use crate as soroban_sdk;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Auths},
+    Address, Env, IntoVal, Symbol,
+};
+
+#[contract]
+pub struct ContractA;
+
+#[contractimpl]
+impl ContractA {
+    pub fn fna(e: Env, contract: Address, a: Address, b: Option<Address>) {
+        a.require_auth();
+        ContractBClient::new(&e, &contract).fnb(&a, &b);
+    }
+}
+
+#[contract]
+pub struct ContractB;
+
+#[contractimpl]
+impl ContractB {
+    pub fn fnb(a: Address, b: Option<Address>) {
+        a.require_auth();
+        if let Some(b) = b {
+            b.require_auth();
+        }
+    }
+}
+
+#[test]
+fn test() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_a_id = e.register(ContractA, ());
+    let contract_b_id = e.register(ContractB, ());
+    let client = ContractAClient::new(&e, &contract_a_id);
+
+    let a = Address::generate(&e);
+
+    client.fna(&contract_b_id, &a, &None);
+
+    e.auths().assert_eq(&[(
+        a.clone(),
+        AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((
+                contract_a_id.clone(),
+                Symbol::new(&e, "fna"),
+                (&contract_b_id, &a, None::<Address>).into_val(&e),
+            )),
+            sub_invocations: std::vec![AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_b_id.clone(),
+                    Symbol::new(&e, "fnb"),
+                    (&a, None::<Address>).into_val(&e),
+                )),
+                sub_invocations: std::vec![],
+            }],
+        },
+    )]);
+}
+
+#[test]
+#[should_panic = "unexpected"]
+fn test_extra_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_a_id = e.register(ContractA, ());
+    let contract_b_id = e.register(ContractB, ());
+    let client = ContractAClient::new(&e, &contract_a_id);
+
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+
+    client.fna(&contract_b_id, &a, &Some(b.clone()));
+
+    // The auth required of b by ContractB is not expected.
+    e.auths().assert_eq(&[(
+        a.clone(),
+        AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((
+                contract_a_id.clone(),
+                Symbol::new(&e, "fna"),
+                (&contract_b_id, &a, Some(b.clone())).into_val(&e),
+            )),
+            sub_invocations: std::vec![AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_b_id.clone(),
+                    Symbol::new(&e, "fnb"),
+                    (&a, Some(b.clone())).into_val(&e),
+                )),
+                sub_invocations: std::vec![],
+            }],
+        },
+    )]);
+}
@@ -0,0 +1,79 @@
+use core::cell::Cell;
+
+use crate::{
+    iter::{BoundedEnumerable, IterationBound},
+    map, vec, Env, Vec,
+};
+
+#[test]
+fn test_bounded_under_limit() {
+    let e = Env::default();
+    let bound = e.bounded(3);
+    assert_eq!(bound.max(), 3);
+
+    let v = vec![&e, 1u32, 2];
+    let items: std::vec::Vec<u32> = v.iter().bounded(&bound).collect();
+    assert_eq!(items, [1, 2]);
+
+    // Reaching the limit exactly is allowed, and each loop counts separately.
+    let v = vec![&e, 1u32, 2, 3];
+    assert_eq!(v.iter().bounded(&bound).sum::<u32>(), 6);
+    assert_eq!(v.iter().bounded(&bound).sum::<u32>(), 6);
+
+    let m = map![&e, (1u32, 10u32), (2, 20)];
+    assert_eq!(m.iter().bounded(&bound).count(), 2);
+
+    assert_eq!(
+        Vec::<u32>::new(&e)
+            .iter()
+            .bounded(&IterationBound::new(0))
+            .count(),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "iteration exceeded its bound")]
+fn test_bounded_over_limit() {
+    let e = Env::default();
+    let bound = e.bounded(3);
+
+    let v = vec![&e, 1u32, 2, 3, 4];
+    for _ in v.iter().bounded(&bound) {}
+}
+
+#[test]
+fn test_bounded_without_exact_size_hint() {
+    let e = Env::default();
+    let bound = e.bounded(3);
+
+    // A filtered iterator can't tell from its size hint whether it's done,
+    // so reaching the limit exactly has to look for one more item.
+    let v = vec![&e, 1u32, 2, 3, 4, 5, 6];
+    let even = v.iter().filter(|i| i % 2 == 0);
+    assert_eq!(even.bounded(&bound).sum::<u32>(), 12);
+
+    let res = std::panic::catch_unwind(|| {
+        let e = Env::default();
+        let v = vec![&e, 1u32, 2, 3, 4, 5, 6, 8];
+        v.iter()
+            .filter(|i| i % 2 == 0)
+            .bounded(&e.bounded(3))
+            .count()
+    });
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_bounded_takes_one_item_past_limit() {
+    let taken = Cell::new(0);
+    let items = (0..10).inspect(|_| taken.set(taken.get() + 1));
+    let mut iter = items.bounded(&IterationBound::new(3));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| iter.next()));
+    assert!(res.is_err());
+    assert_eq!(taken.get(), 4);
+}
@@ -0,0 +1,48 @@
+use crate as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contract]
+pub struct Callee;
+
+#[contractimpl]
+impl Callee {
+    pub fn burn(env: Env, n: u32) -> u32 {
+        let mut v = Vec::new(&env);
+        for i in 0..n {
+            v.push_back(i);
+        }
+        v.len()
+    }
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    pub fn call_with_budget(env: Env, callee: Address, n: u32, cpu: u64) -> bool {
+        env.cost_estimate().budget().reset_limits(cpu, u64::MAX);
+        CalleeClient::new(&env, &callee).try_burn(&n).is_ok()
+    }
+}
+
+#[test]
+fn test_subcall_within_limits() {
+    let e = Env::default();
+    let callee = e.register(Callee, ());
+    let caller = CallerClient::new(&e, &e.register(Caller, ()));
+
+    assert!(caller.call_with_budget(&callee, &10, &10_000_000));
+}
+
+#[test]
+#[should_panic(expected = "Error(Budget, ExceededLimit)")]
+fn test_subcall_exceeding_limits_is_not_recoverable() {
+    let e = Env::default();
+    let callee = e.register(Callee, ());
+    let caller = CallerClient::new(&e, &e.register(Caller, ()));
+
+    // The caller uses try_burn, but exceeding the budget aborts the whole
+    // invocation rather than returning an error to the caller.
+    let _ = caller.try_call_with_budget(&callee, &10_000, &100_000);
+}
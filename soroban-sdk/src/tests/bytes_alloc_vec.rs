@@ -1,5 +1,7 @@
 #![cfg(feature = "alloc")]
 
+extern crate alloc;
+
 use crate::{Bytes, Env};
 
 #[test]
@@ -13,3 +15,24 @@ fn test_bytes_alloc_vec() {
         bytes.to_alloc_vec().as_slice()
     );
 }
+
+#[test]
+fn test_bytes_from_alloc_vec_round_trip() {
+    let env = Env::default();
+
+    let vec = alloc::vec![1u8, 2, 3, 4, 5];
+    let bytes = Bytes::from_alloc_vec(&env, vec.clone());
+
+    assert_eq!(bytes, Bytes::from_slice(&env, &[1, 2, 3, 4, 5]));
+    assert_eq!(bytes.to_alloc_vec(), vec);
+}
+
+#[test]
+fn test_bytes_from_alloc_vec_empty() {
+    let env = Env::default();
+
+    let bytes = Bytes::from_alloc_vec(&env, alloc::vec::Vec::new());
+
+    assert!(bytes.is_empty());
+    assert!(bytes.to_alloc_vec().is_empty());
+}
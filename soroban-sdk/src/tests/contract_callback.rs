@@ -0,0 +1,91 @@
+use crate::{
+    self as soroban_sdk, contract, contractclient, contractimpl, symbol_short,
+    testutils::Address as _, token, Address, Env, Symbol,
+};
+
+const TOKEN: Symbol = symbol_short!("token");
+
+/// The callback a lender invokes on the borrower during a flash loan. The
+/// lender calls it through the generated client and the borrower implements
+/// the trait, so the function name and arguments match on both ends.
+#[contractclient(name = "FlashLoanReceiverClient")]
+pub trait FlashLoanReceiver {
+    fn on_flash_loan(env: Env, lender: Address, amount: i128, fee: i128);
+}
+
+#[contract]
+pub struct Lender;
+
+#[contractimpl]
+impl Lender {
+    pub fn flash_loan(env: Env, token: Address, receiver: Address, amount: i128) {
+        let token = token::Client::new(&env, &token);
+        let lender = env.current_contract_address();
+        let fee = amount / 100;
+
+        let balance_before = token.balance(&lender);
+        token.transfer(&lender, &receiver, &amount);
+        FlashLoanReceiverClient::new(&env, &receiver).on_flash_loan(&lender, &amount, &fee);
+        if token.balance(&lender) < balance_before + fee {
+            panic!("flash loan not repaid");
+        }
+    }
+}
+
+#[contract]
+pub struct Borrower;
+
+#[contractimpl]
+impl Borrower {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&TOKEN, &token);
+    }
+}
+
+#[contractimpl]
+impl FlashLoanReceiver for Borrower {
+    fn on_flash_loan(env: Env, lender: Address, amount: i128, fee: i128) {
+        let token: Address = env.storage().instance().get(&TOKEN).unwrap();
+        let token = token::Client::new(&env, &token);
+        let borrower = env.current_contract_address();
+        // Repay only when holding enough to cover the fee as well.
+        if token.balance(&borrower) >= amount + fee {
+            token.transfer(&borrower, &lender, &(amount + fee));
+        }
+    }
+}
+
+fn setup(e: &Env, borrower_funds: i128) -> (token::Client, Address, Address) {
+    e.mock_all_auths();
+    let admin = Address::generate(e);
+    let token_id = e.register_stellar_asset_contract_v2(admin).address();
+    let token_admin = token::StellarAssetClient::new(e, &token_id);
+    let lender_id = e.register(Lender, ());
+    let borrower_id = e.register(Borrower, (&token_id,));
+    token_admin.mint(&lender_id, &1_000);
+    token_admin.mint(&borrower_id, &borrower_funds);
+    (token::Client::new(e, &token_id), lender_id, borrower_id)
+}
+
+#[test]
+fn test_flash_loan_callback_repays() {
+    let e = Env::default();
+    let (token, lender_id, borrower_id) = setup(&e, 5);
+
+    LenderClient::new(&e, &lender_id).flash_loan(&token.address, &borrower_id, &500);
+
+    assert_eq!(token.balance(&lender_id), 1_005);
+    assert_eq!(token.balance(&borrower_id), 0);
+}
+
+#[test]
+fn test_flash_loan_callback_fails_to_repay() {
+    let e = Env::default();
+    let (token, lender_id, borrower_id) = setup(&e, 0);
+
+    let res = LenderClient::new(&e, &lender_id).try_flash_loan(&token.address, &borrower_id, &500);
+
+    assert!(res.is_err());
+    assert_eq!(token.balance(&lender_id), 1_000);
+    assert_eq!(token.balance(&borrower_id), 0);
+}
@@ -0,0 +1,35 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Unauthorized = 1,
+    InsufficientBalance = 2,
+    Overflow = 10,
+}
+
+#[test]
+fn test_as_u32_and_from_u32_round_trip() {
+    for (error, code) in [
+        (Error::Unauthorized, 1),
+        (Error::InsufficientBalance, 2),
+        (Error::Overflow, 10),
+    ] {
+        assert_eq!(error.as_u32(), code);
+        assert_eq!(Error::from_u32(code), Some(error));
+    }
+}
+
+#[test]
+fn test_from_u32_unknown_code() {
+    assert_eq!(Error::from_u32(0), None);
+    assert_eq!(Error::from_u32(3), None);
+    assert_eq!(Error::from_u32(u32::MAX), None);
+}
+
+#[test]
+fn test_const_codes() {
+    const CODE: u32 = Error::Overflow.as_u32();
+    assert_eq!(CODE, 10);
+}
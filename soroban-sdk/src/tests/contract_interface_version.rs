@@ -0,0 +1,124 @@
+use crate::{self as soroban_sdk, contract, contractimpl, Address, Env};
+
+mod v1 {
+    use crate::{self as soroban_sdk, contract, contractimpl, contractinterface, Env};
+
+    #[contract]
+    #[contractinterface(version = 1)]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn count(_env: Env) -> u32 {
+            1
+        }
+    }
+}
+
+mod v2 {
+    use crate::{self as soroban_sdk, contract, contractimpl, contractinterface, Env};
+
+    #[contract]
+    #[contractinterface(version = 2)]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn count(_env: Env) -> u32 {
+            1
+        }
+
+        pub fn count_by(_env: Env, step: u32) -> u32 {
+            step
+        }
+    }
+}
+
+mod unversioned {
+    use crate::{self as soroban_sdk, contract, contractimpl, Env};
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn count(_env: Env) -> u32 {
+            1
+        }
+    }
+}
+
+mod own_interface_version {
+    use crate::{self as soroban_sdk, contract, contractimpl, contractinterface, Env};
+
+    #[contract]
+    #[contractinterface(version = 3)]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn interface_version(_env: Env) -> u32 {
+            7
+        }
+    }
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    pub fn count(env: Env, counter: Address) -> u32 {
+        // The version 2 client is used for every counter, and count_by, which
+        // was added in version 2 of the interface, is only called if the
+        // counter implements it.
+        let client = v2::ContractClient::new(&env, &counter);
+        match client.probe_version() {
+            Some(version) if version >= 2 => client.count_by(&10),
+            _ => client.count(),
+        }
+    }
+}
+
+#[test]
+fn test_probe_version() {
+    let e = Env::default();
+    let v1_id = e.register(v1::Contract, ());
+    let v2_id = e.register(v2::Contract, ());
+    let unversioned_id = e.register(unversioned::Contract, ());
+
+    assert_eq!(v1::ContractClient::new(&e, &v1_id).probe_version(), Some(1));
+    assert_eq!(v2::ContractClient::new(&e, &v2_id).probe_version(), Some(2));
+    assert_eq!(
+        v2::ContractClient::new(&e, &unversioned_id).probe_version(),
+        None
+    );
+    assert_eq!(
+        v2::ContractClient::new(&e, &v2_id).soroban_interface_version(),
+        2
+    );
+}
+
+#[test]
+fn test_caller_branches_on_probed_version() {
+    let e = Env::default();
+    let v1_id = e.register(v1::Contract, ());
+    let v2_id = e.register(v2::Contract, ());
+    let unversioned_id = e.register(unversioned::Contract, ());
+    let caller_id = e.register(Caller, ());
+    let caller = CallerClient::new(&e, &caller_id);
+
+    assert_eq!(caller.count(&v1_id), 1);
+    assert_eq!(caller.count(&v2_id), 10);
+    assert_eq!(caller.count(&unversioned_id), 1);
+}
+
+#[test]
+fn test_interface_version_does_not_collide() {
+    let e = Env::default();
+    let id = e.register(own_interface_version::Contract, ());
+    let client = own_interface_version::ContractClient::new(&e, &id);
+
+    assert_eq!(client.interface_version(), 7);
+    assert_eq!(client.probe_version(), Some(3));
+}
@@ -0,0 +1,47 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, symbol_short, vec, xdr::ScValType, Env,
+    TypeMismatchError,
+};
+
+extern crate std;
+use std::string::ToString;
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn amount(_env: Env) -> i128 {
+        5
+    }
+}
+
+#[test]
+fn test_strict_invoke_matching_type() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let rv = e.invoke_contract_strict::<i128>(&contract_id, &symbol_short!("amount"), vec![&e]);
+    assert_eq!(rv, Ok(5));
+}
+
+#[test]
+fn test_strict_invoke_mismatched_type() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let err = e
+        .invoke_contract_strict::<u64>(&contract_id, &symbol_short!("amount"), vec![&e])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TypeMismatchError {
+            expected: "u64",
+            actual: ScValType::I128,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "value of type I128 can't be converted to u64"
+    );
+}
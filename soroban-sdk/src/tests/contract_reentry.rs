@@ -0,0 +1,65 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, symbol_short, vec,
+    xdr::{ScErrorCode, ScErrorType},
+    Address, Env, Error, IntoVal,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn noop(_env: Env) {}
+
+    pub fn call_self(env: Env) -> Error {
+        let rv = env.try_invoke_contract::<(), Error>(
+            &env.current_contract_address(),
+            &symbol_short!("noop"),
+            vec![&env],
+        );
+        rv.unwrap_err().unwrap()
+    }
+
+    pub fn call_back(env: Env, other: Address) {
+        env.invoke_contract::<()>(
+            &other,
+            &symbol_short!("call"),
+            vec![&env, env.current_contract_address().into_val(&env)],
+        );
+    }
+}
+
+#[contract]
+pub struct CallBack;
+
+#[contractimpl]
+impl CallBack {
+    pub fn call(env: Env, contract: Address) {
+        env.invoke_contract::<()>(&contract, &symbol_short!("noop"), vec![&env]);
+    }
+}
+
+#[test]
+fn test_self_reentry_fails() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert_eq!(
+        client.call_self(),
+        Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+    );
+    // The contract can be called again once the call has completed.
+    client.noop();
+    client.call_self();
+}
+
+#[test]
+fn test_callback_reentry_fails() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+    let callback_id = e.register(CallBack, ());
+
+    assert!(client.try_call_back(&callback_id).is_err());
+    // Calling the callback contract directly is not re-entry.
+    CallBackClient::new(&e, &callback_id).call(&client.address);
+}
@@ -0,0 +1,184 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, vec, Address, BytesN, Env,
+    String, Symbol, Vec,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn arity1(a: Address) -> (Address,) {
+        (a,)
+    }
+
+    pub fn arity2(a: Address, i: i128) -> (Address, i128) {
+        (a, i)
+    }
+
+    pub fn arity3(a: Address, i: i128, s: Symbol) -> (Address, i128, Symbol) {
+        (a, i, s)
+    }
+
+    pub fn arity4(a: Address, i: i128, s: Symbol, b: bool) -> (Address, i128, Symbol, bool) {
+        (a, i, s, b)
+    }
+
+    pub fn arity5(
+        env: Env,
+        a: Address,
+        i: i128,
+        s: Symbol,
+        b: bool,
+    ) -> (Address, i128, Symbol, bool, BytesN<32>) {
+        (a, i, s, b, BytesN::from_array(&env, &[7; 32]))
+    }
+
+    pub fn arity6(
+        env: Env,
+        a: Address,
+        i: i128,
+        s: Symbol,
+        b: bool,
+    ) -> (Address, i128, Symbol, bool, BytesN<32>, String) {
+        (
+            a,
+            i,
+            s,
+            b,
+            BytesN::from_array(&env, &[7; 32]),
+            String::from_str(&env, "six"),
+        )
+    }
+
+    pub fn arity7(
+        env: Env,
+        a: Address,
+        i: i128,
+        s: Symbol,
+        b: bool,
+    ) -> (Address, i128, Symbol, bool, BytesN<32>, String, u64) {
+        (
+            a,
+            i,
+            s,
+            b,
+            BytesN::from_array(&env, &[7; 32]),
+            String::from_str(&env, "six"),
+            u64::MAX,
+        )
+    }
+
+    pub fn arity8(
+        env: Env,
+        a: Address,
+        i: i128,
+        s: Symbol,
+        b: bool,
+    ) -> (
+        Address,
+        i128,
+        Symbol,
+        bool,
+        BytesN<32>,
+        String,
+        u64,
+        Vec<Option<u32>>,
+    ) {
+        (
+            a,
+            i,
+            s,
+            b,
+            BytesN::from_array(&env, &[7; 32]),
+            String::from_str(&env, "six"),
+            u64::MAX,
+            vec![&env, Some(1), None],
+        )
+    }
+}
+
+#[test]
+fn test_return_tuples() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let a = Address::generate(&e);
+    let i = i128::MIN;
+    let s = symbol_short!("sym");
+    let bytes = BytesN::from_array(&e, &[7; 32]);
+    let string = String::from_str(&e, "six");
+    let v = vec![&e, Some(1), None];
+
+    assert_eq!(client.arity1(&a), (a.clone(),));
+    assert_eq!(client.arity2(&a, &i), (a.clone(), i));
+    assert_eq!(client.arity3(&a, &i, &s), (a.clone(), i, s.clone()));
+    assert_eq!(
+        client.arity4(&a, &i, &s, &true),
+        (a.clone(), i, s.clone(), true)
+    );
+    assert_eq!(
+        client.arity5(&a, &i, &s, &false),
+        (a.clone(), i, s.clone(), false, bytes.clone())
+    );
+    assert_eq!(
+        client.arity6(&a, &i, &s, &true),
+        (a.clone(), i, s.clone(), true, bytes.clone(), string.clone())
+    );
+    assert_eq!(
+        client.arity7(&a, &i, &s, &true),
+        (
+            a.clone(),
+            i,
+            s.clone(),
+            true,
+            bytes.clone(),
+            string.clone(),
+            u64::MAX
+        )
+    );
+    assert_eq!(
+        client.arity8(&a, &i, &s, &true),
+        (
+            a.clone(),
+            i,
+            s.clone(),
+            true,
+            bytes.clone(),
+            string.clone(),
+            u64::MAX,
+            v.clone()
+        )
+    );
+}
+
+#[test]
+fn test_try_return_tuples() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let a = Address::generate(&e);
+    let s = symbol_short!("sym");
+
+    assert_eq!(client.try_arity1(&a), Ok(Ok((a.clone(),))));
+    assert_eq!(
+        client.try_arity4(&a, &1, &s, &true),
+        Ok(Ok((a.clone(), 1, s.clone(), true)))
+    );
+    assert_eq!(
+        client.try_arity8(&a, &1, &s, &false),
+        Ok(Ok((
+            a.clone(),
+            1,
+            s.clone(),
+            false,
+            BytesN::from_array(&e, &[7; 32]),
+            String::from_str(&e, "six"),
+            u64::MAX,
+            vec![&e, Some(1), None],
+        )))
+    );
+}
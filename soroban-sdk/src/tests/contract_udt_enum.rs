@@ -102,3 +102,47 @@ fn round_trips() {
     let after: Udt = scvec.try_into_val(&env).unwrap();
     assert_eq!(before, after);
 }
+
+mod reordered {
+    use crate::{self as soroban_sdk};
+    use soroban_sdk::{contract, contracttype, Env};
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub enum Schema {
+        Aaa,
+        Bbb(i32),
+        Ccc(u32),
+    }
+
+    // The same enum as Schema, with its variants reordered and a variant
+    // inserted, as it might look after an upgrade.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub enum SchemaUpgraded {
+        Ccc(u32),
+        Ddd,
+        Bbb(i32),
+        Aaa,
+    }
+
+    #[contract]
+    pub struct Contract;
+
+    #[test]
+    fn test_reordered_variants_read_stored_values() {
+        let env = Env::default();
+        let contract_id = env.register(Contract, ());
+
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            storage.set(&1, &Schema::Aaa);
+            storage.set(&2, &Schema::Bbb(-2));
+            storage.set(&3, &Schema::Ccc(3));
+
+            assert_eq!(storage.get(&1), Some(SchemaUpgraded::Aaa));
+            assert_eq!(storage.get(&2), Some(SchemaUpgraded::Bbb(-2)));
+            assert_eq!(storage.get(&3), Some(SchemaUpgraded::Ccc(3)));
+        });
+    }
+}
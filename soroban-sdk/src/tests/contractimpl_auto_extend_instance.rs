@@ -0,0 +1,68 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl,
+    testutils::{storage::Instance as _, Ledger as _},
+    Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl(auto_extend_instance(threshold = 500, extend_to = 1000))]
+impl Contract {
+    pub fn noop() {}
+
+    pub fn get(env: Env) -> u32 {
+        env.storage().instance().get(&1).unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct NoExtendContract;
+
+#[contractimpl]
+impl NoExtendContract {
+    pub fn call() {}
+}
+
+#[test]
+fn test_auto_extend_instance() {
+    let e = Env::default();
+    e.ledger().set_min_persistent_entry_ttl(100);
+    e.ledger().set_max_entry_ttl(20_000);
+
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+    let ttl = || e.as_contract(&contract_id, || e.storage().instance().get_ttl());
+    assert_eq!(ttl(), 99);
+
+    // Methods without an Env argument are extended too.
+    client.noop();
+    assert_eq!(ttl(), 1000);
+
+    // The TTL is not extended while it is above the threshold.
+    e.ledger().set_sequence_number(400);
+    assert_eq!(ttl(), 600);
+    assert_eq!(client.get(), 0);
+    assert_eq!(ttl(), 600);
+
+    e.ledger().set_sequence_number(600);
+    assert_eq!(ttl(), 400);
+    assert_eq!(client.get(), 0);
+    assert_eq!(ttl(), 1000);
+}
+
+#[test]
+fn test_no_auto_extend_instance() {
+    let e = Env::default();
+    e.ledger().set_min_persistent_entry_ttl(100);
+    e.ledger().set_max_entry_ttl(20_000);
+
+    let contract_id = e.register(NoExtendContract, ());
+    let client = NoExtendContractClient::new(&e, &contract_id);
+
+    client.call();
+    assert_eq!(
+        e.as_contract(&contract_id, || e.storage().instance().get_ttl()),
+        99
+    );
+}
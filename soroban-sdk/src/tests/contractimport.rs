@@ -138,3 +138,11 @@ fn test_spec() {
     })];
     assert_eq!(entries, expect);
 }
+
+#[test]
+fn test_client_meta() {
+    let meta = addcontract::Client::META;
+    assert!(meta.contains(&("desc", "adds two u64s")));
+    // The meta the SDK adds to every contract is included.
+    assert!(meta.iter().any(|(key, _)| *key == "rssdkver"));
+}
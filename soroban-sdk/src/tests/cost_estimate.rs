@@ -1,6 +1,6 @@
 use crate as soroban_sdk;
 use expect_test::expect;
-use soroban_sdk::Env;
+use soroban_sdk::{contract, contractimpl, xdr, Env};
 use soroban_sdk_macros::symbol_short;
 use stellar_xdr::curr::ContractCostType;
 
@@ -145,6 +145,43 @@ fn test_cost_estimate_with_storage() {
     .assert_eq(format!("{:#?}", e.cost_estimate().fee()).as_str());
 }
 
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn put_and_extend(env: Env, k: u32, v: u32) {
+        env.storage().persistent().set(&k, &v);
+        env.storage().persistent().extend_ttl(&k, 100_000, 100_000);
+    }
+
+    pub fn get(env: Env, k: u32) -> Option<u32> {
+        env.storage().persistent().get(&k)
+    }
+}
+
+#[test]
+fn test_cost_estimate_resource_fee() {
+    let e = Env::default();
+
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.put_and_extend(&1, &2);
+    let fee = e.cost_estimate().resource_fee();
+    assert!(fee.read > 0);
+    assert!(fee.write > 0);
+    assert!(fee.rent > 0);
+    assert!(fee.total >= fee.read + fee.write + fee.rent);
+
+    let estimate = e.cost_estimate().fee();
+    assert_eq!(fee.total, estimate.total);
+    assert_eq!(
+        fee.rent,
+        estimate.persistent_entry_rent + estimate.temporary_entry_rent
+    );
+}
+
 #[test]
 fn test_cost_estimate_budget() {
     let e = Env::default();
@@ -261,3 +298,81 @@ fn test_cost_estimate_budget() {
         .as_str(),
     );
 }
+
+#[test]
+fn test_cost_estimate_measure() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        let push = |n: u32| {
+            let mut v = soroban_sdk::Vec::new(&e);
+            for i in 0..n {
+                v.push_back(i);
+            }
+        };
+
+        let small = e.cost_estimate().measure(|| push(10));
+        let large = e.cost_estimate().measure(|| push(100));
+        assert!(small.cpu_insns > 0);
+        assert!(small.mem_bytes > 0);
+        assert!(small.cpu_insns < large.cpu_insns);
+        assert!(small.mem_bytes < large.mem_bytes);
+
+        // Nested measurements are included in the enclosing measurement.
+        let mut inner = Default::default();
+        let outer = e.cost_estimate().measure(|| {
+            push(10);
+            inner = e.cost_estimate().measure(|| push(100));
+        });
+        assert!(outer.cpu_insns >= inner.cpu_insns);
+        assert!(outer.mem_bytes >= inner.mem_bytes);
+        assert!(outer.cpu_insns > inner.cpu_insns);
+
+        // Nothing is metered for a function that doesn't call the host.
+        let none = e.cost_estimate().measure(|| {});
+        assert_eq!(none.cpu_insns, 0);
+        assert_eq!(none.mem_bytes, 0);
+    });
+}
+
+#[test]
+fn test_cost_estimate_footprint() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let contract: xdr::ScAddress = contract_id.clone().try_into().unwrap();
+    let key = |k: u32| {
+        xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+            contract: contract.clone(),
+            key: xdr::ScVal::U32(k),
+            durability: xdr::ContractDataDurability::Persistent,
+        })
+    };
+
+    client.put_and_extend(&1, &2);
+    let footprint = e.cost_estimate().footprint();
+    assert!(footprint.read_write.contains(&key(1)));
+    assert!(!footprint.read_only.contains(&key(1)));
+
+    // The footprint only covers what the last invocation added.
+    assert_eq!(client.get(&3), None);
+    let footprint = e.cost_estimate().footprint();
+    assert_eq!(footprint.read_only.as_slice(), [key(3)]);
+    assert!(footprint.read_write.is_empty());
+
+    // Reading an entry that was read before adds nothing, and writing it
+    // makes it read-write.
+    assert_eq!(client.get(&3), None);
+    assert!(e.cost_estimate().footprint().read_only.is_empty());
+    client.put_and_extend(&3, &4);
+    let footprint = e.cost_estimate().footprint();
+    assert_eq!(footprint.read_write.as_slice(), [key(3)]);
+
+    // Entries accessed outside of an invocation are not included.
+    e.as_contract(&contract_id, || {
+        let _: Option<u32> = e.storage().persistent().get(&5u32);
+    });
+    assert_eq!(e.cost_estimate().footprint(), footprint);
+}
@@ -1,4 +1,4 @@
-use crate::{bytesn, BytesN, Env, IntoVal};
+use crate::{bytesn, Bytes, BytesN, Env, IntoVal};
 
 #[test]
 fn test_keccak256() {
@@ -12,3 +12,19 @@ fn test_keccak256() {
     let hash: BytesN<32> = env.crypto().keccak256(&bytes).into();
     assert_eq!(hash, expect);
 }
+
+#[test]
+fn test_keccak256_hasher_matches_one_shot() {
+    let env = Env::default();
+
+    let mut hasher = env.crypto().keccak256_hasher();
+    for piece in [&b"test vector"[..], b" ", b"for soroban"] {
+        hasher.update(&Bytes::from_slice(&env, piece));
+    }
+    let hash: BytesN<32> = hasher.finalize().into();
+    let expect = bytesn!(
+        &env,
+        0x352fe2eaddf44eb02eb3eab1f8d6ff4ba426df4f1734b1e3f210d621ee8853d9
+    );
+    assert_eq!(hash, expect);
+}
@@ -0,0 +1,148 @@
+use crate::{bytes, bytesn, crypto::MerkleHash, vec, Bytes, BytesN, Env, Vec};
+
+// A tree of four leaves, where each leaf is the sha256 of a single byte
+// 0x00..=0x03, and inner nodes hash the sorted pair of their children:
+//
+//               root
+//           /          \
+//        n01            n23
+//       /   \          /   \
+//     l0     l1      l2     l3
+fn leaves(env: &Env) -> [BytesN<32>; 4] {
+    [
+        bytesn!(
+            env,
+            0x6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d
+        ),
+        bytesn!(
+            env,
+            0x4bf5122f344554c53bde2ebb8cd2b7e3d1600ad631c385a5d7cce23c7785459a
+        ),
+        bytesn!(
+            env,
+            0xdbc1b4c900ffe48d575b5da5c638040125f65db0fe3e24494b76ea986457d986
+        ),
+        bytesn!(
+            env,
+            0x084fed08b978af4d7d196a7446a86b58009e636b611db16211b65a9aadff29c5
+        ),
+    ]
+}
+
+fn n01(env: &Env) -> BytesN<32> {
+    bytesn!(
+        env,
+        0x86c00a08265c84d8dec95f226f7acbbc0dd44910b86b6b5d27c6bab7cfff243b
+    )
+}
+
+fn n23(env: &Env) -> BytesN<32> {
+    bytesn!(
+        env,
+        0x93dbb3d0262759bb8996963d1cb5f5ee195f6d13271de3db1cd5b92959103b5a
+    )
+}
+
+fn root(env: &Env) -> BytesN<32> {
+    bytesn!(
+        env,
+        0xa932f0ce28c9f994df39eed8956600a00e64cff2aa2a0675032a7b6cf28ce6a3
+    )
+}
+
+#[test]
+fn test_verify_merkle_proof_sha256() {
+    let env = Env::default();
+    let [l0, l1, l2, l3] = leaves(&env);
+    let root = root(&env);
+
+    let proofs = [
+        (&l0, vec![&env, l1.clone(), n23(&env)]),
+        (&l1, vec![&env, l0.clone(), n23(&env)]),
+        (&l2, vec![&env, l3.clone(), n01(&env)]),
+        (&l3, vec![&env, l2.clone(), n01(&env)]),
+    ];
+    for (leaf, proof) in proofs {
+        assert!(env
+            .crypto()
+            .verify_merkle_proof(&root, leaf, &proof, MerkleHash::Sha256));
+    }
+
+    // A tree of one leaf has an empty proof.
+    assert!(env
+        .crypto()
+        .verify_merkle_proof(&root, &root, &Vec::new(&env), MerkleHash::Sha256));
+}
+
+#[test]
+fn test_verify_merkle_proof_tampered() {
+    let env = Env::default();
+    let [l0, l1, l2, _] = leaves(&env);
+    let root = root(&env);
+    let proof = vec![&env, l1.clone(), n23(&env)];
+
+    // Wrong leaf.
+    assert!(!env
+        .crypto()
+        .verify_merkle_proof(&root, &l2, &proof, MerkleHash::Sha256));
+
+    // Tampered sibling.
+    let mut tampered = proof.clone();
+    tampered.set(1, n01(&env));
+    assert!(!env
+        .crypto()
+        .verify_merkle_proof(&root, &l0, &tampered, MerkleHash::Sha256));
+
+    // Truncated and extended proofs.
+    assert!(!env.crypto().verify_merkle_proof(
+        &root,
+        &l0,
+        &vec![&env, l1.clone()],
+        MerkleHash::Sha256
+    ));
+    let mut extended = proof.clone();
+    extended.push_back(l1);
+    assert!(!env
+        .crypto()
+        .verify_merkle_proof(&root, &l0, &extended, MerkleHash::Sha256));
+
+    // Wrong hash function.
+    assert!(!env
+        .crypto()
+        .verify_merkle_proof(&root, &l0, &proof, MerkleHash::Keccak256));
+}
+
+#[test]
+fn test_verify_merkle_proof_keccak256() {
+    let env = Env::default();
+
+    let hash_pair = |a: &BytesN<32>, b: &BytesN<32>| -> BytesN<32> {
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from(a);
+        data.append(&Bytes::from(b));
+        env.crypto().keccak256(&data).into()
+    };
+    let leaf = |b: Bytes| -> BytesN<32> { env.crypto().keccak256(&b).into() };
+
+    let l0 = leaf(bytes!(&env, 0x00));
+    let l1 = leaf(bytes!(&env, 0x01));
+    let l2 = leaf(bytes!(&env, 0x02));
+    let n01 = hash_pair(&l0, &l1);
+    let root = hash_pair(&n01, &l2);
+
+    assert!(env.crypto().verify_merkle_proof(
+        &root,
+        &l2,
+        &vec![&env, n01.clone()],
+        MerkleHash::Keccak256
+    ));
+    assert!(env.crypto().verify_merkle_proof(
+        &root,
+        &l1,
+        &vec![&env, l0.clone(), l2.clone()],
+        MerkleHash::Keccak256
+    ));
+    assert!(!env
+        .crypto()
+        .verify_merkle_proof(&root, &l1, &vec![&env, l0, l2], MerkleHash::Sha256));
+}
@@ -1,4 +1,7 @@
-use crate::{bytes, bytesn, BytesN, Env};
+use crate::{
+    self as soroban_sdk, bytes, bytesn, contracttype, map, symbol_short, testutils::Address as _,
+    vec, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
 
 #[test]
 fn test_sha256() {
@@ -12,3 +15,112 @@ fn test_sha256() {
     let hash: BytesN<32> = env.crypto().sha256(&input).into();
     assert_eq!(hash, expect);
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commitment {
+    pub owner: Address,
+    pub amount: i128,
+    pub salt: BytesN<32>,
+}
+
+#[test]
+fn test_hash_val_matches_sha256_of_xdr() {
+    let env = Env::default();
+
+    let value = map![&env, (symbol_short!("a"), 1u32), (symbol_short!("b"), 2u32)];
+    let hash: BytesN<32> = env.crypto().hash_val(&value).into();
+    let expect: BytesN<32> = env.crypto().sha256(&value.clone().to_xdr(&env)).into();
+    assert_eq!(hash, expect);
+
+    // The hash of a u32 is the hash of its ScVal XDR: the ScU32 discriminant
+    // followed by the big-endian value.
+    let hash: BytesN<32> = env.crypto().hash_val(&1u32).into();
+    let expect: BytesN<32> = env
+        .crypto()
+        .sha256(&bytes!(&env, 0x0000000300000001))
+        .into();
+    assert_eq!(hash, expect);
+}
+
+#[test]
+fn test_hash_val_equal_values() {
+    let env = Env::default();
+
+    let owner = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let a = Commitment {
+        owner: owner.clone(),
+        amount: 100,
+        salt: salt.clone(),
+    };
+    let b = Commitment {
+        owner,
+        amount: 100,
+        salt,
+    };
+    assert_eq!(
+        env.crypto().hash_val(&a).to_bytes(),
+        env.crypto().hash_val(&b).to_bytes()
+    );
+
+    // Maps are ordered by key, so insertion order does not affect the hash.
+    assert_eq!(
+        env.crypto()
+            .hash_val(&map![&env, (1u32, 10u32), (2u32, 20u32)])
+            .to_bytes(),
+        env.crypto()
+            .hash_val(&map![&env, (2u32, 20u32), (1u32, 10u32)])
+            .to_bytes()
+    );
+}
+
+#[test]
+fn test_hash_val_distinct_values() {
+    let env = Env::default();
+
+    let owner = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let a = Commitment {
+        owner: owner.clone(),
+        amount: 100,
+        salt: salt.clone(),
+    };
+    let b = Commitment {
+        owner,
+        amount: 101,
+        salt,
+    };
+    assert_ne!(
+        env.crypto().hash_val(&a).to_bytes(),
+        env.crypto().hash_val(&b).to_bytes()
+    );
+
+    // Values of different types with the same numeric value differ.
+    assert_ne!(
+        env.crypto().hash_val(&1u32).to_bytes(),
+        env.crypto().hash_val(&1i32).to_bytes()
+    );
+    assert_ne!(
+        env.crypto().hash_val(&vec![&env, 1u32, 2]).to_bytes(),
+        env.crypto().hash_val(&vec![&env, 2u32, 1]).to_bytes()
+    );
+}
+
+#[test]
+fn test_sha256_hasher_matches_one_shot() {
+    let env = Env::default();
+
+    let mut hasher = env.crypto().sha256_hasher();
+    hasher.update(&bytes!(&env, 0x0102));
+    hasher.update(&Bytes::new(&env));
+    hasher.update(&bytes!(&env, 0x030405));
+    let hash: BytesN<32> = hasher.finalize().into();
+    let expect: BytesN<32> = env.crypto().sha256(&bytes!(&env, 0x0102030405)).into();
+    assert_eq!(hash, expect);
+
+    // A hasher that is never updated hashes the empty input.
+    let hash: BytesN<32> = env.crypto().sha256_hasher().finalize().into();
+    let expect: BytesN<32> = env.crypto().sha256(&Bytes::new(&env)).into();
+    assert_eq!(hash, expect);
+}
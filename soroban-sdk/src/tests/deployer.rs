@@ -0,0 +1,123 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl,
+    testutils::{Address as _, Deployer as _},
+    Address, BytesN, Env,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+#[test]
+fn test_derive_address_deterministic() {
+    let e = Env::default();
+    let base = Address::generate(&e);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+
+    let derived = e.deployer().derive_address(&base, &salt);
+    assert_eq!(derived, e.deployer().derive_address(&base, &salt));
+    assert_eq!(
+        derived,
+        e.deployer()
+            .with_address(base.clone(), salt.clone())
+            .deployed_address()
+    );
+    assert_ne!(derived, base);
+    assert!(derived.contract_id_bytes().is_some());
+}
+
+#[test]
+fn test_derive_address_distinct() {
+    let e = Env::default();
+    let base = Address::generate(&e);
+    let other_base = Address::generate(&e);
+    let salt1 = BytesN::from_array(&e, &[1; 32]);
+    let salt2 = BytesN::from_array(&e, &[2; 32]);
+
+    let derived = e.deployer().derive_address(&base, &salt1);
+    assert_ne!(derived, e.deployer().derive_address(&base, &salt2));
+    assert_ne!(derived, e.deployer().derive_address(&other_base, &salt1));
+}
+
+#[test]
+fn test_get_contract_executable_wasm() {
+    let e = Env::default();
+    let wasm_hash = e.deployer().upload_contract_wasm(contract_data::WASM);
+    let contract_id = e.register(contract_data::WASM, ());
+
+    assert_eq!(
+        e.deployer().get_contract_executable(&contract_id),
+        Some(wasm_hash)
+    );
+}
+
+#[test]
+fn test_get_contract_executable_not_wasm() {
+    let e = Env::default();
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+
+    assert_eq!(e.deployer().get_contract_executable(&sac.address()), None);
+    assert_eq!(
+        e.deployer().get_contract_executable(&Address::generate(&e)),
+        None
+    );
+}
+
+#[contract]
+pub struct Upgradable;
+
+#[contractimpl]
+impl Upgradable {
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>, require_different: bool) {
+        env.deployer()
+            .update_current_contract_wasm_checked(wasm_hash, require_different);
+    }
+}
+
+// Native test contracts keep running their registered functions after an
+// upgrade, so the client can call the contract again once its executable is
+// the uploaded Wasm.
+
+#[test]
+fn test_update_current_contract_wasm_checked() {
+    let e = Env::default();
+    let wasm_hash = e.deployer().upload_contract_wasm(contract_data::WASM);
+    let contract_id = e.register(Upgradable, ());
+    let client = UpgradableClient::new(&e, &contract_id);
+
+    client.upgrade(&wasm_hash, &true);
+
+    assert_eq!(
+        e.deployer().get_contract_executable(&contract_id),
+        Some(wasm_hash)
+    );
+}
+
+#[test]
+#[should_panic(expected = "contract executable is already the wasm")]
+fn test_update_current_contract_wasm_checked_same_hash() {
+    let e = Env::default();
+    let wasm_hash = e.deployer().upload_contract_wasm(contract_data::WASM);
+    let contract_id = e.register(Upgradable, ());
+    let client = UpgradableClient::new(&e, &contract_id);
+
+    client.upgrade(&wasm_hash, &true);
+    client.upgrade(&wasm_hash, &true);
+}
+
+#[test]
+fn test_update_current_contract_wasm_checked_same_hash_allowed() {
+    let e = Env::default();
+    let wasm_hash = e.deployer().upload_contract_wasm(contract_data::WASM);
+    let contract_id = e.register(Upgradable, ());
+    let client = UpgradableClient::new(&e, &contract_id);
+
+    client.upgrade(&wasm_hash, &true);
+    client.upgrade(&wasm_hash, &false);
+
+    assert_eq!(
+        e.deployer().get_contract_executable(&contract_id),
+        Some(wasm_hash)
+    );
+}
@@ -1,9 +1,10 @@
 use crate::{
     self as soroban_sdk, contract, contractimpl,
     env::EnvTestConfig,
-    testutils::{Address as _, Logs as _},
+    testutils::{Address as _, Logs as _, MockAuth, MockAuthInvoke},
+    vec,
     xdr::{ScErrorCode, ScErrorType},
-    Address, Env, Error,
+    Address, Env, Error, IntoVal,
 };
 
 #[test]
@@ -202,3 +203,96 @@ fn test_snapshot_file_disabled_after_creation() {
     assert!(!p2.exists());
     let _ = std::fs::remove_file(&p1);
 }
+
+#[test]
+fn test_registered_contracts() {
+    let env = Env::default();
+    assert_eq!(env.registered_contracts(), vec![&env]);
+
+    let a = env.register(Contract, ());
+    let b = env.register(Contract, ());
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let c = sac.address();
+    assert_eq!(env.registered_contracts(), vec![&env, a.clone(), b, c]);
+
+    // Registering again at an existing address does not list it twice.
+    let _ = env.register_at(&a, Contract, ());
+    assert_eq!(env.registered_contracts().len(), 3);
+
+    // Contracts registered internally to mock auths are not listed.
+    let signer = Address::generate(&env);
+    env.mock_auths(&[MockAuth {
+        address: &signer,
+        invoke: &MockAuthInvoke {
+            contract: &a,
+            fn_name: "test",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert_eq!(env.registered_contracts().len(), 3);
+
+    // Contracts registered in a different Env are not listed.
+    let other = Env::default();
+    let _ = other.register(Contract, ());
+    assert_eq!(env.registered_contracts().len(), 3);
+    assert_eq!(other.registered_contracts().len(), 1);
+}
+
+#[test]
+fn test_register_named() {
+    let env = Env::default();
+    let router = env.register_named("router", Contract, ());
+    let pool = env.register_named("pool", Contract, ());
+
+    assert_eq!(env.contract_by_name("router"), router);
+    assert_eq!(env.contract_by_name("pool"), pool);
+    assert_eq!(env.registered_contracts(), vec![&env, router, pool.clone()]);
+
+    // The retrieved address can be invoked like any other.
+    ContractClient::new(&env, &env.contract_by_name("pool")).test();
+
+    // Registering under an existing name replaces the name's contract.
+    let new_router = env.register_named("router", Contract, ());
+    assert_eq!(env.contract_by_name("router"), new_router);
+}
+
+#[test]
+#[should_panic(expected = "no contract registered with the name \"router\"")]
+fn test_contract_by_name_unknown() {
+    let env = Env::default();
+    let _ = env.register(Contract, ());
+    let _ = env.contract_by_name("router");
+}
+
+#[contract]
+struct Counter;
+
+#[contractimpl]
+impl Counter {
+    pub fn get(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&env.current_contract_address())
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_as_contract_storage_read_by_client() {
+    let env = Env::default();
+    let a = env.register(Counter, ());
+    let b = env.register(Counter, ());
+
+    // Storage written within as_contract belongs to that contract, and is
+    // keyed here by the address the contract sees as its own.
+    env.as_contract(&a, || {
+        assert_eq!(env.current_contract_address(), a);
+        env.storage()
+            .instance()
+            .set(&env.current_contract_address(), &7u32);
+    });
+
+    assert_eq!(CounterClient::new(&env, &a).get(), 7);
+    assert_eq!(CounterClient::new(&env, &b).get(), 0);
+}
@@ -0,0 +1,92 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, symbol_short, testutils::Events as _, Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn transfer(env: Env, amount: i128) {
+        env.events()
+            .publish((symbol_short!("transfer"), symbol_short!("alice")), amount);
+    }
+
+    pub fn noop(_env: Env) {}
+}
+
+#[test]
+fn test_assert_published() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.transfer(&100);
+
+    env.events().assert_published(
+        &contract_id,
+        (symbol_short!("transfer"), symbol_short!("alice")),
+        100i128,
+    );
+    env.events().assert_not_published(
+        &contract_id,
+        (symbol_short!("transfer"), symbol_short!("alice")),
+        99i128,
+    );
+    env.events().assert_not_published(
+        &contract_id,
+        (symbol_short!("transfer"), symbol_short!("bob")),
+        100i128,
+    );
+    let other = env.register(Contract, ());
+    env.events().assert_not_published(
+        &other,
+        (symbol_short!("transfer"), symbol_short!("alice")),
+        100i128,
+    );
+}
+
+#[test]
+#[should_panic(expected = "published:\n  contract: Contract(")]
+fn test_assert_published_mismatch_lists_published() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.transfer(&100);
+
+    env.events().assert_published(
+        &contract_id,
+        (symbol_short!("transfer"), symbol_short!("alice")),
+        99i128,
+    );
+}
+
+#[test]
+#[should_panic(expected = "published:\n  (none)")]
+fn test_assert_published_none_published() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.noop();
+
+    env.events()
+        .assert_published(&contract_id, (symbol_short!("transfer"),), 100i128);
+}
+
+#[test]
+#[should_panic(expected = "unexpected event was published")]
+fn test_assert_not_published() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.transfer(&100);
+
+    env.events().assert_not_published(
+        &contract_id,
+        (symbol_short!("transfer"), symbol_short!("alice")),
+        100i128,
+    );
+}
@@ -0,0 +1,46 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, symbol_short, testutils::Events as _, vec,
+    Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn settle(env: Env) {
+        env.events().publish((symbol_short!("debit"),), 3u32);
+        env.events().publish((symbol_short!("credit"),), 2u32);
+        env.events().publish((symbol_short!("fee"),), 1u32);
+    }
+}
+
+fn event(e: &Env, contract_id: &Address, topic: Symbol, data: u32) -> (Address, Vec<Val>, Val) {
+    (
+        contract_id.clone(),
+        vec![e, topic.into_val(e)],
+        data.into_val(e),
+    )
+}
+
+#[test]
+fn test_events_in_publish_order() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.settle();
+
+    let expected = [
+        event(&e, &contract_id, symbol_short!("debit"), 3),
+        event(&e, &contract_id, symbol_short!("credit"), 2),
+        event(&e, &contract_id, symbol_short!("fee"), 1),
+    ];
+    for (i, expected) in expected.iter().enumerate() {
+        // Events are compared as Vecs, because Val has no PartialEq.
+        let nth = e.events().nth(i as u32).unwrap();
+        assert_eq!(vec![&e, nth], vec![&e, expected.clone()]);
+    }
+    assert!(e.events().nth(3).is_none());
+    assert_eq!(e.events().all(), Vec::from_array(&e, expected));
+}
@@ -0,0 +1,135 @@
+use crate::{
+    self as soroban_sdk, contract, contracterror, contractimpl,
+    testutils::fault_injection::HostOp,
+    xdr::{ScErrorCode, ScErrorType},
+    Address, Bytes, Env, Error,
+};
+
+#[contract]
+pub struct Store;
+
+#[contractimpl]
+impl Store {
+    pub fn store(env: Env, value: u32) {
+        env.storage().persistent().set(&1, &value);
+    }
+
+    pub fn load(env: Env) -> Option<u32> {
+        env.storage().persistent().get(&1)
+    }
+
+    pub fn hash(env: Env, b: Bytes) -> Bytes {
+        env.crypto().sha256(&b).into()
+    }
+}
+
+#[contracterror]
+#[derive(Debug, Eq, PartialEq)]
+pub enum StoreError {
+    StoreFailed = 1,
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    pub fn store_or_err(env: Env, store: Address, value: u32) -> Result<(), StoreError> {
+        match StoreClient::new(&env, &store).try_store(&value) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(StoreError::StoreFailed),
+        }
+    }
+}
+
+#[test]
+fn test_fail_next_storage_write() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+    let client = StoreClient::new(&e, &contract_id);
+
+    e.host_fault_injection().fail_next(HostOp::StorageWrite);
+    assert_eq!(e.host_fault_injection().pending(), 1);
+    assert_eq!(
+        client.try_store(&2),
+        Err(Ok(Error::from_type_and_code(
+            ScErrorType::Storage,
+            ScErrorCode::InvalidAction
+        )))
+    );
+    assert_eq!(e.host_fault_injection().pending(), 0);
+    assert_eq!(client.load(), None);
+
+    client.store(&2);
+    assert_eq!(client.load(), Some(2));
+}
+
+#[test]
+fn test_fail_next_only_matching_op() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+    let client = StoreClient::new(&e, &contract_id);
+
+    e.host_fault_injection().fail_next(HostOp::Crypto);
+    client.store(&3);
+    assert_eq!(client.load(), Some(3));
+    assert_eq!(e.host_fault_injection().pending(), 1);
+
+    assert_eq!(
+        client.try_hash(&Bytes::from_array(&e, &[1, 2, 3])),
+        Err(Ok(Error::from_type_and_code(
+            ScErrorType::Crypto,
+            ScErrorCode::InvalidAction
+        )))
+    );
+    assert_eq!(e.host_fault_injection().pending(), 0);
+}
+
+#[test]
+fn test_fail_next_with_error() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+    let client = StoreClient::new(&e, &contract_id);
+
+    e.host_fault_injection().fail_next_with(
+        HostOp::Function("get_contract_data"),
+        Error::from_contract_error(7),
+    );
+    client.store(&4);
+    assert_eq!(client.try_load(), Err(Ok(Error::from_contract_error(7))));
+    assert_eq!(client.load(), Some(4));
+}
+
+#[test]
+fn test_contract_error_path() {
+    let e = Env::default();
+    let store_id = e.register(Store, ());
+    let caller_id = e.register(Caller, ());
+    let caller = CallerClient::new(&e, &caller_id);
+
+    e.host_fault_injection().fail_next(HostOp::StorageWrite);
+    assert_eq!(
+        caller.try_store_or_err(&store_id, &5),
+        Err(Ok(StoreError::StoreFailed))
+    );
+    assert_eq!(StoreClient::new(&e, &store_id).load(), None);
+
+    assert_eq!(caller.try_store_or_err(&store_id, &5), Ok(Ok(())));
+    assert_eq!(StoreClient::new(&e, &store_id).load(), Some(5));
+}
+
+#[test]
+fn test_clear() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+    let client = StoreClient::new(&e, &contract_id);
+
+    e.host_fault_injection().fail_next(HostOp::StorageWrite);
+    e.host_fault_injection().fail_next(HostOp::StorageRead);
+    assert_eq!(e.host_fault_injection().pending(), 2);
+    e.host_fault_injection().clear();
+    assert_eq!(e.host_fault_injection().pending(), 0);
+
+    client.store(&6);
+    assert_eq!(client.load(), Some(6));
+}
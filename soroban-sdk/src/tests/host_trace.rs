@@ -0,0 +1,81 @@
+use crate::{self as soroban_sdk, contract, contractimpl, Address, Env};
+
+#[contract]
+pub struct Counter;
+
+#[contractimpl]
+impl Counter {
+    pub fn incr(env: Env) -> u32 {
+        let v: u32 = env.storage().persistent().get(&1).unwrap_or(0) + 1;
+        env.storage().persistent().set(&1, &v);
+        v
+    }
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    pub fn incr(env: Env, counter: Address) -> u32 {
+        CounterClient::new(&env, &counter).incr()
+    }
+}
+
+/// Returns the storage and invocation host calls in the trace.
+fn trace(e: &Env) -> std::vec::Vec<(&'static str, u32)> {
+    e.host_trace()
+        .iter()
+        .filter(|c| c.fn_name.ends_with("_contract_data") || c.fn_name == "call")
+        .map(|c| (c.fn_name, c.depth))
+        .collect()
+}
+
+#[test]
+fn test_host_trace_storage_ops_in_order() {
+    let e = Env::default();
+    let contract_id = e.register(Counter, ());
+    let client = CounterClient::new(&e, &contract_id);
+
+    assert!(e.host_trace().is_empty());
+
+    client.incr();
+    assert_eq!(
+        trace(&e),
+        [("has_contract_data", 1), ("put_contract_data", 1)]
+    );
+
+    // The trace only covers the last invocation.
+    client.incr();
+    assert_eq!(
+        trace(&e),
+        [
+            ("has_contract_data", 1),
+            ("get_contract_data", 1),
+            ("put_contract_data", 1)
+        ]
+    );
+
+    // Calls made outside of an invocation are not recorded.
+    e.as_contract(&contract_id, || {
+        let _: Option<u32> = e.storage().persistent().get(&1);
+    });
+    assert_eq!(trace(&e).len(), 3);
+}
+
+#[test]
+fn test_host_trace_cross_contract() {
+    let e = Env::default();
+    let counter_id = e.register(Counter, ());
+    let caller_id = e.register(Caller, ());
+
+    CallerClient::new(&e, &caller_id).incr(&counter_id);
+    assert_eq!(
+        trace(&e),
+        [
+            ("call", 1),
+            ("has_contract_data", 2),
+            ("put_contract_data", 2)
+        ]
+    );
+}
@@ -0,0 +1,102 @@
+use crate::{self as soroban_sdk, contract, contractimpl, symbol_short, vec, Address, Env, Symbol};
+
+#[contract]
+pub struct Store;
+
+#[contractimpl]
+impl Store {
+    pub fn get(env: Env) -> u32 {
+        env.storage().persistent().get(&1).unwrap_or(0)
+    }
+
+    pub fn get_and_bump(env: Env) -> u32 {
+        env.storage().instance().extend_ttl(1, 100);
+        env.storage().persistent().get(&1).unwrap_or(0)
+    }
+
+    pub fn set(env: Env, value: u32) {
+        env.storage().persistent().set(&1, &value);
+    }
+
+    pub fn notify(env: Env) -> u32 {
+        env.events().publish((symbol_short!("read"),), ());
+        env.storage().persistent().get(&1).unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    pub fn set_via(env: Env, store: Address, value: u32) {
+        StoreClient::new(&env, &store).set(&value);
+    }
+
+    pub fn try_set_via(env: Env, store: Address, value: u32) -> bool {
+        StoreClient::new(&env, &store).try_set(&value).is_ok()
+    }
+}
+
+#[test]
+fn test_invoke_view_read_only() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+    let client = StoreClient::new(&e, &contract_id);
+    client.set(&7);
+
+    let v: u32 = e.invoke_view(&contract_id, &symbol_short!("get"), vec![&e]);
+    assert_eq!(v, 7);
+    let v: u32 = e.invoke_view(&contract_id, &Symbol::new(&e, "get_and_bump"), vec![&e]);
+    assert_eq!(v, 7);
+
+    // Writes are allowed again once the view call has ended.
+    client.set(&8);
+    assert_eq!(client.get(), 8);
+}
+
+#[test]
+#[should_panic(expected = "attempted to mutate state: StorageWrite")]
+fn test_invoke_view_storage_write() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+
+    let _: () = e.invoke_view(&contract_id, &symbol_short!("set"), vec![&e, 1u32.into()]);
+}
+
+#[test]
+#[should_panic(expected = "attempted to mutate state: PublishEvent")]
+fn test_invoke_view_publish_event() {
+    let e = Env::default();
+    let contract_id = e.register(Store, ());
+
+    let _: u32 = e.invoke_view(&contract_id, &symbol_short!("notify"), vec![&e]);
+}
+
+#[test]
+#[should_panic(expected = "attempted to mutate state: StorageWrite")]
+fn test_invoke_view_nested_write() {
+    let e = Env::default();
+    let store_id = e.register(Store, ());
+    let caller_id = e.register(Caller, ());
+
+    let _: () = e.invoke_view(
+        &caller_id,
+        &symbol_short!("set_via"),
+        vec![&e, store_id.to_val(), 1u32.into()],
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempted to mutate state: StorageWrite")]
+fn test_invoke_view_recovered_write() {
+    let e = Env::default();
+    let store_id = e.register(Store, ());
+    let caller_id = e.register(Caller, ());
+
+    let _: bool = e.invoke_view(
+        &caller_id,
+        &Symbol::new(&e, "try_set_via"),
+        vec![&e, store_id.to_val(), 1u32.into()],
+    );
+}
@@ -0,0 +1,54 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, log, log_fields, symbol_short,
+    testutils::{Address as _, Logs as _},
+    Address, Env, Symbol,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        log!(&env, "transfer starting");
+        log_fields!(&env, "transfer", from => from, to => to, amount => amount);
+    }
+}
+
+#[test]
+fn test_log_fields() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    client.transfer(&from, &to, &123);
+
+    let entry = e.logs().find("transfer").unwrap();
+    assert_eq!(entry.message(), "transfer");
+    assert_eq!(entry.field::<Address>("from"), Some(from));
+    assert_eq!(entry.field::<Address>("to"), Some(to));
+    assert_eq!(entry.field::<i128>("amount"), Some(123));
+    assert_eq!(entry.field::<i128>("missing"), None);
+    // A field that is not convertible to the requested type is not returned.
+    assert_eq!(entry.field::<Symbol>("amount"), None);
+
+    let entry = e.logs().find("transfer starting").unwrap();
+    assert!(entry.args().is_empty());
+    assert_eq!(entry.field::<i128>("amount"), None);
+
+    assert!(e.logs().find("other").is_none());
+}
+
+#[test]
+fn test_find_most_recent() {
+    let e = Env::default();
+
+    log_fields!(&e, "count", n => 1u32);
+    log_fields!(&e, "count", n => 2u32, label => symbol_short!("two"),);
+
+    let entry = e.logs().find("count").unwrap();
+    assert_eq!(entry.field::<u32>("n"), Some(2));
+    assert_eq!(entry.field::<Symbol>("label"), Some(symbol_short!("two")));
+}
@@ -0,0 +1,62 @@
+use crate::{
+    self as soroban_sdk, bytes, contract, contractimpl, log, log_fields, symbol_short,
+    testutils::{Address as _, Logs as _},
+    vec, Address, Env, String,
+};
+
+extern crate std;
+use std::{format, string::ToString};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn transfer(env: Env, to: Address, amount: i128) {
+        log!(&env, "transfer", to, amount);
+    }
+}
+
+#[test]
+fn test_rendered_address_and_i128() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let to = Address::generate(&e);
+    client.transfer(&to, &-170_141_183_460_469_231_731_687_303_715_884_105_728);
+
+    let strkey = to.to_string().to_string();
+    assert_eq!(
+        e.logs().rendered(),
+        [format!(
+            "transfer: {strkey}, -170141183460469231731687303715884105728"
+        )]
+    );
+}
+
+#[test]
+fn test_rendered_values() {
+    let e = Env::default();
+
+    log!(&e, "no args");
+    log!(
+        &e,
+        "values",
+        symbol_short!("sym"),
+        String::from_str(&e, "text"),
+        bytes!(&e, 0x00ff10),
+        true,
+        vec![&e, 1u32, 2u32],
+    );
+    log_fields!(&e, "fields", a => 1u64, b => -2i32);
+
+    assert_eq!(
+        e.logs().rendered(),
+        [
+            "no args",
+            "values: sym, \"text\", 0x00ff10, true, [1, 2]",
+            "fields: {a: 1, b: -2}",
+        ]
+    );
+}
@@ -243,6 +243,57 @@ fn test_prng_gen_bytesn() {
     });
 }
 
+#[test]
+fn test_prng_gen_bytes_and_bytesn_helpers() {
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+
+    e.as_contract(&id, || {
+        let bytes = e.prng().gen_bytes(32);
+        assert_eq!(
+            bytes,
+            Bytes::from_array(
+                &e,
+                &[
+                    82, 78, 226, 155, 156, 113, 7, 94, 210, 231, 31, 49, 14, 38, 158, 29, 119, 169,
+                    67, 74, 0, 33, 229, 5, 124, 88, 142, 155, 100, 252, 88, 124
+                ]
+            )
+        );
+        let bytesn = e.prng().gen_bytesn::<32>();
+        assert_eq!(
+            bytesn,
+            BytesN::from_array(
+                &e,
+                &[
+                    135, 192, 91, 227, 201, 91, 94, 147, 96, 233, 24, 221, 122, 144, 212, 16, 4,
+                    136, 28, 41, 249, 105, 126, 159, 101, 184, 58, 122, 80, 8, 9, 250
+                ]
+            )
+        );
+        assert_eq!(e.prng().gen_bytes(0).len(), 0);
+        assert_eq!(e.prng().gen_bytes(7).len(), 7);
+        assert_eq!(e.prng().gen_bytesn::<5>().len(), 5);
+    });
+}
+
+#[test]
+fn test_prng_gen_bytes_deterministic_with_seed() {
+    let gen = |seed: [u8; 32]| {
+        let e = Env::default();
+        let id = e.register(TestPrngContract, ());
+        e.as_contract(&id, || {
+            e.prng().seed(Bytes::from_array(&e, &seed));
+            (
+                <[u8; 16]>::try_from(e.prng().gen_bytes(16)).unwrap(),
+                e.prng().gen_bytesn::<16>().to_array(),
+            )
+        })
+    };
+    assert_eq!(gen([1; 32]), gen([1; 32]));
+    assert_ne!(gen([1; 32]), gen([2; 32]));
+}
+
 #[test]
 fn test_prng_fill_slice() {
     let e = Env::default();
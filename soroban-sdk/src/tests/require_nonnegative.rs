@@ -0,0 +1,60 @@
+use crate::{self as soroban_sdk, contract, contractimpl, vec, Env, Vec};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn deposit(env: Env, amount: i128) -> i128 {
+        env.require_nonnegative(amount);
+        amount
+    }
+
+    pub fn deposit_all(env: Env, amounts: Vec<i128>) -> u32 {
+        env.require_all_nonnegative(&amounts);
+        amounts.len()
+    }
+}
+
+#[test]
+fn test_nonnegative() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert_eq!(client.deposit(&0), 0);
+    assert_eq!(client.deposit(&1), 1);
+    assert_eq!(client.deposit(&i128::MAX), i128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "amount must not be negative")]
+fn test_negative() {
+    let e = Env::default();
+    e.require_nonnegative(-1);
+}
+
+#[test]
+fn test_negative_in_contract() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert!(client.try_deposit(&-1).is_err());
+    assert!(client.try_deposit(&i128::MIN).is_err());
+}
+
+#[test]
+fn test_all_nonnegative() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert_eq!(client.deposit_all(&vec![&e]), 0);
+    assert_eq!(client.deposit_all(&vec![&e, 0, 5, 10]), 3);
+    assert!(client.try_deposit_all(&vec![&e, 0, 5, -10]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "amount must not be negative")]
+fn test_all_nonnegative_panics() {
+    let e = Env::default();
+    e.require_all_nonnegative(&vec![&e, 3, -2, 1]);
+}
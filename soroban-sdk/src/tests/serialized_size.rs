@@ -0,0 +1,53 @@
+use crate::{
+    self as soroban_sdk, bytes, contracttype, map, symbol_short, testutils::Address as _, vec,
+    xdr::ToXdr, Address, Bytes, Env, String,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    pub owner: Address,
+    pub amount: i128,
+    pub memo: String,
+}
+
+#[test]
+fn test_serialized_size_matches_xdr_len() {
+    let e = Env::default();
+
+    assert_eq!(e.serialized_size(&1u32), 1u32.to_xdr(&e).len());
+    assert_eq!(e.serialized_size(&-5i128), (-5i128).to_xdr(&e).len());
+    assert_eq!(
+        e.serialized_size(&symbol_short!("abc")),
+        symbol_short!("abc").to_xdr(&e).len()
+    );
+
+    let data = bytes!(&e, 0x0102030405);
+    assert_eq!(e.serialized_size(&data), data.clone().to_xdr(&e).len());
+
+    let list = vec![&e, 1u64, 2, 3];
+    assert_eq!(e.serialized_size(&list), list.clone().to_xdr(&e).len());
+
+    let m = map![&e, (1u32, true), (2u32, false)];
+    assert_eq!(e.serialized_size(&m), m.clone().to_xdr(&e).len());
+
+    let order = Order {
+        owner: Address::generate(&e),
+        amount: 100,
+        memo: String::from_str(&e, "hello"),
+    };
+    assert_eq!(e.serialized_size(&order), order.clone().to_xdr(&e).len());
+}
+
+#[test]
+fn test_serialized_size_grows_with_input() {
+    let e = Env::default();
+
+    // A u32 is its 4-byte type followed by the 4-byte value.
+    assert_eq!(e.serialized_size(&7u32), 8);
+
+    // Bytes are the type and length, followed by the contents padded to a
+    // multiple of four bytes.
+    assert_eq!(e.serialized_size(&Bytes::new(&e)), 8);
+    assert_eq!(e.serialized_size(&Bytes::from_array(&e, &[0; 5])), 16);
+}
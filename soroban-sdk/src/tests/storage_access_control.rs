@@ -0,0 +1,157 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl, storage::AccessControl, symbol_short,
+    testutils::Address as _, Address, Env, Symbol,
+};
+
+const MINTER: Symbol = symbol_short!("minter");
+const PAUSER: Symbol = symbol_short!("pauser");
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, admin: Address) {
+        AccessControl::new(&env).grant_role_unchecked(&AccessControl::DEFAULT_ADMIN_ROLE, &admin);
+    }
+
+    pub fn grant(env: Env, caller: Address, role: Symbol, account: Address) {
+        AccessControl::new(&env).grant_role(&caller, &role, &account);
+    }
+
+    pub fn revoke(env: Env, caller: Address, role: Symbol, account: Address) {
+        AccessControl::new(&env).revoke_role(&caller, &role, &account);
+    }
+
+    pub fn set_admin(env: Env, caller: Address, role: Symbol, admin_role: Symbol) {
+        AccessControl::new(&env).set_role_admin(&caller, &role, &admin_role);
+    }
+
+    pub fn has(env: Env, role: Symbol, account: Address) -> bool {
+        AccessControl::new(&env).has_role(&role, &account)
+    }
+
+    pub fn mint(env: Env, minter: Address) {
+        AccessControl::new(&env).require_role(&MINTER, &minter);
+    }
+}
+
+fn setup(e: &Env) -> (ContractClient<'_>, Address) {
+    let admin = Address::generate(e);
+    let client = ContractClient::new(e, &e.register(Contract, (&admin,)));
+    (client, admin)
+}
+
+#[test]
+fn test_grant_and_revoke() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup(&e);
+    let minter = Address::generate(&e);
+
+    assert!(client.has(&AccessControl::DEFAULT_ADMIN_ROLE, &admin));
+    assert!(!client.has(&MINTER, &minter));
+
+    client.grant(&admin, &MINTER, &minter);
+    assert!(client.has(&MINTER, &minter));
+    assert!(!client.has(&PAUSER, &minter));
+
+    client.revoke(&admin, &MINTER, &minter);
+    assert!(!client.has(&MINTER, &minter));
+}
+
+#[test]
+fn test_require_role_success() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup(&e);
+    let minter = Address::generate(&e);
+
+    client.grant(&admin, &MINTER, &minter);
+    client.mint(&minter);
+}
+
+#[test]
+#[should_panic(expected = "account does not hold the role")]
+fn test_require_role_without_role() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup(&e);
+
+    client.mint(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_require_role_without_auth() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let minter = Address::generate(&e);
+
+    e.mock_all_auths();
+    client.grant(&admin, &MINTER, &minter);
+    e.set_auths(&[]);
+    client.mint(&minter);
+}
+
+#[test]
+#[should_panic(expected = "account does not hold the role")]
+fn test_grant_by_non_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup(&e);
+    let other = Address::generate(&e);
+
+    client.grant(&other, &MINTER, &other);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_grant_without_admin_auth() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+
+    client.grant(&admin, &MINTER, &Address::generate(&e));
+}
+
+#[test]
+fn test_role_admin_hierarchy() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup(&e);
+    let pauser_admin = Address::generate(&e);
+    let pauser = Address::generate(&e);
+
+    // Holders of the minter role manage the pauser role.
+    client.set_admin(&admin, &PAUSER, &MINTER);
+    client.grant(&admin, &MINTER, &pauser_admin);
+    client.grant(&pauser_admin, &PAUSER, &pauser);
+    assert!(client.has(&PAUSER, &pauser));
+
+    // The default admin no longer manages the pauser role.
+    let res = client.try_revoke(&admin, &PAUSER, &pauser);
+    assert!(res.is_err());
+    assert!(client.has(&PAUSER, &pauser));
+
+    client.revoke(&pauser_admin, &PAUSER, &pauser);
+    assert!(!client.has(&PAUSER, &pauser));
+}
+
+#[test]
+fn test_reserved_keys() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup(&e);
+    let minter = Address::generate(&e);
+
+    client.grant(&admin, &MINTER, &minter);
+    client.set_admin(&admin, &PAUSER, &MINTER);
+    e.as_contract(&client.address, || {
+        let storage = e.storage().persistent();
+        assert!(storage.has(&(symbol_short!("Role"), MINTER, minter.clone())));
+        assert_eq!(
+            storage.get(&(symbol_short!("RoleAdmin"), PAUSER)),
+            Some(MINTER)
+        );
+    });
+}
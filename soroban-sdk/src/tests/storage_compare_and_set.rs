@@ -0,0 +1,68 @@
+use crate::{self as soroban_sdk, contract, symbol_short, Env, Symbol};
+
+#[contract]
+pub struct Contract;
+
+const KEY: Symbol = symbol_short!("key");
+
+#[test]
+fn test_compare_and_set_match() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        let storage = e.storage().persistent();
+        storage.set(&KEY, &1u32);
+        assert!(storage.compare_and_set(&KEY, Some(1u32), 2));
+        assert_eq!(storage.get(&KEY), Some(2u32));
+    });
+}
+
+#[test]
+fn test_compare_and_set_mismatch() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        let storage = e.storage().persistent();
+        storage.set(&KEY, &1u32);
+        assert!(!storage.compare_and_set(&KEY, Some(3u32), 2));
+        assert_eq!(storage.get(&KEY), Some(1u32));
+
+        // A value is stored, so expecting none does not match either.
+        assert!(!storage.compare_and_set(&KEY, None, 2u32));
+        assert_eq!(storage.get(&KEY), Some(1u32));
+    });
+}
+
+#[test]
+fn test_compare_and_set_expected_none() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        let storage = e.storage().persistent();
+        assert!(!storage.compare_and_set(&KEY, Some(1u32), 2));
+        assert!(!storage.has(&KEY));
+
+        assert!(storage.compare_and_set(&KEY, None, 1u32));
+        assert_eq!(storage.get(&KEY), Some(1u32));
+    });
+}
+
+#[test]
+fn test_compare_and_set_storage_types() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        assert!(e.storage().temporary().compare_and_set(&KEY, None, 1u32));
+        assert!(e.storage().instance().compare_and_set(&KEY, None, 2u32));
+        assert!(e.storage().temporary().compare_and_set(&KEY, Some(1u32), 3));
+        assert!(!e.storage().instance().compare_and_set(&KEY, Some(1u32), 3));
+
+        assert_eq!(e.storage().temporary().get(&KEY), Some(3u32));
+        assert_eq!(e.storage().instance().get(&KEY), Some(2u32));
+        assert!(!e.storage().persistent().has(&KEY));
+    });
+}
@@ -0,0 +1,79 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl,
+    storage::Idempotency,
+    symbol_short,
+    testutils::{storage::Temporary as _, Ledger},
+    BytesN, Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn relay(env: Env, key: BytesN<32>) -> bool {
+        Idempotency::new(&env, 100).guard(&key)
+    }
+}
+
+#[test]
+fn test_guard() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_temp_entry_ttl(10);
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    let a = BytesN::from_array(&e, &[1; 32]);
+    let b = BytesN::from_array(&e, &[2; 32]);
+    assert!(client.relay(&a));
+    assert!(!client.relay(&a));
+    assert!(client.relay(&b));
+    assert!(!client.relay(&b));
+}
+
+#[test]
+fn test_guard_expiry() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_temp_entry_ttl(10);
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let key = BytesN::from_array(&e, &[1; 32]);
+    assert!(client.relay(&key));
+
+    // The key stays recorded for the TTL.
+    e.ledger().set_sequence_number(1100);
+    assert!(!client.relay(&key));
+    e.as_contract(&contract_id, || {
+        assert!(Idempotency::new(&e, 100).is_recorded(&key));
+    });
+
+    // Once the record expires the key can be used again.
+    e.ledger().set_sequence_number(1101);
+    e.as_contract(&contract_id, || {
+        assert!(!Idempotency::new(&e, 100).is_recorded(&key));
+    });
+    assert!(client.relay(&key));
+    assert!(!client.relay(&key));
+}
+
+#[test]
+fn test_guard_beyond_max_ttl() {
+    let e = Env::default();
+    e.ledger().set_max_entry_ttl(1_000);
+    let contract_id = e.register(Contract, ());
+
+    let key = BytesN::from_array(&e, &[1; 32]);
+    e.as_contract(&contract_id, || {
+        let idempotency = Idempotency::new(&e, 100_000);
+        assert!(idempotency.guard(&key));
+        assert!(idempotency.is_recorded(&key));
+
+        let storage_key = (symbol_short!("Idempotcy"), key.clone());
+        assert_eq!(
+            e.storage().temporary().get_ttl(&storage_key),
+            e.storage().max_ttl()
+        );
+    });
+}
@@ -0,0 +1,71 @@
+use crate::{
+    self as soroban_sdk, contract, contracttype, testutils::Address as _, Address, Env, StorageKey,
+};
+
+#[contracttype]
+#[derive(StorageKey)]
+pub enum DataKey {
+    #[storage(value = u32)]
+    Counter,
+    #[storage(temporary, value = Address)]
+    Delegate(Address),
+    Unmanaged,
+}
+
+#[contract]
+pub struct Contract;
+
+#[test]
+fn test_load_save_remove() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+
+    e.as_contract(&contract_id, || {
+        assert_eq!(DataKey::load_counter(&e), None);
+        assert_eq!(DataKey::load_delegate(&e, &owner), None);
+
+        DataKey::save_counter(&e, &3);
+        DataKey::save_delegate(&e, &owner, &delegate);
+        assert_eq!(DataKey::load_counter(&e), Some(3));
+        assert_eq!(DataKey::load_delegate(&e, &owner), Some(delegate.clone()));
+
+        DataKey::remove_counter(&e);
+        assert_eq!(DataKey::load_counter(&e), None);
+        assert_eq!(DataKey::load_delegate(&e, &owner), Some(delegate.clone()));
+
+        DataKey::remove_delegate(&e, &owner);
+        assert_eq!(DataKey::load_delegate(&e, &owner), None);
+    });
+}
+
+#[test]
+fn test_storage_types() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+
+    e.as_contract(&contract_id, || {
+        DataKey::save_counter(&e, &7);
+        DataKey::save_delegate(&e, &owner, &delegate);
+
+        assert_eq!(
+            e.storage().persistent().get::<_, u32>(&DataKey::Counter),
+            Some(7)
+        );
+        assert!(!e.storage().temporary().has(&DataKey::Counter));
+        assert_eq!(
+            e.storage()
+                .temporary()
+                .get::<_, Address>(&DataKey::Delegate(owner.clone())),
+            Some(delegate)
+        );
+        assert!(!e
+            .storage()
+            .persistent()
+            .has(&DataKey::Delegate(owner.clone())));
+        assert!(!e.storage().persistent().has(&DataKey::Unmanaged));
+    });
+}
@@ -0,0 +1,62 @@
+use crate::{self as soroban_sdk, contract, storage::List, symbol_short, vec, Env, Symbol};
+
+#[contract]
+pub struct Contract;
+
+const ORDERS: Symbol = symbol_short!("orders");
+
+fn orders(e: &Env) -> List<u32> {
+    List::new(e, ORDERS)
+}
+
+/// Runs `f` with a list of the five items 0, 10, 20, 30 and 40.
+fn with_five_orders(f: impl FnOnce(&Env, List<u32>)) {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let list = orders(&e);
+        for i in 0..5 {
+            list.push_back(&(i * 10));
+        }
+        f(&e, list);
+    });
+}
+
+#[test]
+fn test_first_page() {
+    with_five_orders(|e, list| {
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.page(0, 2), vec![e, 0, 10]);
+        assert_eq!(list.page(2, 2), vec![e, 20, 30]);
+    });
+}
+
+#[test]
+fn test_last_partial_page() {
+    with_five_orders(|e, list| {
+        assert_eq!(list.page(4, 2), vec![e, 40]);
+        assert_eq!(list.page(3, u32::MAX), vec![e, 30, 40]);
+    });
+}
+
+#[test]
+fn test_offset_beyond_end() {
+    with_five_orders(|e, list| {
+        assert_eq!(list.page(5, 2), vec![e]);
+        assert_eq!(list.page(u32::MAX, 2), vec![e]);
+        assert_eq!(list.get(4), Some(40));
+        assert_eq!(list.get(5), None);
+    });
+}
+
+#[test]
+fn test_empty() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let list = orders(&e);
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert_eq!(list.page(0, 10), vec![&e]);
+    });
+}
@@ -0,0 +1,58 @@
+use crate as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+const BALANCE: Symbol = symbol_short!("BALANCE");
+
+#[contract]
+pub struct ContractA;
+
+#[contractimpl]
+impl ContractA {
+    pub fn deposit(env: Env, vault: Address, amount: i128) {
+        ContractBClient::new(&env, &vault).credit(&amount);
+    }
+}
+
+#[contract]
+pub struct ContractB;
+
+#[contractimpl]
+impl ContractB {
+    pub fn credit(env: Env, amount: i128) {
+        let balance: i128 = env.storage().persistent().get(&BALANCE).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&BALANCE, &(balance + amount));
+    }
+}
+
+#[test]
+fn test_read_storage_of_other_contract() {
+    let e = Env::default();
+    let a = e.register(ContractA, ());
+    let b = e.register(ContractB, ());
+    let client = ContractAClient::new(&e, &a);
+
+    client.deposit(&b, &10);
+    client.deposit(&b, &5);
+
+    let balance: Option<i128> = e.storage_of(&b, |s| s.persistent().get(&BALANCE));
+    assert_eq!(balance, Some(15));
+
+    // Contract A's storage is separate.
+    assert!(!e.storage_of(&a, |s| s.persistent().has(&BALANCE)));
+}
+
+#[test]
+fn test_write_storage_of_other_contract() {
+    let e = Env::default();
+    let a = e.register(ContractA, ());
+    let b = e.register(ContractB, ());
+    let client = ContractAClient::new(&e, &a);
+
+    e.storage_of(&b, |s| s.persistent().set(&BALANCE, &100i128));
+    client.deposit(&b, &1);
+
+    let balance: Option<i128> = e.storage_of(&b, |s| s.persistent().get(&BALANCE));
+    assert_eq!(balance, Some(101));
+}
@@ -0,0 +1,123 @@
+use crate::{
+    self as soroban_sdk, contract, contractimpl,
+    storage::Ownable,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, owner: Address) {
+        Ownable::new(&env).set_owner_unchecked(&owner);
+    }
+
+    pub fn owner(env: Env) -> Option<Address> {
+        Ownable::new(&env).owner()
+    }
+
+    pub fn pending(env: Env) -> Option<Address> {
+        Ownable::new(&env).pending_owner()
+    }
+
+    pub fn transfer(env: Env, new_owner: Address) {
+        Ownable::new(&env).transfer_ownership(&new_owner);
+    }
+
+    pub fn accept(env: Env) {
+        Ownable::new(&env).accept_ownership();
+    }
+}
+
+fn setup(e: &Env) -> (ContractClient<'_>, Address) {
+    let owner = Address::generate(e);
+    let client = ContractClient::new(e, &e.register(Contract, (&owner,)));
+    (client, owner)
+}
+
+#[test]
+fn test_transfer_and_accept() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, owner) = setup(&e);
+    let new_owner = Address::generate(&e);
+
+    assert_eq!(client.owner(), Some(owner.clone()));
+    assert_eq!(client.pending(), None);
+
+    // The owner does not change until the transfer is accepted.
+    client.transfer(&new_owner);
+    assert_eq!(e.auths()[0].0, owner);
+    assert_eq!(client.owner(), Some(owner.clone()));
+    assert_eq!(client.pending(), Some(new_owner.clone()));
+
+    client.accept();
+    assert_eq!(e.auths()[0].0, new_owner);
+    assert_eq!(client.owner(), Some(new_owner));
+    assert_eq!(client.pending(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_accept_by_wrong_address() {
+    let e = Env::default();
+    let (client, owner) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "transfer",
+                args: (&new_owner,).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .transfer(&new_owner);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &other,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "accept",
+                args: ().into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .accept();
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_transfer_by_non_owner() {
+    let e = Env::default();
+    let (client, _) = setup(&e);
+    let other = Address::generate(&e);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &other,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "transfer",
+                args: (&other,).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .transfer(&other);
+}
+
+#[test]
+#[should_panic(expected = "no ownership transfer is pending")]
+fn test_accept_without_pending_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup(&e);
+
+    client.accept();
+}
@@ -0,0 +1,77 @@
+use crate::{self as soroban_sdk, contract, storage::Queue, symbol_short, vec, Env, Symbol, Vec};
+
+#[contract]
+pub struct Contract;
+
+const JOBS: Symbol = symbol_short!("jobs");
+
+fn jobs(e: &Env) -> Queue<u32> {
+    Queue::new(e, JOBS)
+}
+
+#[test]
+fn test_fifo() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let queue = jobs(&e);
+        queue.push_back(&1);
+        queue.push_back(&2);
+        queue.push_back(&3);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop_front(), Some(1));
+        queue.push_back(&4);
+        assert_eq!(queue.pop_front(), Some(2));
+        assert_eq!(queue.pop_front(), Some(3));
+        assert_eq!(queue.pop_front(), Some(4));
+        assert_eq!(queue.pop_front(), None);
+        assert_eq!(queue.len(), 0);
+    });
+}
+
+#[test]
+fn test_drain_fewer_than_available() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let queue = jobs(&e);
+        for i in 0..5 {
+            queue.push_back(&i);
+        }
+        assert_eq!(queue.drain(2), vec![&e, 0, 1]);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.drain(10), vec![&e, 2, 3, 4]);
+        assert_eq!(queue.len(), 0);
+    });
+}
+
+#[test]
+fn test_drain_empty() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let queue = jobs(&e);
+        assert_eq!(queue.drain(3), Vec::<u32>::new(&e));
+        assert_eq!(queue.len(), 0);
+    });
+}
+
+#[test]
+fn test_consumed_entries_removed() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let queue = jobs(&e);
+        let storage = e.storage().persistent();
+
+        queue.push_back(&1);
+        queue.push_back(&2);
+        assert_eq!(queue.drain(1), vec![&e, 1]);
+        assert!(!storage.has(&(symbol_short!("Queue"), JOBS, 0u64)));
+        assert!(storage.has(&(symbol_short!("Queue"), JOBS, 1u64)));
+
+        assert_eq!(queue.pop_front(), Some(2));
+        assert!(!storage.has(&(symbol_short!("Queue"), JOBS, 1u64)));
+        assert!(!storage.has(&(symbol_short!("Queue"), JOBS)));
+    });
+}
@@ -0,0 +1,57 @@
+use crate::{self as soroban_sdk, contract, contractimpl, storage::SchemaVersion, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn version(env: Env) -> u32 {
+        SchemaVersion::new(&env).version()
+    }
+
+    pub fn migrate(env: Env, version: u32) {
+        SchemaVersion::new(&env).set_version(version);
+    }
+
+    pub fn read(env: Env, expected: u32) {
+        SchemaVersion::new(&env).require_version(expected);
+    }
+}
+
+#[test]
+fn test_unset_is_version_0() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert_eq!(client.version(), 0);
+    client.read(&0);
+}
+
+#[test]
+fn test_matching_version() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    client.migrate(&3);
+    assert_eq!(client.version(), 3);
+    client.read(&3);
+}
+
+#[test]
+#[should_panic(expected = "storage schema version does not match the expected version")]
+fn test_mismatched_version() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    client.migrate(&2);
+    client.read(&1);
+}
+
+#[test]
+#[should_panic(expected = "storage schema version does not match the expected version")]
+fn test_unset_mismatched_version() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    client.read(&1);
+}
@@ -0,0 +1,104 @@
+use crate::{
+    self as soroban_sdk, contract, contracttype, testutils::Address as _, Address, Env, Symbol,
+};
+
+#[contracttype(split_storage)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Account {
+    pub owner: Address,
+    pub balance: i128,
+    pub nonce: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Account(Address),
+}
+
+#[contract]
+pub struct Contract;
+
+#[test]
+fn test_fields() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let owner = Address::generate(&e);
+    let key = DataKey::Account(owner.clone());
+
+    e.as_contract(&contract_id, || {
+        assert_eq!(Account::load_balance(&e, &key), None);
+        assert_eq!(Account::load_fields(&e, &key), None);
+
+        Account::save_balance(&e, &key, &100);
+        assert_eq!(Account::load_balance(&e, &key), Some(100));
+        assert_eq!(Account::load_nonce(&e, &key), None);
+        // The struct is incomplete until every field is stored.
+        assert_eq!(Account::load_fields(&e, &key), None);
+
+        Account::save_owner(&e, &key, &owner);
+        Account::save_nonce(&e, &key, &7);
+        assert_eq!(
+            Account::load_fields(&e, &key),
+            Some(Account {
+                owner: owner.clone(),
+                balance: 100,
+                nonce: 7,
+            })
+        );
+
+        // Each field is stored in its own entry.
+        assert_eq!(
+            e.storage()
+                .persistent()
+                .get::<_, u64>(&(key.clone(), Symbol::new(&e, "nonce"))),
+            Some(7)
+        );
+        assert!(!e.storage().persistent().has(&key));
+    });
+}
+
+#[test]
+fn test_save_load_remove_fields() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+
+    e.as_contract(&contract_id, || {
+        let account_a = Account {
+            owner: a.clone(),
+            balance: 10,
+            nonce: 1,
+        };
+        let account_b = Account {
+            owner: b.clone(),
+            balance: 20,
+            nonce: 2,
+        };
+        account_a.save_fields(&e, &DataKey::Account(a.clone()));
+        account_b.save_fields(&e, &DataKey::Account(b.clone()));
+
+        // Updating one field leaves the others untouched.
+        Account::save_balance(&e, &DataKey::Account(a.clone()), &15);
+        assert_eq!(
+            Account::load_fields(&e, &DataKey::Account(a.clone())),
+            Some(Account {
+                balance: 15,
+                ..account_a
+            })
+        );
+        assert_eq!(
+            Account::load_fields(&e, &DataKey::Account(b.clone())),
+            Some(account_b.clone())
+        );
+
+        Account::remove_fields(&e, &DataKey::Account(a.clone()));
+        assert_eq!(Account::load_fields(&e, &DataKey::Account(a.clone())), None);
+        assert_eq!(Account::load_owner(&e, &DataKey::Account(a.clone())), None);
+        assert_eq!(
+            Account::load_fields(&e, &DataKey::Account(b.clone())),
+            Some(account_b)
+        );
+    });
+}
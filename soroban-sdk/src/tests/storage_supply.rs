@@ -0,0 +1,72 @@
+use crate::{self as soroban_sdk, contract, contractimpl, storage::Supply, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn mint(env: Env, amount: i128) -> i128 {
+        Supply::new(&env).mint(amount)
+    }
+
+    pub fn burn(env: Env, amount: i128) -> i128 {
+        Supply::new(&env).burn(amount)
+    }
+
+    pub fn total(env: Env) -> i128 {
+        Supply::new(&env).total()
+    }
+}
+
+#[test]
+fn test_mint_and_burn() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert_eq!(client.total(), 0);
+    assert_eq!(client.mint(&100), 100);
+    assert_eq!(client.mint(&50), 150);
+    assert_eq!(client.total(), 150);
+    assert_eq!(client.burn(&120), 30);
+    assert_eq!(client.burn(&30), 0);
+    assert_eq!(client.total(), 0);
+}
+
+#[test]
+#[should_panic(expected = "burn exceeds the total supply")]
+fn test_burn_more_than_supply() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    client.mint(&10);
+    client.burn(&11);
+}
+
+#[test]
+#[should_panic(expected = "mint overflows the total supply")]
+fn test_mint_overflow() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    assert_eq!(client.mint(&i128::MAX), i128::MAX);
+    client.mint(&1);
+}
+
+#[test]
+#[should_panic(expected = "amount must not be negative")]
+fn test_mint_negative() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    client.mint(&-1);
+}
+
+#[test]
+#[should_panic(expected = "amount must not be negative")]
+fn test_burn_negative() {
+    let e = Env::default();
+    let client = ContractClient::new(&e, &e.register(Contract, ()));
+
+    client.mint(&10);
+    client.burn(&-1);
+}
@@ -0,0 +1,59 @@
+use crate::{self as soroban_sdk, contract, storage::SymbolRegistry, symbol_short, Env, Symbol};
+
+#[contract]
+pub struct Contract;
+
+const ASSETS: Symbol = symbol_short!("assets");
+
+fn assets(e: &Env) -> SymbolRegistry {
+    SymbolRegistry::new(e, ASSETS)
+}
+
+#[test]
+fn test_first_assignment() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let registry = assets(&e);
+        assert_eq!(registry.len(), 0);
+        assert_eq!(registry.id_of(&symbol_short!("USDC")), 0);
+        assert_eq!(registry.id_of(&symbol_short!("EURC")), 1);
+        assert_eq!(registry.id_of(&symbol_short!("XLM")), 2);
+        assert_eq!(registry.len(), 3);
+    });
+}
+
+#[test]
+fn test_stable_relookup() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let registry = assets(&e);
+        assert_eq!(registry.id_of(&symbol_short!("USDC")), 0);
+        assert_eq!(registry.id_of(&symbol_short!("EURC")), 1);
+        assert_eq!(registry.id_of(&symbol_short!("USDC")), 0);
+        assert_eq!(registry.id_of(&symbol_short!("EURC")), 1);
+        assert_eq!(registry.len(), 2);
+
+        assert_eq!(registry.symbol_of(0), Some(symbol_short!("USDC")));
+        assert_eq!(registry.symbol_of(1), Some(symbol_short!("EURC")));
+
+        // Registries with different names assign ids independently.
+        let other = SymbolRegistry::new(&e, symbol_short!("other"));
+        assert_eq!(other.id_of(&symbol_short!("EURC")), 0);
+        assert_eq!(registry.id_of(&symbol_short!("EURC")), 1);
+    });
+}
+
+#[test]
+fn test_unknown_id() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        let registry = assets(&e);
+        assert_eq!(registry.symbol_of(0), None);
+        registry.id_of(&symbol_short!("USDC"));
+        assert_eq!(registry.symbol_of(1), None);
+        assert_eq!(registry.symbol_of(u32::MAX), None);
+    });
+}
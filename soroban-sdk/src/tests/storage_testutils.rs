@@ -1,14 +1,37 @@
 use crate::testutils::{Deployer, Ledger};
 use crate::{
     self as soroban_sdk,
-    testutils::storage::{Instance as _, Persistent as _, Temporary as _},
-    Map, Val,
+    storage::ReadOnlyStorage,
+    testutils::storage::{
+        Instance as _, Persistent as _, ReadOnlyStorage as _, Storage as _, StorageDump,
+        Temporary as _,
+    },
+    vec, Map, Val, Vec,
 };
-use soroban_sdk::{contract, Env};
+use soroban_sdk::{contract, contractimpl, Env};
 
 #[contract]
 pub struct Contract;
 
+#[contract]
+pub struct TempContract;
+
+#[contractimpl]
+impl TempContract {
+    pub fn store(env: Env, value: u32, ttl: u32) {
+        env.storage().temporary().set(&1, &value);
+        env.storage().temporary().extend_ttl(&1, ttl, ttl);
+    }
+
+    pub fn has(env: Env) -> bool {
+        env.storage().temporary().has(&1)
+    }
+
+    pub fn load(env: Env) -> Option<u32> {
+        env.storage().temporary().get(&1)
+    }
+}
+
 #[test]
 fn all() {
     let e = Env::default();
@@ -42,6 +65,77 @@ fn all() {
     });
 }
 
+#[test]
+fn dump() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let other = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        e.storage().instance().set(&1, &2);
+        e.storage().persistent().set(&10, &20);
+        e.storage().persistent().set(&11, &21);
+        e.storage().temporary().set(&100, &200);
+    });
+    // Another contract's storage is not included in the dump.
+    e.as_contract(&other, || {
+        e.storage().instance().set(&3, &4);
+        e.storage().persistent().set(&12, &22);
+        e.storage().temporary().set(&101, &201);
+    });
+
+    e.as_contract(&id, || {
+        assert_eq!(
+            e.storage().dump(),
+            StorageDump {
+                instance: Map::from_array(&e, [(1.into(), 2.into())]),
+                persistent: Map::from_array(&e, [(10.into(), 20.into()), (11.into(), 21.into())]),
+                temporary: Map::from_array(&e, [(100.into(), 200.into())]),
+            }
+        );
+        assert_eq!(
+            e.storage().dump().persistent,
+            e.storage().persistent().all()
+        );
+    });
+}
+
+#[contract]
+pub struct WriterContract;
+
+#[contractimpl]
+impl WriterContract {
+    pub fn write(env: Env) {
+        let _: Option<u32> = env.storage().persistent().get(&5);
+        env.storage().persistent().set(&2, &20u32);
+        env.storage().persistent().set(&1, &10u32);
+        env.storage().persistent().set(&1, &11u32);
+        env.storage().temporary().set(&3, &30u32);
+        env.storage().persistent().remove(&4);
+        env.storage().instance().set(&6, &60u32);
+    }
+}
+
+#[test]
+fn modified_keys() {
+    let e = Env::default();
+    let id = e.register(WriterContract, ());
+    let client = WriterContractClient::new(&e, &id);
+
+    // Entries written before the invocation are not included.
+    e.as_contract(&id, || {
+        e.storage().persistent().set(&4, &40u32);
+        e.storage().persistent().set(&5, &50u32);
+        e.storage().persistent().set(&7, &70u32);
+    });
+
+    client.write();
+
+    // Reads and instance storage writes are not included.
+    let expected: Vec<Val> = vec![&e, 1.into(), 2.into(), 3.into(), 4.into()];
+    e.as_contract(&id, || assert_eq!(e.storage().modified_keys(), expected));
+}
+
 #[test]
 fn ttl_getters() {
     let e = Env::default();
@@ -108,6 +202,43 @@ fn ttl_getters() {
     assert_eq!(e.deployer().get_contract_code_ttl(&contract_b), 2000);
 }
 
+#[test]
+fn readonly() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_persistent_entry_ttl(100);
+    e.ledger().set_min_temp_entry_ttl(10);
+    let contract = e.register(Contract, ());
+
+    fn read(storage: &ReadOnlyStorage, key: u32) -> Option<u32> {
+        if storage.has(&key) {
+            storage.get(&key)
+        } else {
+            None
+        }
+    }
+
+    e.as_contract(&contract, || {
+        e.storage().persistent().set(&1, &2);
+        e.storage().temporary().set(&1, &3);
+
+        let persistent = e.storage().persistent().as_readonly();
+        let temporary = e.storage().temporary().as_readonly();
+        assert_eq!(read(&persistent, 1), Some(2));
+        assert_eq!(read(&temporary, 1), Some(3));
+        assert_eq!(read(&persistent, 2), None);
+        assert_eq!(persistent.get_ttl(&1), 99);
+        assert_eq!(temporary.get_ttl(&1), 9);
+
+        // The view reads the current value, not a copy taken when it was
+        // created.
+        e.storage().persistent().set(&1, &4);
+        e.storage().temporary().remove(&1);
+        assert_eq!(read(&persistent, 1), Some(4));
+        assert!(!temporary.has(&1));
+    });
+}
+
 #[test]
 fn temp_entry_expiration() {
     let e = Env::default();
@@ -138,6 +269,30 @@ fn temp_entry_expiration() {
     });
 }
 
+#[test]
+fn temp_entry_expiration_after_ttl() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_temp_entry_ttl(10);
+    let contract = e.register(TempContract, ());
+    let client = TempContractClient::new(&e, &contract);
+
+    client.store(&7, &50);
+    e.as_contract(&contract, || {
+        assert_eq!(e.storage().temporary().get_ttl(&1), 50);
+    });
+
+    // The entry is live up to and including its live-until ledger.
+    e.ledger().set_sequence_number(1050);
+    assert!(client.has());
+    assert_eq!(client.load(), Some(7));
+
+    // The entry is gone once the ledger sequence passes its live-until ledger.
+    e.ledger().set_sequence_number(1051);
+    assert!(!client.has());
+    assert_eq!(client.load(), None);
+}
+
 #[test]
 #[should_panic(expected = "[testing-only] Accessed contract data key key that has been archived")]
 fn test_persistent_entry_expiration() {
@@ -159,6 +314,123 @@ fn test_persistent_entry_expiration() {
     });
 }
 
+#[test]
+fn test_persistent_get_and_extend() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_persistent_entry_ttl(100);
+
+    let contract = e.register(Contract, ());
+    e.as_contract(&contract, || {
+        let persistent = e.storage().persistent();
+        persistent.set(&1, &2);
+        assert_eq!(persistent.get_ttl(&1), 99);
+
+        // The TTL is not below the threshold, so it is not extended.
+        assert_eq!(persistent.get_and_extend::<_, u32>(&1, 50, 500), Some(2));
+        assert_eq!(persistent.get_ttl(&1), 99);
+
+        // The TTL is below the threshold, so it is extended.
+        assert_eq!(persistent.get_and_extend::<_, u32>(&1, 100, 500), Some(2));
+        assert_eq!(persistent.get_ttl(&1), 500);
+
+        assert_eq!(persistent.get_and_extend::<_, u32>(&2, 1000, 1000), None);
+    });
+}
+
+#[test]
+fn test_persistent_remove_many() {
+    let e = Env::default();
+    let contract = e.register(Contract, ());
+    e.as_contract(&contract, || {
+        let persistent = e.storage().persistent();
+        persistent.set(&1, &10);
+        persistent.set(&2, &20);
+        persistent.set(&3, &30);
+
+        // Keys 4 and 5 have no value stored and are skipped.
+        persistent.remove_many(&vec![&e, 1, 4, 3, 5]);
+        assert!(!persistent.has(&1));
+        assert!(!persistent.has(&3));
+        assert!(!persistent.has(&4));
+        assert_eq!(persistent.get::<_, u32>(&2), Some(20));
+
+        persistent.remove_many(&Vec::<u32>::new(&e));
+        assert_eq!(persistent.get::<_, u32>(&2), Some(20));
+    });
+}
+
+#[test]
+fn test_temporary_remove_many() {
+    let e = Env::default();
+    let contract = e.register(Contract, ());
+    e.as_contract(&contract, || {
+        let temporary = e.storage().temporary();
+        temporary.set(&1, &10);
+        temporary.set(&2, &20);
+
+        temporary.remove_many(&vec![&e, 2, 3, 1]);
+        assert!(!temporary.has(&1));
+        assert!(!temporary.has(&2));
+        assert!(!temporary.has(&3));
+    });
+}
+
+#[test]
+fn test_instance_get_and_extend() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_persistent_entry_ttl(100);
+
+    let contract = e.register(Contract, ());
+    e.as_contract(&contract, || {
+        let instance = e.storage().instance();
+        assert_eq!(instance.get_and_extend::<_, u32>(&1, 1000, 1000), None);
+        assert_eq!(instance.get_ttl(), 99);
+
+        instance.set(&1, &2);
+        assert_eq!(instance.get_and_extend::<_, u32>(&1, 50, 500), Some(2));
+        assert_eq!(instance.get_ttl(), 99);
+        assert_eq!(instance.get_and_extend::<_, u32>(&1, 100, 500), Some(2));
+        assert_eq!(instance.get_ttl(), 500);
+    });
+}
+
+#[test]
+fn test_persistent_simulate_archive_and_restore() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_persistent_entry_ttl(100);
+
+    let contract = e.register(Contract, ());
+    e.as_contract(&contract, || {
+        e.storage().persistent().set(&1, &2);
+        assert!(!e.storage().persistent().is_archived(&1));
+
+        e.storage().persistent().simulate_archive(&1);
+        assert!(e.storage().persistent().is_archived(&1));
+
+        e.storage().persistent().simulate_restore(&1);
+        assert!(!e.storage().persistent().is_archived(&1));
+        assert_eq!(e.storage().persistent().get_ttl(&1), 99);
+        assert_eq!(e.storage().persistent().get::<_, u32>(&1), Some(2));
+    });
+}
+
+#[test]
+#[should_panic(expected = "[testing-only] Accessed contract data key key that has been archived")]
+fn test_persistent_simulate_archive_blocks_access() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+
+    let contract = e.register(Contract, ());
+    e.as_contract(&contract, || {
+        e.storage().persistent().set(&1, &2);
+        e.storage().persistent().simulate_archive(&1);
+        let _ = e.storage().persistent().get::<_, u32>(&1);
+    });
+}
+
 #[test]
 #[should_panic(expected = "`env.as_contract()`")]
 fn useful_error_message_for_storage_access() {
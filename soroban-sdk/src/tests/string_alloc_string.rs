@@ -0,0 +1,25 @@
+#![cfg(feature = "alloc")]
+
+extern crate alloc;
+
+use crate::{Env, String};
+
+#[test]
+fn test_string_alloc_string_round_trip() {
+    let env = Env::default();
+
+    let s = String::from_str(&env, "a message ✓");
+    let alloc_string = s.to_alloc_string();
+
+    assert_eq!(alloc_string, "a message ✓");
+    assert_eq!(String::from_str(&env, &alloc_string), s);
+}
+
+#[test]
+fn test_string_alloc_string_invalid_utf8() {
+    let env = Env::default();
+
+    let s = String::from_bytes(&env, &[b'a', 0xff, b'b']);
+
+    assert_eq!(s.to_alloc_string(), "a\u{fffd}b");
+}
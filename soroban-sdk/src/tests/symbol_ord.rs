@@ -0,0 +1,47 @@
+use crate::{map, Env, Map, Symbol};
+
+#[test]
+fn test_map_keys_in_symbol_order() {
+    let e = Env::default();
+
+    let mut m = Map::<Symbol, u32>::new(&e);
+    for (i, s) in ["b", "ab", "a_very_long_symbol_name", "aB", "a", "a_b", "a1"]
+        .into_iter()
+        .enumerate()
+    {
+        m.set(Symbol::new(&e, s), i as u32);
+    }
+
+    let expected = ["a", "a1", "aB", "a_b", "a_very_long_symbol_name", "ab", "b"];
+    let keys = m.keys();
+    assert_eq!(keys.len(), expected.len() as u32);
+    for (key, s) in keys.iter().zip(expected) {
+        assert_eq!(key, Symbol::new(&e, s));
+    }
+
+    // The same symbols compare in the same order as the map keys.
+    for pair in expected.windows(2) {
+        assert!(Symbol::new(&e, pair[0]) < Symbol::new(&e, pair[1]));
+    }
+}
+
+#[test]
+fn test_map_with_symbol_keys_equal_regardless_of_insertion_order() {
+    let e = Env::default();
+
+    let a: Map<Symbol, u32> = map![
+        &e,
+        (Symbol::new(&e, "zeta"), 1),
+        (Symbol::new(&e, "Alpha"), 2),
+        (Symbol::new(&e, "a_long_symbol_key"), 3),
+    ];
+    let b: Map<Symbol, u32> = map![
+        &e,
+        (Symbol::new(&e, "a_long_symbol_key"), 3),
+        (Symbol::new(&e, "zeta"), 1),
+        (Symbol::new(&e, "Alpha"), 2),
+    ];
+    assert_eq!(a, b);
+    assert_eq!(a.keys(), b.keys());
+    assert_eq!(a.keys().first(), Some(Symbol::new(&e, "Alpha")));
+}
@@ -0,0 +1,153 @@
+use std::rc::Rc;
+
+use crate::{
+    testutils::{Address as _, StellarAssetContract, StellarAssetIssuer},
+    token::{StellarAssetClient, TokenClient},
+    xdr, Address, Env, TryFromVal,
+};
+
+#[test]
+fn test_all_balances_sum_to_total_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let asset = StellarAssetClient::new(&env, &sac.address());
+    let token = TokenClient::new(&env, &sac.address());
+
+    assert!(sac.all_balances().unwrap().is_empty());
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    asset.mint(&a, &1000);
+    asset.mint(&b, &500);
+    let total_supply = 1500;
+
+    token.transfer(&a, &c, &300);
+    token.transfer(&b, &c, &200);
+    token.transfer(&c, &a, &50);
+
+    let balances = sac.all_balances().unwrap();
+    assert_eq!(balances.len(), 3);
+    assert_eq!(balances.get(a.clone()), Some(750));
+    assert_eq!(balances.get(b.clone()), Some(300));
+    assert_eq!(balances.get(c.clone()), Some(450));
+    for (holder, balance) in balances.iter() {
+        assert_eq!(token.balance(&holder), balance);
+    }
+    assert_eq!(balances.values().iter().sum::<i128>(), total_supply);
+
+    // Balances of other assets are not included.
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &other.address()).mint(&a, &1);
+    assert_eq!(sac.all_balances().unwrap(), balances);
+    assert_eq!(other.all_balances().unwrap().len(), 1);
+}
+
+#[test]
+fn test_all_balances_trustlines_of_same_issuer() {
+    let env = Env::default();
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let xdr::ScAddress::Account(issuer) = xdr::ScAddress::from(&sac.issuer().address()) else {
+        unreachable!()
+    };
+
+    let holder = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256([1; 32])));
+    let put_trustline = |asset_code: [u8; 4], balance: i64| {
+        let asset = xdr::TrustLineAsset::CreditAlphanum4(xdr::AlphaNum4 {
+            asset_code: xdr::AssetCode4(asset_code),
+            issuer: issuer.clone(),
+        });
+        let key = Rc::new(xdr::LedgerKey::Trustline(xdr::LedgerKeyTrustLine {
+            account_id: holder.clone(),
+            asset: asset.clone(),
+        }));
+        let entry = Rc::new(xdr::LedgerEntry {
+            data: xdr::LedgerEntryData::Trustline(xdr::TrustLineEntry {
+                account_id: holder.clone(),
+                asset,
+                balance,
+                limit: i64::MAX,
+                flags: xdr::TrustLineFlags::AuthorizedFlag as u32,
+                ext: xdr::TrustLineEntryExt::V0,
+            }),
+            last_modified_ledger_seq: 0,
+            ext: xdr::LedgerEntryExt::V0,
+        });
+        env.host()
+            .with_mut_storage(|s| {
+                s.put(
+                    &key,
+                    &entry,
+                    None,
+                    soroban_env_host::budget::AsBudget::as_budget(env.host()),
+                )
+            })
+            .unwrap();
+    };
+    // The asset registered by the test Env, and another asset of the issuer.
+    put_trustline([b'a', b'a', b'a', 0], 7);
+    put_trustline([b'b', b'b', b'b', 0], 9);
+
+    let holder = Address::try_from_val(&env, &xdr::ScAddress::Account(holder.clone())).unwrap();
+    let balances = sac.all_balances().unwrap();
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances.get(holder), Some(7));
+}
+
+#[test]
+fn test_all_balances_native() {
+    let env = Env::default();
+    let create = xdr::HostFunction::CreateContract(xdr::CreateContractArgs {
+        contract_id_preimage: xdr::ContractIdPreimage::Asset(xdr::Asset::Native),
+        executable: xdr::ContractExecutable::StellarAsset,
+    });
+    let address =
+        Address::try_from_val(&env, &env.host().invoke_function(create).unwrap()).unwrap();
+    let account =
+        |n: u8| xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256([n; 32])));
+    let issuer = StellarAssetIssuer::new(env.clone(), account(0));
+    let sac = StellarAssetContract::new(address, issuer, xdr::Asset::Native);
+
+    let put_account = |account_id: xdr::AccountId, balance: i64| {
+        let key = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+            account_id: account_id.clone(),
+        }));
+        let entry = Rc::new(xdr::LedgerEntry {
+            data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
+                account_id,
+                balance,
+                flags: 0,
+                home_domain: Default::default(),
+                inflation_dest: None,
+                num_sub_entries: 0,
+                seq_num: xdr::SequenceNumber(0),
+                thresholds: xdr::Thresholds([1; 4]),
+                signers: xdr::VecM::default(),
+                ext: xdr::AccountEntryExt::V0,
+            }),
+            last_modified_ledger_seq: 0,
+            ext: xdr::LedgerEntryExt::V0,
+        });
+        env.host()
+            .with_mut_storage(|s| {
+                s.put(
+                    &key,
+                    &entry,
+                    None,
+                    soroban_env_host::budget::AsBudget::as_budget(env.host()),
+                )
+            })
+            .unwrap();
+    };
+    put_account(account(1), 100);
+    put_account(account(2), 250);
+
+    let holder = |n: u8| Address::try_from_val(&env, &xdr::ScAddress::Account(account(n))).unwrap();
+    let balances = sac.all_balances().unwrap();
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances.get(holder(1)), Some(100));
+    assert_eq!(balances.get(holder(2)), Some(250));
+}
@@ -0,0 +1,147 @@
+use crate::{
+    self as soroban_sdk, contract, symbol_short,
+    testutils::{storage::Temporary as _, Address as _, Ledger},
+    token::{Allowance, AllowanceError},
+    Address, Env,
+};
+
+#[contract]
+pub struct Contract;
+
+fn setup() -> (Env, Address, Address, Address) {
+    let env = Env::default();
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(Contract, ());
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    (env, contract_id, owner, spender)
+}
+
+#[test]
+fn test_spend_within_limit() {
+    let (env, contract_id, owner, spender) = setup();
+    env.as_contract(&contract_id, || {
+        let allowance = Allowance::new(&env, &owner);
+        assert_eq!(allowance.remaining(&spender), 0);
+        assert_eq!(allowance.expiration_ledger(&spender), None);
+
+        allowance.set(&spender, 100, 200);
+        assert_eq!(allowance.remaining(&spender), 100);
+        assert_eq!(allowance.expiration_ledger(&spender), Some(200));
+
+        assert_eq!(allowance.spend(&spender, 30), Ok(()));
+        assert_eq!(allowance.spend(&spender, 0), Ok(()));
+        assert_eq!(allowance.remaining(&spender), 70);
+        assert_eq!(allowance.spend(&spender, 70), Ok(()));
+        assert_eq!(allowance.remaining(&spender), 0);
+        assert_eq!(allowance.expiration_ledger(&spender), Some(200));
+
+        // Allowances are per owner and spender.
+        let other = Address::generate(&env);
+        allowance.set(&other, 5, 200);
+        assert_eq!(allowance.remaining(&other), 5);
+        assert_eq!(Allowance::new(&env, &other).remaining(&spender), 0);
+    });
+}
+
+#[test]
+fn test_spend_over_limit() {
+    let (env, contract_id, owner, spender) = setup();
+    env.as_contract(&contract_id, || {
+        let allowance = Allowance::new(&env, &owner);
+        assert_eq!(
+            allowance.spend(&spender, 1),
+            Err(AllowanceError::Insufficient)
+        );
+
+        allowance.set(&spender, 100, 200);
+        assert_eq!(
+            allowance.spend(&spender, 101),
+            Err(AllowanceError::Insufficient)
+        );
+        assert_eq!(
+            allowance.spend(&spender, -1),
+            Err(AllowanceError::NegativeAmount)
+        );
+        assert_eq!(allowance.remaining(&spender), 100);
+    });
+}
+
+#[test]
+fn test_expired() {
+    let (env, contract_id, owner, spender) = setup();
+    env.as_contract(&contract_id, || {
+        let allowance = Allowance::new(&env, &owner);
+        allowance.set(&spender, 100, 150);
+
+        // The allowance can be spent up to and including the expiration
+        // ledger.
+        env.ledger().set_sequence_number(150);
+        assert_eq!(allowance.spend(&spender, 10), Ok(()));
+        assert_eq!(allowance.remaining(&spender), 90);
+
+        env.ledger().set_sequence_number(151);
+        assert_eq!(allowance.remaining(&spender), 0);
+        assert_eq!(
+            allowance.spend(&spender, 10),
+            Err(AllowanceError::Insufficient)
+        );
+    });
+}
+
+#[test]
+fn test_set_replaces_allowance() {
+    let (env, contract_id, owner, spender) = setup();
+    env.as_contract(&contract_id, || {
+        let allowance = Allowance::new(&env, &owner);
+        allowance.set(&spender, 100, 200);
+        allowance.spend(&spender, 60).unwrap();
+
+        allowance.set(&spender, 50, 300);
+        assert_eq!(allowance.remaining(&spender), 50);
+        assert_eq!(allowance.expiration_ledger(&spender), Some(300));
+
+        allowance.set(&spender, 0, 0);
+        assert_eq!(allowance.remaining(&spender), 0);
+        assert_eq!(allowance.expiration_ledger(&spender), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "expiration_ledger is less than ledger seq when amount > 0")]
+fn test_set_expired() {
+    let (env, contract_id, owner, spender) = setup();
+    env.as_contract(&contract_id, || {
+        Allowance::new(&env, &owner).set(&spender, 100, 99);
+    });
+}
+
+#[test]
+fn test_set_beyond_max_ttl() {
+    let (env, contract_id, owner, spender) = setup();
+    env.ledger().set_max_entry_ttl(1_000);
+    env.as_contract(&contract_id, || {
+        let allowance = Allowance::new(&env, &owner);
+        allowance.set(&spender, 100, 100_000);
+        assert_eq!(allowance.remaining(&spender), 100);
+        assert_eq!(allowance.expiration_ledger(&spender), Some(100_000));
+
+        let key = (symbol_short!("Allowance"), owner.clone(), spender.clone());
+        assert_eq!(
+            env.storage().temporary().get_ttl(&key),
+            env.storage().max_ttl()
+        );
+    });
+}
+
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        crate::Error::from(AllowanceError::Insufficient),
+        crate::Error::from_contract_error(1)
+    );
+    assert_eq!(
+        crate::Error::from(AllowanceError::NegativeAmount),
+        crate::Error::from_contract_error(2)
+    );
+}
@@ -0,0 +1,38 @@
+use crate::{
+    symbol_short, xdr::ScValType, Address, ConversionError, Env, IntoVal, Symbol, TryAs,
+    TypeMismatchError, Val,
+};
+
+extern crate std;
+use std::string::ToString;
+
+#[test]
+fn test_try_as_matching_type() {
+    let e = Env::default();
+
+    let val: Val = symbol_short!("hello").into_val(&e);
+    assert_eq!(val.try_as::<Symbol>(&e), Ok(symbol_short!("hello")));
+
+    let val: Val = (-3i128).into_val(&e);
+    assert_eq!(val.try_as::<i128>(&e), Ok(-3));
+}
+
+#[test]
+fn test_try_as_mismatched_type() {
+    let e = Env::default();
+    let val: Val = 7u64.into_val(&e);
+
+    let err = val.try_as::<Address>(&e).unwrap_err();
+    assert_eq!(
+        err,
+        TypeMismatchError {
+            expected: "soroban_sdk::address::Address",
+            actual: ScValType::U64,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "value of type U64 can't be converted to soroban_sdk::address::Address"
+    );
+    assert_eq!(ConversionError::from(err), ConversionError);
+}
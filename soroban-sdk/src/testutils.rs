@@ -12,7 +12,8 @@ pub use sign::ed25519;
 
 mod mock_auth;
 pub use mock_auth::{
-    AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthContract, MockAuthInvoke,
+    AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthContract, MockAuthEntry,
+    MockAuthInvoke,
 };
 use soroban_env_host::TryIntoVal;
 
@@ -20,6 +21,10 @@ pub mod storage;
 
 pub mod cost_estimate;
 
+pub mod fault_injection;
+
+pub mod host_trace;
+
 use crate::{xdr, ConstructorArgs, Env, Val, Vec};
 use soroban_ledger_snapshot::LedgerSnapshot;
 
@@ -349,6 +354,20 @@ pub mod budget {
             self.0.reset_unlimited().unwrap();
         }
 
+        /// Reset the budget, limiting the CPU instructions and memory bytes
+        /// that may be consumed from now on.
+        ///
+        /// The budget is shared by every contract invoked within the
+        /// environment, in the same way that the budget of a transaction is
+        /// shared by every contract it invokes. Exceeding the limits fails
+        /// with an `Error(Budget, ExceededLimit)` that aborts the whole
+        /// invocation: it cannot be caught by a caller using a `try_`
+        /// function, because on the network no contract can continue once the
+        /// transaction's budget is exhausted.
+        ///
+        /// To test how a contract behaves when a contract it calls consumes a
+        /// large amount of resources, the limits can be reset from within the
+        /// calling contract, immediately before the call.
         pub fn reset_limits(&mut self, cpu: u64, mem: u64) {
             self.0.reset_limits(cpu, mem).unwrap();
         }
@@ -396,21 +415,207 @@ pub mod budget {
 pub trait Events {
     /// Returns all events that have been published by contracts.
     ///
+    /// Events are returned in the exact order they were published, including
+    /// across contracts called during an invocation, so tests can assert the
+    /// sequence of events an operation emits.
+    ///
     /// Returns a [`Vec`] of three element tuples containing:
     /// - Contract ID
     /// - Event Topics as a [`Vec<Val>`]
     /// - Event Data as a [`Val`]
     fn all(&self) -> Vec<(crate::Address, Vec<Val>, Val)>;
+
+    /// Returns the event at the position in [`all`][Events::all], or None if
+    /// fewer events have been published.
+    fn nth(&self, i: u32) -> Option<(crate::Address, Vec<Val>, Val)>;
+
+    /// Asserts that the contract has published an event with the topics and
+    /// data.
+    ///
+    /// ### Panics
+    ///
+    /// If no such event has been published. The panic message lists the
+    /// events that have been published.
+    fn assert_published<T, D>(&self, contract: &crate::Address, topics: T, data: D)
+    where
+        T: crate::events::Topics,
+        D: crate::IntoVal<Env, Val>;
+
+    /// Asserts that the contract has not published an event with the topics
+    /// and data.
+    ///
+    /// ### Panics
+    ///
+    /// If such an event has been published.
+    fn assert_not_published<T, D>(&self, contract: &crate::Address, topics: T, data: D)
+    where
+        T: crate::events::Topics,
+        D: crate::IntoVal<Env, Val>;
 }
 
 /// Test utilities for [`Logs`][crate::logs::Logs].
 pub trait Logs {
     /// Returns all diagnostic events that have been logged.
     fn all(&self) -> std::vec::Vec<String>;
+    /// Returns all logs, each rendered as its message followed by its
+    /// arguments as readable text, e.g. `minted: 100, USDC`.
+    ///
+    /// Numbers are rendered in decimal, symbols as their text, strings
+    /// quoted, bytes in hex, and addresses as strkeys. Vecs and maps render
+    /// their contents.
+    fn rendered(&self) -> std::vec::Vec<String>;
+    /// Returns the most recent log entry with the message, if any.
+    ///
+    /// Use [`LogEntry::field`] to access the fields logged with
+    /// [`log_fields`][crate::log_fields].
+    fn find(&self, message: &str) -> Option<LogEntry>;
     /// Prints all diagnostic events to stdout.
     fn print(&self);
 }
 
+/// A log entry returned by [`Logs::find`].
+#[derive(Clone)]
+pub struct LogEntry {
+    message: std::string::String,
+    args: Vec<Val>,
+}
+
+impl LogEntry {
+    pub(crate) fn new(message: &str, args: Vec<Val>) -> Self {
+        Self {
+            message: message.to_owned(),
+            args,
+        }
+    }
+
+    /// Returns the message of the log entry.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the values logged after the message.
+    pub fn args(&self) -> Vec<Val> {
+        self.args.clone()
+    }
+
+    /// Returns the value of the field with the name, if the entry was logged
+    /// with [`log_fields`][crate::log_fields] and has the field.
+    ///
+    /// Returns `None` if the field is missing or is not convertible to `T`.
+    pub fn field<T>(&self, name: &str) -> Option<T>
+    where
+        T: crate::TryFromVal<Env, Val>,
+    {
+        use crate::TryFromVal;
+        let env = self.args.env();
+        if self.args.len() != 1 {
+            return None;
+        }
+        let fields =
+            crate::Map::<crate::Symbol, Val>::try_from_val(env, &self.args.get(0)?).ok()?;
+        let value = fields.get(crate::Symbol::new(env, name))?;
+        T::try_from_val(env, &value).ok()
+    }
+}
+
+impl core::fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::TryFromVal;
+        write!(f, "{}", self.message)?;
+        let env = self.args.env();
+        for (i, arg) in self.args.iter().enumerate() {
+            let arg = xdr::ScVal::try_from_val(env, &arg).map_err(|_| core::fmt::Error)?;
+            let sep = if i == 0 { ": " } else { ", " };
+            write!(f, "{sep}{}", render_scval(&arg))?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders the value as text that is readable in test output.
+fn render_scval(v: &xdr::ScVal) -> std::string::String {
+    use std::string::ToString;
+    use stellar_strkey::{ed25519, Contract, Strkey};
+    use xdr::ScVal;
+    match v {
+        ScVal::Bool(b) => b.to_string(),
+        ScVal::Void => "()".to_string(),
+        ScVal::U32(n) => n.to_string(),
+        ScVal::I32(n) => n.to_string(),
+        ScVal::U64(n) => n.to_string(),
+        ScVal::I64(n) => n.to_string(),
+        ScVal::Timepoint(xdr::TimePoint(n)) => n.to_string(),
+        ScVal::Duration(xdr::Duration(n)) => n.to_string(),
+        ScVal::U128(p) => ((u128::from(p.hi) << 64) | u128::from(p.lo)).to_string(),
+        ScVal::I128(p) => ((i128::from(p.hi) << 64) | i128::from(p.lo)).to_string(),
+        ScVal::Symbol(s) => s.0.to_utf8_string_lossy(),
+        ScVal::String(s) => std::format!("{:?}", s.0.to_utf8_string_lossy()),
+        ScVal::Bytes(b) => {
+            let mut hex = std::string::String::from("0x");
+            for byte in b.0.iter() {
+                hex.push_str(&std::format!("{byte:02x}"));
+            }
+            hex
+        }
+        ScVal::Address(xdr::ScAddress::Account(xdr::AccountId(
+            xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(key)),
+        ))) => Strkey::PublicKeyEd25519(ed25519::PublicKey(*key)).to_string(),
+        ScVal::Address(xdr::ScAddress::Contract(xdr::Hash(id))) => {
+            Strkey::Contract(Contract(*id)).to_string()
+        }
+        ScVal::Vec(Some(items)) => {
+            let items: std::vec::Vec<_> = items.0.iter().map(render_scval).collect();
+            std::format!("[{}]", items.join(", "))
+        }
+        ScVal::Map(Some(entries)) => {
+            let entries: std::vec::Vec<_> = entries
+                .0
+                .iter()
+                .map(|e| std::format!("{}: {}", render_scval(&e.key), render_scval(&e.val)))
+                .collect();
+            std::format!("{{{}}}", entries.join(", "))
+        }
+        v => std::format!("{v:?}"),
+    }
+}
+
+/// Test utilities for the authorizations returned by
+/// [`Env::auths`][crate::Env::auths].
+pub trait Auths {
+    /// Asserts that the recorded authorizations are exactly the expected
+    /// authorizations, including the addresses, the invocations, and how the
+    /// invocations are nested.
+    ///
+    /// Unlike checking that an expected authorization is present, this also
+    /// fails when an authorization was required that isn't expected.
+    ///
+    /// ### Panics
+    ///
+    /// If the recorded authorizations differ from the expected. The panic
+    /// message lists the expected authorizations that are missing, the
+    /// recorded authorizations that are unexpected, and all the recorded
+    /// authorizations.
+    fn assert_eq(&self, expected: &[(crate::Address, AuthorizedInvocation)]);
+}
+
+impl Auths for std::vec::Vec<(crate::Address, AuthorizedInvocation)> {
+    #[track_caller]
+    fn assert_eq(&self, expected: &[(crate::Address, AuthorizedInvocation)]) {
+        if self.as_slice() == expected {
+            return;
+        }
+        let missing: std::vec::Vec<_> = expected.iter().filter(|a| !self.contains(a)).collect();
+        let unexpected: std::vec::Vec<_> = self.iter().filter(|a| !expected.contains(a)).collect();
+        panic!(
+            "recorded authorizations do not match the expected authorizations\n\
+             missing: {missing:#?}\n\
+             unexpected: {unexpected:#?}\n\
+             recorded: {:#?}",
+            self
+        );
+    }
+}
+
 /// Test utilities for [`BytesN`][crate::BytesN].
 pub trait BytesN<const N: usize> {
     // Generate a BytesN filled with random bytes.
@@ -456,6 +661,79 @@ pub trait Deployer {
     /// Panics if there is no contract instance/code corresponding to
     /// the provided address, or if the instance/code has expired.
     fn get_contract_code_ttl(&self, contract: &crate::Address) -> u32;
+
+    /// Gets the hash of the Wasm that the given contract's instance is
+    /// running.
+    ///
+    /// Returns None if the contract is a Stellar Asset Contract, or if there
+    /// is no contract instance corresponding to the provided address.
+    ///
+    /// The instance entry is looked up directly in the test host's storage
+    /// rather than through a host function, so the read is not recorded in
+    /// the footprint.
+    fn get_contract_executable(&self, contract: &crate::Address) -> Option<crate::BytesN<32>>;
+}
+
+/// Asserts that a contract call made with a `try_` client function failed
+/// with the contract error.
+///
+/// ### Panics
+///
+/// If the call succeeded, or failed with a different error.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contracterror, contractimpl, testutils::assert_contract_err, Env};
+///
+/// #[contracterror]
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// pub enum Error {
+///     Unauthorized = 1,
+/// }
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn withdraw(_env: Env) -> Result<(), Error> {
+///         Err(Error::Unauthorized)
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     assert_contract_err(client.try_withdraw(), Error::Unauthorized);
+/// }
+/// ```
+#[track_caller]
+pub fn assert_contract_err<T, C, E>(
+    result: Result<Result<T, C>, Result<E, crate::InvokeError>>,
+    expected: E,
+) where
+    T: core::fmt::Debug,
+    C: core::fmt::Debug,
+    E: core::fmt::Debug + PartialEq,
+{
+    match result {
+        Err(Ok(err)) if err == expected => {}
+        Err(Ok(err)) => {
+            panic!("expected the call to fail with {expected:?}, but it failed with {err:?}")
+        }
+        Err(Err(err)) => {
+            panic!("expected the call to fail with {expected:?}, but it failed with {err:?}")
+        }
+        Ok(res) => {
+            panic!("expected the call to fail with {expected:?}, but it succeeded with {res:?}")
+        }
+    }
 }
 
 pub use xdr::AccountFlags as IssuerFlags;
@@ -556,11 +834,20 @@ impl StellarAssetIssuer {
 pub struct StellarAssetContract {
     address: crate::Address,
     issuer: StellarAssetIssuer,
+    asset: xdr::Asset,
 }
 
 impl StellarAssetContract {
-    pub(crate) fn new(address: crate::Address, issuer: StellarAssetIssuer) -> Self {
-        Self { address, issuer }
+    pub(crate) fn new(
+        address: crate::Address,
+        issuer: StellarAssetIssuer,
+        asset: xdr::Asset,
+    ) -> Self {
+        Self {
+            address,
+            issuer,
+            asset,
+        }
     }
 
     pub fn address(&self) -> crate::Address {
@@ -570,4 +857,102 @@ impl StellarAssetContract {
     pub fn issuer(&self) -> StellarAssetIssuer {
         self.issuer.clone()
     }
+
+    /// Returns the balances of all addresses holding the asset.
+    ///
+    /// Contract addresses are included from the balance entries stored by the
+    /// contract, and account addresses from the trustlines for the asset, or
+    /// from the account entries themselves for the native asset. Holders whose
+    /// balance entry exists but is zero are included.
+    ///
+    /// Returns an error if a balance entry stored by the contract can't be
+    /// decoded.
+    pub fn all_balances(&self) -> Result<crate::Map<crate::Address, i128>, crate::ConversionError> {
+        let env = &self.issuer.env;
+        let contract_address: xdr::ScAddress = (&self.address).into();
+        let trustline_asset = match &self.asset {
+            xdr::Asset::Native => xdr::TrustLineAsset::Native,
+            xdr::Asset::CreditAlphanum4(a) => xdr::TrustLineAsset::CreditAlphanum4(a.clone()),
+            xdr::Asset::CreditAlphanum12(a) => xdr::TrustLineAsset::CreditAlphanum12(a.clone()),
+        };
+        env.host()
+            .with_mut_storage(|s| {
+                let mut balances = crate::Map::<crate::Address, i128>::new(env);
+                let budget = soroban_env_host::budget::AsBudget::as_budget(env.host());
+                for (_, entry) in s.map.iter(budget)? {
+                    let Some((v, _)) = entry else {
+                        continue;
+                    };
+                    match self.balance_of_entry(&v.data, &contract_address, &trustline_asset) {
+                        Ok(Some((holder, amount))) => balances.set(holder, amount),
+                        Ok(None) => (),
+                        Err(e) => return Ok(Err(e)),
+                    }
+                }
+                Ok(Ok(balances))
+            })
+            .unwrap()
+    }
+
+    /// Returns the holder and balance in the ledger entry, if it is a balance
+    /// entry of the contract, a trustline for the asset, or an account entry
+    /// when the asset is native.
+    fn balance_of_entry(
+        &self,
+        data: &xdr::LedgerEntryData,
+        contract_address: &xdr::ScAddress,
+        trustline_asset: &xdr::TrustLineAsset,
+    ) -> Result<Option<(crate::Address, i128)>, crate::ConversionError> {
+        use crate::TryFromVal;
+        let env = &self.issuer.env;
+        match data {
+            xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+                contract,
+                key,
+                val,
+                ..
+            }) if contract == contract_address => {
+                let Ok(key) = Val::try_from_val(env, key) else {
+                    return Ok(None);
+                };
+                let Ok((sym, holder)) = <(crate::Symbol, crate::Address)>::try_from_val(env, &key)
+                else {
+                    return Ok(None);
+                };
+                if sym != soroban_sdk_macros::internal_symbol_short!("Balance") {
+                    return Ok(None);
+                }
+                let val = Val::try_from_val(env, val)?;
+                let value = crate::Map::<crate::Symbol, Val>::try_from_val(env, &val)?;
+                let amount = value
+                    .get(soroban_sdk_macros::internal_symbol_short!("amount"))
+                    .ok_or(crate::ConversionError)?;
+                Ok(Some((holder, i128::try_from_val(env, &amount)?)))
+            }
+            xdr::LedgerEntryData::Trustline(xdr::TrustLineEntry {
+                account_id,
+                asset,
+                balance,
+                ..
+            }) if asset == trustline_asset => {
+                let holder = crate::Address::try_from_val(
+                    env,
+                    &xdr::ScAddress::Account(account_id.clone()),
+                )?;
+                Ok(Some((holder, i128::from(*balance))))
+            }
+            xdr::LedgerEntryData::Account(xdr::AccountEntry {
+                account_id,
+                balance,
+                ..
+            }) if *trustline_asset == xdr::TrustLineAsset::Native => {
+                let holder = crate::Address::try_from_val(
+                    env,
+                    &xdr::ScAddress::Account(account_id.clone()),
+                )?;
+                Ok(Some((holder, i128::from(*balance))))
+            }
+            _ => Ok(None),
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use soroban_env_host::{fees::FeeConfiguration, FeeEstimate, InvocationResources};
 
-use crate::{testutils::budget::Budget, Env};
+use crate::{testutils::budget::Budget, xdr::LedgerFootprint, Env};
 
 pub struct CostEstimate {
     env: Env,
@@ -31,6 +31,32 @@ impl CostEstimate {
         }
     }
 
+    /// Returns the storage footprint of the last top level contract
+    /// invocation, i.e. the keys of the ledger entries it read and wrote.
+    ///
+    /// Entries that were only read or had their TTL extended are in
+    /// `read_only`, and entries that were created, updated or removed are in
+    /// `read_write`. Both are sorted by key. The
+    /// footprint includes the entries accessed by any contracts called during
+    /// the invocation, as well as the contract instance and code entries.
+    ///
+    /// The host keeps one footprint for the whole test, and this is the part
+    /// of it the invocation added. An entry that an earlier invocation, or
+    /// code outside of an invocation such as [`Env::as_contract`], had already
+    /// accessed is only included if this invocation wrote an entry that had
+    /// only been read.
+    ///
+    /// ### Panics
+    ///
+    /// If no contract has been invoked.
+    pub fn footprint(&self) -> LedgerFootprint {
+        if let Some(footprint) = self.env.last_footprint() {
+            footprint
+        } else {
+            panic!("Invocation footprint is not available. Make sure this is called after an invocation.")
+        }
+    }
+
     /// Estimates the fee for the last invocation's resources, i.e. the
     /// resources returned by `resources()`.
     ///
@@ -69,6 +95,24 @@ impl CostEstimate {
         )
     }
 
+    /// Returns the resource fee for the last invocation's resources, i.e. the
+    /// fee returned by `fee()`, grouped into the ledger read, ledger write and
+    /// rent categories.
+    ///
+    /// Fees that don't belong to any of the categories, such as the fee for
+    /// instructions and contract events, are only included in the total.
+    ///
+    /// The same caveats as for `fee()` apply.
+    pub fn resource_fee(&self) -> ResourceFee {
+        let fee = self.fee();
+        ResourceFee {
+            total: fee.total,
+            read: fee.read_entries + fee.read_bytes,
+            write: fee.write_entries + fee.write_bytes,
+            rent: fee.persistent_entry_rent + fee.temporary_entry_rent,
+        }
+    }
+
     /// Returns the budget object that provides the detailed CPU and memory
     /// metering information recorded thus far.
     ///
@@ -85,4 +129,52 @@ impl CostEstimate {
     pub fn budget(&self) -> Budget {
         Budget::new(self.env.host().budget_cloned())
     }
+
+    /// Runs the function and returns the CPU instructions and memory bytes
+    /// that were metered while it ran.
+    ///
+    /// The cost is the difference between the budget consumed before and after
+    /// the function, so measurements nest: the cost measured for a function
+    /// includes the cost of any measurements made within it.
+    ///
+    /// Measure code that runs within a contract, e.g. within
+    /// [`Env::as_contract`] or a contract function. The budget is reset at the
+    /// start of every top level contract invocation, so the cost of a function
+    /// that invokes a contract from outside of a contract only includes what
+    /// was metered after the reset.
+    pub fn measure(&self, f: impl FnOnce()) -> MeasuredCost {
+        let budget = self.budget();
+        let cpu_insns = budget.cpu_instruction_cost();
+        let mem_bytes = budget.memory_bytes_cost();
+        f();
+        MeasuredCost {
+            cpu_insns: budget.cpu_instruction_cost().saturating_sub(cpu_insns),
+            mem_bytes: budget.memory_bytes_cost().saturating_sub(mem_bytes),
+        }
+    }
+}
+
+/// Resource fee of an invocation broken down by category.
+///
+/// Returned by [`CostEstimate::resource_fee`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ResourceFee {
+    /// Total fee of the invocation, including the fees that are not part of
+    /// any of the categories below (e.g. instructions and events).
+    pub total: i64,
+    /// Fee for the ledger entries read and the bytes read.
+    pub read: i64,
+    /// Fee for the ledger entries written and the bytes written.
+    pub write: i64,
+    /// Fee for the rent of persistent and temporary entries.
+    pub rent: i64,
+}
+
+/// CPU instructions and memory bytes metered while running a function.
+///
+/// Returned by [`CostEstimate::measure`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MeasuredCost {
+    pub cpu_insns: u64,
+    pub mem_bytes: u64,
 }
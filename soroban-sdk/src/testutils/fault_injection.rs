@@ -0,0 +1,251 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+};
+
+use crate::{
+    xdr::{ScErrorCode, ScErrorType},
+    Env, Error,
+};
+
+/// A category of host function calls that can be made to fail with
+/// [`HostFaultInjection`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HostOp {
+    /// Reading contract data, i.e. `has` and `get` on any storage type.
+    StorageRead,
+    /// Writing contract data, i.e. `set` on any storage type.
+    StorageWrite,
+    /// Removing contract data, i.e. `remove` on any storage type.
+    StorageRemove,
+    /// Extending the TTL of contract data, instances or code.
+    StorageExtendTtl,
+    /// Hashing, signature verification, key recovery and curve operations.
+    Crypto,
+    /// Calling another contract.
+    Call,
+    /// Requiring authorization of an address.
+    RequireAuth,
+    /// Publishing a contract event.
+    PublishEvent,
+    /// Any host function, identified by its name in the host interface, e.g.
+    /// `"put_contract_data"`.
+    Function(&'static str),
+}
+
+impl HostOp {
+    pub(crate) fn matches(&self, fn_name: &str) -> bool {
+        match self {
+            HostOp::StorageRead => matches!(fn_name, "has_contract_data" | "get_contract_data"),
+            HostOp::StorageWrite => fn_name == "put_contract_data",
+            HostOp::StorageRemove => fn_name == "del_contract_data",
+            HostOp::StorageExtendTtl => matches!(
+                fn_name,
+                "extend_contract_data_ttl"
+                    | "extend_current_contract_instance_and_code_ttl"
+                    | "extend_contract_instance_and_code_ttl"
+                    | "extend_contract_instance_ttl"
+                    | "extend_contract_code_ttl"
+            ),
+            HostOp::Crypto => {
+                fn_name.starts_with("compute_hash_")
+                    || fn_name.starts_with("verify_sig_")
+                    || fn_name.starts_with("recover_key_")
+                    || fn_name.starts_with("bls12_381_")
+            }
+            HostOp::Call => matches!(fn_name, "call" | "try_call"),
+            HostOp::RequireAuth => matches!(fn_name, "require_auth" | "require_auth_for_args"),
+            HostOp::PublishEvent => fn_name == "contract_event",
+            HostOp::Function(name) => fn_name == *name,
+        }
+    }
+
+    /// The error returned by a failure injected for this operation when no
+    /// other error is specified.
+    pub(crate) fn default_error(&self) -> Error {
+        let ty = match self {
+            HostOp::StorageRead
+            | HostOp::StorageWrite
+            | HostOp::StorageRemove
+            | HostOp::StorageExtendTtl => ScErrorType::Storage,
+            HostOp::Crypto => ScErrorType::Crypto,
+            HostOp::Call => ScErrorType::Context,
+            HostOp::RequireAuth => ScErrorType::Auth,
+            HostOp::PublishEvent => ScErrorType::Events,
+            HostOp::Function(_) => ScErrorType::Context,
+        };
+        Error::from_type_and_code(ty, ScErrorCode::InvalidAction)
+    }
+}
+
+/// Operations that fail while a view call is in progress.
+const VIEW_DENIED: [HostOp; 3] = [
+    HostOp::StorageWrite,
+    HostOp::StorageRemove,
+    HostOp::PublishEvent,
+];
+
+/// Fault injection, shared between an [Env] and the [Env]s handed to the
+/// native contracts it invokes.
+#[derive(Default)]
+pub(crate) struct FaultInjection {
+    /// Whether any failure is pending or a view call is in progress. Every
+    /// host function call checks this before looking up failures, so that
+    /// calls stay cheap while nothing is armed.
+    armed: Cell<bool>,
+    state: RefCell<FaultInjectionState>,
+}
+
+impl FaultInjection {
+    pub(crate) fn with_state<T>(&self, f: impl FnOnce(&mut FaultInjectionState) -> T) -> T {
+        let mut state = self.state.borrow_mut();
+        let t = f(&mut state);
+        self.armed.set(!state.pending.is_empty() || state.views > 0);
+        t
+    }
+
+    /// Removes and returns the error of the first pending failure that matches
+    /// the host function, if any. See [`FaultInjectionState::take`].
+    pub(crate) fn take(&self, fn_name: &str) -> Option<Error> {
+        if !self.armed.get() {
+            return None;
+        }
+        self.with_state(|s| s.take(fn_name))
+    }
+}
+
+/// Pending failures and view calls in progress.
+#[derive(Default)]
+pub(crate) struct FaultInjectionState {
+    pending: VecDeque<(HostOp, Error)>,
+    views: u32,
+    view_violation: Option<HostOp>,
+}
+
+impl FaultInjectionState {
+    /// Starts a view call, during which host functions that mutate state fail.
+    pub(crate) fn begin_view(&mut self) {
+        if self.views == 0 {
+            self.view_violation = None;
+        }
+        self.views += 1;
+    }
+
+    /// Ends a view call, returning the first mutating operation attempted
+    /// during the outermost view call, if any.
+    pub(crate) fn end_view(&mut self) -> Option<HostOp> {
+        self.views -= 1;
+        if self.views == 0 {
+            self.view_violation.take()
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the error of the first pending failure that matches
+    /// the host function, if any. During a view call, mutating host functions
+    /// fail regardless of pending failures.
+    pub(crate) fn take(&mut self, fn_name: &str) -> Option<Error> {
+        if self.views > 0 {
+            if let Some(op) = VIEW_DENIED.iter().find(|op| op.matches(fn_name)) {
+                self.view_violation.get_or_insert(*op);
+                return Some(op.default_error());
+            }
+        }
+        let i = self
+            .pending
+            .iter()
+            .position(|(op, _)| op.matches(fn_name))?;
+        self.pending.remove(i).map(|(_, e)| e)
+    }
+}
+
+/// Injects failures into host function calls made through an [Env].
+///
+/// A failure is consumed by the first host function call that matches its
+/// [`HostOp`]. The call does not reach the host and instead fails with the
+/// injected error, exactly as if the host had returned it. Inside a contract
+/// invocation the error aborts the invocation and is returned to the caller,
+/// which makes it possible to exercise error paths that are otherwise
+/// unreachable in tests.
+///
+/// Only calls made by the SDK are affected. Host functions called by Wasm
+/// contracts, including those registered with [`Env::register`] from a Wasm
+/// file, go straight to the host without passing through the SDK's [Env],
+/// and cannot be failed.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, Env, Error};
+/// use soroban_sdk::testutils::fault_injection::HostOp;
+/// use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn store(env: Env) {
+///         env.storage().persistent().set(&1, &2);
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     env.host_fault_injection().fail_next(HostOp::StorageWrite);
+///     assert_eq!(
+///         client.try_store(),
+///         Err(Ok(Error::from_type_and_code(
+///             ScErrorType::Storage,
+///             ScErrorCode::InvalidAction
+///         )))
+///     );
+///
+///     // The failure is consumed, so the next write succeeds.
+///     client.store();
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct HostFaultInjection {
+    env: Env,
+}
+
+impl HostFaultInjection {
+    pub(crate) fn new(env: Env) -> Self {
+        Self { env }
+    }
+
+    /// Fail the next host function call matching the operation with an
+    /// [`ScErrorCode::InvalidAction`] error of the error type that corresponds
+    /// to the operation, e.g. [`ScErrorType::Storage`] for storage operations.
+    pub fn fail_next(&self, op: HostOp) {
+        self.fail_next_with(op, op.default_error());
+    }
+
+    /// Fail the next host function call matching the operation with the
+    /// provided error.
+    pub fn fail_next_with(&self, op: HostOp, error: impl Into<Error>) {
+        self.env
+            .with_fault_injection(|s| s.pending.push_back((op, error.into())));
+    }
+
+    /// Returns the number of injected failures that have not yet been
+    /// consumed by a host function call.
+    pub fn pending(&self) -> usize {
+        self.env.with_fault_injection(|s| s.pending.len())
+    }
+
+    /// Remove all injected failures that have not yet been consumed.
+    pub fn clear(&self) {
+        self.env.with_fault_injection(|s| s.pending.clear());
+    }
+}
@@ -0,0 +1,53 @@
+/// A host function call made during a contract invocation, as recorded by
+/// [`Env::host_trace`][crate::Env::host_trace].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HostCall {
+    /// The name of the host function in the host interface, e.g.
+    /// `"put_contract_data"`.
+    pub fn_name: &'static str,
+    /// The number of contract invocations in progress when the call was made.
+    /// Calls made by the invoked contract have a depth of 1, calls made by
+    /// contracts it calls have a depth of 2, and so on.
+    pub depth: u32,
+}
+
+/// The host calls of the last top level invocation, shared between an [Env]
+/// and the [Env]s handed to the native contracts it invokes.
+///
+/// [Env]: crate::Env
+#[derive(Default)]
+pub(crate) struct HostTraceState {
+    depth: u32,
+    calls: std::vec::Vec<HostCall>,
+}
+
+impl HostTraceState {
+    /// Records a host function call. If the call is a contract invocation,
+    /// returns the depth of the invocation, 1 for a top level invocation, and
+    /// the call must be followed by a call to [`Self::leave`] once it returns.
+    pub(crate) fn enter(&mut self, fn_name: &'static str) -> Option<u32> {
+        let invoke = matches!(fn_name, "call" | "try_call");
+        if self.depth > 0 {
+            self.calls.push(HostCall {
+                fn_name,
+                depth: self.depth,
+            });
+        } else if invoke {
+            self.calls.clear();
+        }
+        if invoke {
+            self.depth += 1;
+            Some(self.depth)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    pub(crate) fn calls(&self) -> std::vec::Vec<HostCall> {
+        self.calls.clone()
+    }
+}
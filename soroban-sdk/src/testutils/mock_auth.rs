@@ -18,6 +18,19 @@ pub struct MockAuth<'a> {
     pub invoke: &'a MockAuthInvoke<'a>,
 }
 
+impl<'a> MockAuth<'a> {
+    /// Returns a [`MockAuthEntry`] for this mocked auth that uses the nonce.
+    pub fn with_nonce(&self, nonce: i64) -> MockAuthEntry<'a> {
+        MockAuthEntry::from(self.clone()).with_nonce(nonce)
+    }
+
+    /// Returns a [`MockAuthEntry`] for this mocked auth that expires after the
+    /// ledger.
+    pub fn with_signature_expiration_ledger(&self, ledger: u32) -> MockAuthEntry<'a> {
+        MockAuthEntry::from(self.clone()).with_signature_expiration_ledger(ledger)
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MockAuthInvoke<'a> {
     pub contract: &'a Address,
@@ -26,23 +39,81 @@ pub struct MockAuthInvoke<'a> {
     pub sub_invokes: &'a [MockAuthInvoke<'a>],
 }
 
-impl<'a> From<&MockAuth<'a>> for xdr::SorobanAuthorizationEntry {
-    fn from(value: &MockAuth) -> Self {
-        let env = value.address.env();
-        let curr_ledger = env.ledger().sequence();
-        let max_entry_ttl = env.storage().max_ttl();
+/// A [`MockAuth`] with control over the credentials of its authorization
+/// entry, for testing how contracts handle replayed or expired
+/// authorizations.
+///
+/// Pass to [`Env::mock_auth_entries`].
+///
+/// A [`MockAuth`] gets a unique nonce, and expires after the maximum TTL from
+/// the current ledger. The nonce of an address can only be consumed once, so
+/// reusing a nonce causes the authorization to be rejected. The expiration is
+/// checked against the ledger the authorization is used in, so an expiration
+/// that has passed also causes the authorization to be rejected.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MockAuthEntry<'a> {
+    auth: MockAuth<'a>,
+    nonce: Option<i64>,
+    signature_expiration_ledger: Option<u32>,
+}
+
+impl<'a> MockAuthEntry<'a> {
+    /// Uses the nonce instead of a generated one.
+    pub fn with_nonce(mut self, nonce: i64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Expires after the ledger instead of after the maximum TTL.
+    pub fn with_signature_expiration_ledger(mut self, ledger: u32) -> Self {
+        self.signature_expiration_ledger = Some(ledger);
+        self
+    }
+
+    pub(crate) fn address(&self) -> &'a Address {
+        self.auth.address
+    }
+}
+
+impl<'a> From<MockAuth<'a>> for MockAuthEntry<'a> {
+    fn from(auth: MockAuth<'a>) -> Self {
         Self {
-            root_invocation: value.invoke.into(),
+            auth,
+            nonce: None,
+            signature_expiration_ledger: None,
+        }
+    }
+}
+
+impl<'a> From<&MockAuthEntry<'a>> for xdr::SorobanAuthorizationEntry {
+    fn from(value: &MockAuthEntry) -> Self {
+        let env = value.auth.address.env();
+        let nonce = value
+            .nonce
+            .unwrap_or_else(|| env.with_generator(|mut g| g.nonce()));
+        let signature_expiration_ledger = value.signature_expiration_ledger.unwrap_or_else(|| {
+            let curr_ledger = env.ledger().sequence();
+            let max_entry_ttl = env.storage().max_ttl();
+            curr_ledger + max_entry_ttl
+        });
+        Self {
+            root_invocation: value.auth.invoke.into(),
             credentials: xdr::SorobanCredentials::Address(xdr::SorobanAddressCredentials {
-                address: value.address.try_into().unwrap(),
-                nonce: env.with_generator(|mut g| g.nonce()),
-                signature_expiration_ledger: curr_ledger + max_entry_ttl,
+                address: value.auth.address.try_into().unwrap(),
+                nonce,
+                signature_expiration_ledger,
                 signature: xdr::ScVal::Void,
             }),
         }
     }
 }
 
+impl<'a> From<&MockAuth<'a>> for xdr::SorobanAuthorizationEntry {
+    fn from(value: &MockAuth<'a>) -> Self {
+        (&MockAuthEntry::from(value.clone())).into()
+    }
+}
+
 impl<'a> From<MockAuth<'a>> for xdr::SorobanAuthorizationEntry {
     fn from(value: MockAuth<'a>) -> Self {
         (&value).into()
@@ -1,5 +1,34 @@
 use crate::{Env, IntoVal, Map, Val};
 
+/// Test utilities for [`Storage`][crate::storage::Storage].
+pub trait Storage {
+    /// Returns all data stored by the current contract in each type of
+    /// storage, for inspecting storage when debugging a test.
+    fn dump(&self) -> StorageDump;
+
+    /// Returns the keys of the current contract's persistent and temporary
+    /// entries that have been created, updated, or removed during the current
+    /// top level invocation, or during the last one if no invocation is in
+    /// progress.
+    ///
+    /// The keys are those the host records as written in the invocation's
+    /// footprint, ordered by the host's ordering of ledger keys. A key written
+    /// in both persistent and temporary storage is returned once for each.
+    ///
+    /// Instance storage is not included, because all of a contract's instance
+    /// storage is a single entry.
+    fn modified_keys(&self) -> crate::Vec<Val>;
+}
+
+/// The data stored by a contract in each type of storage, returned by
+/// [`Storage::dump`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageDump {
+    pub instance: Map<Val, Val>,
+    pub persistent: Map<Val, Val>,
+    pub temporary: Map<Val, Val>,
+}
+
 /// Test utilities for [`Persistent`][crate::storage::Persistent].
 pub trait Persistent {
     /// Returns all data stored in persistent storage for the contract.
@@ -12,6 +41,29 @@ pub trait Persistent {
     ///
     /// Panics if there is no entry corresponding to the key, or if the entry has expired.
     fn get_ttl<K: IntoVal<Env, Val>>(&self, key: &K) -> u32;
+
+    /// Archives the persistent storage entry corresponding to the provided
+    /// key, as if its TTL had run out in the previous ledger.
+    ///
+    /// Any access to an archived entry fails until it is restored with
+    /// [`simulate_restore`][Persistent::simulate_restore].
+    ///
+    /// Panics if there is no entry corresponding to the key, or if the current
+    /// ledger sequence number is 0.
+    fn simulate_archive<K: IntoVal<Env, Val>>(&self, key: &K);
+
+    /// Restores the persistent storage entry corresponding to the provided
+    /// key, giving it the minimum persistent entry TTL like a restore
+    /// operation on the network would.
+    ///
+    /// Panics if there is no entry corresponding to the key.
+    fn simulate_restore<K: IntoVal<Env, Val>>(&self, key: &K);
+
+    /// Returns true if the persistent storage entry corresponding to the
+    /// provided key is archived.
+    ///
+    /// Panics if there is no entry corresponding to the key.
+    fn is_archived<K: IntoVal<Env, Val>>(&self, key: &K) -> bool;
 }
 
 /// Test utilities for [`Temporary`][crate::storage::Temporary].
@@ -28,6 +80,16 @@ pub trait Temporary {
     fn get_ttl<K: IntoVal<Env, Val>>(&self, key: &K) -> u32;
 }
 
+/// Test utilities for [`ReadOnlyStorage`][crate::storage::ReadOnlyStorage].
+pub trait ReadOnlyStorage {
+    /// Gets the TTL for the entry corresponding to the provided key in the
+    /// storage the view reads from.
+    ///
+    /// Panics if there is no entry corresponding to the key, or if a persistent
+    /// entry has expired.
+    fn get_ttl<K: IntoVal<Env, Val>>(&self, key: &K) -> u32;
+}
+
 /// Test utilities for [`Instance`][crate::storage::Instance].
 pub trait Instance {
     /// Returns all data stored in Instance storage for the contract.
@@ -6,9 +6,15 @@
 //!
 //! Use [`TokenClient`] for calling token contracts such as the Stellar Asset
 //! Contract.
+//!
+//! Use [`Allowance`] for storing and spending SEP-41 style allowances in
+//! token implementations and other contracts.
 
 use crate::{contractclient, contractspecfn, Address, Env, String};
 
+mod allowance;
+pub use allowance::{Allowance, AllowanceError};
+
 // The interface below was copied from
 // https://github.com/stellar/rs-soroban-env/blob/main/soroban-env-host/src/native_contract/token/contract.rs
 // at commit b3c188f48dec51a956c1380fb6fe92201a3f716b.
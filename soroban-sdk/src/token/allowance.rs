@@ -0,0 +1,191 @@
+use crate::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Error returned by [`Allowance::spend`].
+///
+/// It is a [`contracterror`], so a contract function can return it, in which
+/// case the invocation fails with a contract error of code 1 or 2. Contracts
+/// with their own error type should map it into that type instead, to keep
+/// the codes from overlapping with their own.
+#[contracterror(crate_path = "crate", export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AllowanceError {
+    /// The amount to spend is larger than the remaining allowance, including
+    /// when the allowance has expired.
+    Insufficient = 1,
+    /// The amount to spend is negative.
+    NegativeAmount = 2,
+}
+
+#[derive(Clone)]
+#[contracttype(crate_path = "crate", export = false)]
+struct AllowanceValue {
+    amount: i128,
+    expiration_ledger: u32,
+}
+
+const ALLOWANCE: Symbol = soroban_sdk_macros::internal_symbol_short!("Allowance");
+
+/// Allowances that an address has given to spenders, stored in the current
+/// contract's temporary storage.
+///
+/// An allowance permits a spender to spend up to an amount on behalf of the
+/// owner until, and including, the expiration ledger. The semantics match the
+/// `approve`, `allowance` and `transfer_from` functions of
+/// [SEP-41][crate::token::TokenInterface]:
+///
+/// - Setting an allowance replaces any previous allowance for the spender,
+/// including its expiration.
+/// - An allowance whose expiration ledger is before the current ledger has no
+/// remaining amount.
+/// - Spending decrements the remaining amount and leaves the expiration
+/// unchanged.
+///
+/// Allowances are stored under keys of the form `("Allowance", owner,
+/// spender)`, and their TTL is extended to last until the expiration ledger,
+/// or for as long as the network allows if that is sooner.
+///
+/// Allowance does not check authorization. Contracts should require the
+/// owner's authorization before setting an allowance, and the spender's before
+/// spending it.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{token::Allowance, Address, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env, owner: Address, spender: Address) {
+/// let allowance = Allowance::new(&env, &owner);
+/// allowance.set(&spender, 100, env.ledger().sequence() + 10);
+/// allowance.spend(&spender, 40).unwrap();
+/// assert_eq!(allowance.remaining(&spender), 60);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::Address as _;
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f(&Address::generate(&env), &Address::generate(&env));
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct Allowance {
+    env: Env,
+    owner: Address,
+}
+
+impl Allowance {
+    /// Returns the allowances given by the owner.
+    pub fn new(env: &Env, owner: &Address) -> Allowance {
+        Allowance {
+            env: env.clone(),
+            owner: owner.clone(),
+        }
+    }
+
+    /// Sets the allowance of the spender, replacing any previous allowance.
+    ///
+    /// Setting an amount of zero removes the allowance.
+    ///
+    /// The allowance's TTL is extended to the expiration ledger, but never past
+    /// the network's maximum TTL. An allowance that expires further out than
+    /// that is removed from temporary storage early, and reads as zero from
+    /// then on, unless its TTL is extended again before then.
+    ///
+    /// ### Panics
+    ///
+    /// If the amount is negative, or if the amount is positive and the
+    /// expiration ledger is before the current ledger.
+    pub fn set(&self, spender: &Address, amount: i128, expiration_ledger: u32) {
+        if amount < 0 {
+            panic!("negative amount is not allowed");
+        }
+        let key = self.key(spender);
+        let storage = self.env.storage().temporary();
+        if amount == 0 {
+            storage.remove(&key);
+            return;
+        }
+        let sequence = self.env.ledger().sequence();
+        if expiration_ledger < sequence {
+            panic!("expiration_ledger is less than ledger seq when amount > 0");
+        }
+        storage.set(
+            &key,
+            &AllowanceValue {
+                amount,
+                expiration_ledger,
+            },
+        );
+        let live_for = (expiration_ledger - sequence).min(self.env.storage().max_ttl());
+        storage.extend_ttl(&key, live_for, live_for);
+    }
+
+    /// Returns the amount the spender may still spend, or zero if the
+    /// allowance has expired or was never set.
+    pub fn remaining(&self, spender: &Address) -> i128 {
+        match self.read(spender) {
+            Some(v) if v.expiration_ledger >= self.env.ledger().sequence() => v.amount,
+            _ => 0,
+        }
+    }
+
+    /// Returns the expiration ledger of the spender's allowance, if any is
+    /// set. The allowance may already have expired.
+    pub fn expiration_ledger(&self, spender: &Address) -> Option<u32> {
+        self.read(spender).map(|v| v.expiration_ledger)
+    }
+
+    /// Spends the amount from the spender's allowance.
+    ///
+    /// ### Errors
+    ///
+    /// If the amount is negative, or if the amount is larger than the
+    /// remaining allowance, which is zero once the allowance has expired. The
+    /// allowance is unchanged when an error is returned.
+    pub fn spend(&self, spender: &Address, amount: i128) -> Result<(), AllowanceError> {
+        if amount < 0 {
+            return Err(AllowanceError::NegativeAmount);
+        }
+        if amount == 0 {
+            return Ok(());
+        }
+        // Once expired the entry may also have been removed from temporary
+        // storage, so an expired allowance is reported the same as a missing
+        // one.
+        let v = match self.read(spender) {
+            Some(v) if v.expiration_ledger >= self.env.ledger().sequence() => v,
+            _ => return Err(AllowanceError::Insufficient),
+        };
+        if v.amount < amount {
+            return Err(AllowanceError::Insufficient);
+        }
+        self.env.storage().temporary().set(
+            &self.key(spender),
+            &AllowanceValue {
+                amount: v.amount - amount,
+                expiration_ledger: v.expiration_ledger,
+            },
+        );
+        Ok(())
+    }
+
+    fn key(&self, spender: &Address) -> (Symbol, Address, Address) {
+        (ALLOWANCE, self.owner.clone(), spender.clone())
+    }
+
+    fn read(&self, spender: &Address) -> Option<AllowanceValue> {
+        self.env.storage().temporary().get(&self.key(spender))
+    }
+}
@@ -3,9 +3,9 @@ use core::{
     cmp::Ordering,
     convert::Infallible,
     fmt::Debug,
-    iter::FusedIterator,
+    iter::{FusedIterator, Zip},
     marker::PhantomData,
-    ops::{Bound, RangeBounds},
+    ops::{Bound, Range, RangeBounds},
 };
 
 use crate::{
@@ -76,6 +76,10 @@ macro_rules! vec {
 /// Vec values can be stored as [Storage], or in other types like [Vec], [Map],
 /// etc.
 ///
+/// Vecs compare by value, element by element, using the Host's comparison of
+/// [Val]s, so two separately constructed Vecs with equal contents are equal.
+/// This makes Vecs, including nested Vecs, usable as [Map] keys.
+///
 /// ### Examples
 ///
 /// ```
@@ -376,6 +380,23 @@ where
         vec
     }
 
+    /// Returns the items of the Vec as an array.
+    ///
+    /// ### Errors
+    ///
+    /// If the length of the Vec is not `N`, or if any item cannot be converted
+    /// to type T.
+    pub fn to_array<const N: usize>(&self) -> Result<[T; N], ConversionError> {
+        if self.len() as usize != N {
+            return Err(ConversionError);
+        }
+        let items: [Option<T>; N] = core::array::from_fn(|i| self.try_get_in_bounds(i as u32).ok());
+        if items.iter().any(Option::is_none) {
+            return Err(ConversionError);
+        }
+        Ok(items.map(|item| item.unwrap_optimized()))
+    }
+
     /// Returns the item at the position or None if out-of-bounds.
     ///
     /// ### Panics
@@ -394,7 +415,7 @@ where
     #[inline(always)]
     pub fn try_get(&self, i: u32) -> Result<Option<T>, T::Error> {
         if i < self.len() {
-            self.try_get_unchecked(i).map(|val| Some(val))
+            self.try_get_in_bounds(i).map(|val| Some(val))
         } else {
             Ok(None)
         }
@@ -423,6 +444,33 @@ where
     /// If the position is out-of-bounds.
     #[inline(always)]
     pub fn try_get_unchecked(&self, i: u32) -> Result<T, T::Error> {
+        // Outside of wasm the panic names the index and the length, like
+        // indexing into a std Vec does. The length is only looked up once the
+        // host has rejected the index, so in bounds accesses cost no more. In
+        // wasm the host traps with an IndexBounds error instead.
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let env = self.env();
+            match env.host().vec_get(self.obj, i.into()) {
+                Ok(val) => T::try_from_val(env, &val),
+                Err(e) => {
+                    let len = self.len();
+                    if i >= len {
+                        panic!("index out of bounds: the len is {len} but the index is {i}: {e:?}");
+                    }
+                    let val = crate::env::internal::reject_err(env.host(), Err::<Val, _>(e));
+                    T::try_from_val(env, &val.unwrap_infallible())
+                }
+            }
+        }
+        #[cfg(target_family = "wasm")]
+        self.try_get_in_bounds(i)
+    }
+
+    /// Returns the item at a position the caller has already checked is in
+    /// bounds.
+    #[inline(always)]
+    fn try_get_in_bounds(&self, i: u32) -> Result<T, T::Error> {
         let env = self.env();
         let val = env.vec_get(self.obj, i.into()).unwrap_infallible();
         T::try_from_val(env, &val)
@@ -762,6 +810,22 @@ impl<T> Vec<T> {
         unsafe { Self::unchecked_new(env.clone(), obj) }
     }
 
+    /// Splits the vec into two at the given index.
+    ///
+    /// Returns a new vec containing the items in the range `[at, len)`, and
+    /// leaves the vec containing the items in the range `[0, at)`.
+    ///
+    /// ### Panics
+    ///
+    /// If `at > len`.
+    #[must_use = "use `slice` if you don't need the other half"]
+    pub fn split_off(&mut self, at: u32) -> Self {
+        let head = self.slice(..at);
+        let other = self.slice(at..);
+        *self = head;
+        other
+    }
+
     /// Returns copy of the vec shuffled using the NOT-SECURE PRNG.
     ///
     /// In tests, must be called from within a running contract.
@@ -843,6 +907,56 @@ where
             .unwrap()
     }
 
+    /// Returns a new vec containing the first occurrence of each item, in the
+    /// order the items first appear.
+    ///
+    /// Items are compared by value, the same way as [`Vec::contains`]. The
+    /// items already seen are tracked in a [Map][crate::Map], so the cost is
+    /// O(n log n) in the length of the vec rather than the O(n²) of comparing
+    /// every pair of items.
+    #[must_use]
+    pub fn unique(&self) -> Vec<T> {
+        let env = self.env();
+        let mut seen = crate::Map::<Val, ()>::new(env);
+        let mut unique = Vec::<T>::new(env);
+        for i in 0..self.len() {
+            let val = env.vec_get(self.obj, i.into()).unwrap_infallible();
+            if !seen.contains_key(val) {
+                seen.set(val, ());
+                unique.obj = env.vec_push_back(unique.obj, val).unwrap_infallible();
+            }
+        }
+        unique
+    }
+
+    /// Returns true if the items are in ascending order, such as is required
+    /// by [`binary_search`][Vec::binary_search].
+    ///
+    /// Items that compare as equal may be next to each other in any order. An
+    /// empty [Vec] and a [Vec] of one item are sorted.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: Ord,
+    {
+        let len = self.len();
+        if len == 0 {
+            return true;
+        }
+        let mut prev = self.get_unchecked(0);
+        for i in 1..len {
+            let item = self.get_unchecked(i);
+            if item < prev {
+                return false;
+            }
+            prev = item;
+        }
+        true
+    }
+
     /// Returns the index of an occurrence of the item in an already sorted
     /// [Vec], or the index of where the item can be inserted to keep the [Vec]
     /// sorted.
@@ -865,6 +979,32 @@ where
             Err(low)
         }
     }
+
+    /// Inserts the item into an already sorted [Vec] at the position that
+    /// keeps the [Vec] sorted.
+    ///
+    /// An item equal to items already in the [Vec] is inserted after them, so
+    /// items that compare as equal stay in the order they were inserted.
+    ///
+    /// ### Panics
+    ///
+    /// If any item compared against cannot be converted to the type `T`.
+    pub fn binary_insert(&mut self, item: T)
+    where
+        T: Ord,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.get_unchecked(mid) <= item {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        self.insert(low, item);
+    }
 }
 
 impl<T> Vec<Vec<T>>
@@ -880,6 +1020,174 @@ where
         }
         concatenated
     }
+
+    /// Returns a [Vec] of the items of all the inner [Vec]s, in order.
+    ///
+    /// The same as [`concat`][Self::concat], named to match
+    /// [`Iterator::flatten`].
+    #[inline(always)]
+    pub fn flatten(&self) -> Vec<T> {
+        self.concat()
+    }
+}
+
+impl<T> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    T::Error: Debug,
+{
+    /// Returns the maximum item in the [Vec].
+    ///
+    /// If several items are equally maximum, the last item is returned. If
+    /// the [Vec] is empty [None] is returned.
+    ///
+    /// Named `max_item` rather than `max` because [Vec] implements [Ord],
+    /// and `vec.max()` would resolve to [Ord::max].
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    #[must_use]
+    pub fn max_item(&self) -> Option<T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns the minimum item in the [Vec].
+    ///
+    /// If several items are equally minimum, the first item is returned. If
+    /// the [Vec] is empty [None] is returned.
+    ///
+    /// See [`max_item`][Self::max_item] for why this isn't named `min`.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    #[must_use]
+    pub fn min_item(&self) -> Option<T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns the item in the [Vec] that gives the maximum value from the
+    /// function.
+    ///
+    /// If several items are equally maximum, the last item is returned. If
+    /// the [Vec] is empty [None] is returned.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    #[must_use]
+    pub fn max_by_key<B: Ord>(&self, f: impl FnMut(&T) -> B) -> Option<T> {
+        self.iter().max_by_key(f)
+    }
+
+    /// Returns the item in the [Vec] that gives the minimum value from the
+    /// function.
+    ///
+    /// If several items are equally minimum, the first item is returned. If
+    /// the [Vec] is empty [None] is returned.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    #[must_use]
+    pub fn min_by_key<B: Ord>(&self, f: impl FnMut(&T) -> B) -> Option<T> {
+        self.iter().min_by_key(f)
+    }
+
+    /// Splits the [Vec] into the items for which the function returns true
+    /// and the items for which it returns false, in that order.
+    ///
+    /// Items keep their relative order in both returned [Vec]s.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    #[must_use]
+    pub fn partition<F: FnMut(&T) -> bool>(&self, mut f: F) -> (Vec<T>, Vec<T>) {
+        let env = self.env();
+        let mut matches = Vec::<T>::new(env);
+        let mut rest = Vec::<T>::new(env);
+        for item in self.iter() {
+            if f(&item) {
+                matches.push_back(item);
+            } else {
+                rest.push_back(item);
+            }
+        }
+        (matches, rest)
+    }
+
+    /// Returns a [Vec] of pairs of the items of the [Vec] and the other
+    /// [Vec] at the same positions.
+    ///
+    /// If the [Vec]s have different lengths the pairs stop at the end of the
+    /// shorter one, and the remaining items of the longer one are ignored.
+    /// Use [`zip_exact`][Self::zip_exact] to require equal lengths.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T` or `B`.
+    #[must_use]
+    pub fn zip<B>(&self, other: &Vec<B>) -> Vec<(T, B)>
+    where
+        B: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        B::Error: Debug,
+    {
+        let mut pairs = Vec::new(self.env());
+        for pair in self.iter().zip(other.iter()) {
+            pairs.push_back(pair);
+        }
+        pairs
+    }
+
+    /// Returns a [Vec] of pairs of the items of the [Vec] and the other
+    /// [Vec] at the same positions.
+    ///
+    /// ### Panics
+    ///
+    /// If the [Vec]s have different lengths.
+    ///
+    /// If any item cannot be converted to the type `T` or `B`.
+    #[must_use]
+    pub fn zip_exact<B>(&self, other: &Vec<B>) -> Vec<(T, B)>
+    where
+        B: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        B::Error: Debug,
+    {
+        if self.len() != other.len() {
+            sdk_panic!("Vec::zip_exact with mismatched lengths");
+        }
+        self.zip(other)
+    }
+}
+
+impl<T, const N: usize> TryFrom<&Vec<T>> for [T; N]
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    type Error = ConversionError;
+
+    fn try_from(v: &Vec<T>) -> Result<Self, Self::Error> {
+        v.to_array()
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for [T; N]
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    type Error = ConversionError;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        v.to_array()
+    }
 }
 
 impl<T> IntoIterator for Vec<T>
@@ -919,6 +1227,22 @@ where
         self.try_iter().unwrapped()
     }
 
+    /// Returns an iterator over the positions and items of the Vec, like
+    /// calling [`enumerate`][Iterator::enumerate] on [`iter`][Self::iter] but
+    /// with the positions as `u32`, the type used to index a Vec.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to the type `T`.
+    #[inline(always)]
+    pub fn iter_enumerated(&self) -> Zip<Range<u32>, UnwrappedIter<VecTryIter<T>, T, T::Error>>
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+        T::Error: Debug,
+    {
+        (0..self.len()).zip(self.iter())
+    }
+
     #[inline(always)]
     pub fn try_iter(&self) -> VecTryIter<T>
     where
@@ -937,6 +1261,29 @@ where
     }
 }
 
+impl<K, V> Vec<(K, V)>
+where
+    K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    /// Returns a [Map][crate::Map] containing the key-value pairs of the Vec.
+    ///
+    /// If a key appears more than once, the map contains the value of its last
+    /// occurrence in the Vec.
+    ///
+    /// ### Panics
+    ///
+    /// If any item cannot be converted to type `(K, V)`.
+    pub fn to_map(&self) -> crate::Map<K, V> {
+        let mut map = crate::Map::new(self.env());
+        for i in 0..self.len() {
+            let (k, v) = self.get_unchecked(i);
+            map.set(k, v);
+        }
+        map
+    }
+}
+
 #[derive(Clone)]
 pub struct VecTryIter<T> {
     vec: Vec<T>,
@@ -966,7 +1313,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.start < self.end {
-            let val = self.vec.try_get_unchecked(self.start);
+            let val = self.vec.try_get_in_bounds(self.start);
             self.start += 1;
             Some(val)
         } else {
@@ -989,7 +1336,7 @@ where
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.start < self.end {
-            let val = self.vec.try_get_unchecked(self.end - 1);
+            let val = self.vec.try_get_in_bounds(self.end - 1);
             self.end -= 1;
             Some(val)
         } else {
@@ -1140,6 +1487,46 @@ mod test {
         assert_eq!(vec, vec![&env, 1, 2, 3, 4, 5, 6]);
     }
 
+    #[test]
+    fn test_vec_flatten() {
+        let env = Env::default();
+
+        let outer: Vec<Vec<i64>> = vec![&env];
+        assert_eq!(outer.flatten(), vec![&env]);
+
+        let empty: Vec<i64> = vec![&env];
+        let outer = vec![&env, empty.clone(), empty.clone()];
+        assert_eq!(outer.flatten(), vec![&env]);
+
+        let outer = vec![
+            &env,
+            empty.clone(),
+            vec![&env, 1, 2],
+            empty.clone(),
+            empty.clone(),
+            vec![&env, 3],
+            empty,
+        ];
+        assert_eq!(outer.flatten(), vec![&env, 1, 2, 3]);
+
+        let outer = vec![&env, vec![&env, 1, 2, 3], vec![&env, 4], vec![&env, 5, 6]];
+        assert_eq!(outer.flatten(), vec![&env, 1, 2, 3, 4, 5, 6]);
+        // The inner vecs are unchanged.
+        assert_eq!(outer.get_unchecked(0), vec![&env, 1, 2, 3]);
+
+        // Flattening one level of a deeper vec leaves the inner levels.
+        let deep = vec![
+            &env,
+            vec![&env, vec![&env, 1], vec![&env, 2]],
+            vec![&env, vec![&env, 3]],
+        ];
+        assert_eq!(
+            deep.flatten(),
+            vec![&env, vec![&env, 1], vec![&env, 2], vec![&env, 3]]
+        );
+        assert_eq!(deep.flatten().flatten(), vec![&env, 1, 2, 3]);
+    }
+
     #[test]
     fn test_vec_slice() {
         let env = Env::default();
@@ -1180,6 +1567,63 @@ mod test {
         assert_eq!(slice, vec![&env, 1, 2]);
     }
 
+    #[test]
+    fn test_to_array() {
+        let env = Env::default();
+
+        let v = vec![&env, 1u32, 2, 3];
+        assert_eq!(v.to_array::<3>(), Ok([1, 2, 3]));
+        assert_eq!(<[u32; 3]>::try_from(&v), Ok([1, 2, 3]));
+        let a: [u32; 3] = v.clone().try_into().unwrap();
+        assert_eq!(a, [1, 2, 3]);
+
+        assert_eq!(v.to_array::<2>(), Err(ConversionError));
+        assert_eq!(v.to_array::<4>(), Err(ConversionError));
+        assert_eq!(<[u32; 0]>::try_from(&v), Err(ConversionError));
+
+        let empty = Vec::<u32>::new(&env);
+        assert_eq!(empty.to_array::<0>(), Ok([]));
+        assert_eq!(empty.to_array::<1>(), Err(ConversionError));
+
+        let v: Val = (1i64, 2i32).try_into_val(&env).unwrap();
+        let v: Vec<i64> = v.try_into_val(&env).unwrap();
+        assert_eq!(v.to_array::<2>(), Err(ConversionError));
+    }
+
+    #[test]
+    fn test_split_off() {
+        let env = Env::default();
+
+        let mut v = vec![&env, 0, 1, 2, 3, 4];
+        let other = v.split_off(2);
+        assert_eq!(v, vec![&env, 0, 1]);
+        assert_eq!(other, vec![&env, 2, 3, 4]);
+
+        let mut v = vec![&env, 0, 1, 2];
+        let other = v.split_off(0);
+        assert_eq!(v, Vec::<i32>::new(&env));
+        assert_eq!(other, vec![&env, 0, 1, 2]);
+
+        let mut v = vec![&env, 0, 1, 2];
+        let other = v.split_off(3);
+        assert_eq!(v, vec![&env, 0, 1, 2]);
+        assert_eq!(other, Vec::<i32>::new(&env));
+
+        let mut v = Vec::<i32>::new(&env);
+        let other = v.split_off(0);
+        assert!(v.is_empty());
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Object, IndexBounds)")]
+    fn test_split_off_out_of_bounds() {
+        let env = Env::default();
+
+        let mut v = vec![&env, 0, 1, 2];
+        let _ = v.split_off(4);
+    }
+
     #[test]
     fn test_vec_iter() {
         let env = Env::default();
@@ -1340,6 +1784,62 @@ mod test {
         assert_eq!(vec.contains(5), true);
     }
 
+    #[test]
+    fn test_unique() {
+        let env = Env::default();
+
+        let vec: Vec<u32> = vec![&env];
+        assert_eq!(vec.unique(), vec);
+
+        let vec = vec![&env, 3, 1, 2];
+        assert_eq!(vec.unique(), vec);
+
+        let vec = vec![&env, 7, 7, 7, 7];
+        assert_eq!(vec.unique(), vec![&env, 7]);
+
+        let vec = vec![&env, 3, 1, 3, 2, 1, 4, 2, 3];
+        assert_eq!(vec.unique(), vec![&env, 3, 1, 2, 4]);
+        // The original vec is unchanged.
+        assert_eq!(vec.len(), 8);
+
+        let a = crate::Bytes::from_array(&env, &[1]);
+        let b = crate::Bytes::from_array(&env, &[2]);
+        let vec = vec![
+            &env,
+            b.clone(),
+            a.clone(),
+            crate::Bytes::from_array(&env, &[2]),
+            a.clone(),
+        ];
+        assert_eq!(vec.unique(), vec![&env, b, a]);
+    }
+
+    #[test]
+    fn test_partition() {
+        let env = Env::default();
+
+        let vec: Vec<u32> = vec![&env];
+        let (evens, odds) = vec.partition(|x| x % 2 == 0);
+        assert!(evens.is_empty());
+        assert!(odds.is_empty());
+
+        let vec = vec![&env, 2, 4, 6];
+        assert_eq!(vec.partition(|x| x % 2 == 0), (vec.clone(), vec![&env]));
+        assert_eq!(vec.partition(|x| x % 2 == 1), (vec![&env], vec.clone()));
+
+        let vec = vec![&env, 5, 2, 7, 8, 1, 4];
+        let mut calls = 0;
+        let (evens, odds) = vec.partition(|x| {
+            calls += 1;
+            x % 2 == 0
+        });
+        assert_eq!(calls, 6);
+        assert_eq!(evens, vec![&env, 2, 8, 4]);
+        assert_eq!(odds, vec![&env, 5, 7, 1]);
+        // The original vec is unchanged.
+        assert_eq!(vec, vec![&env, 5, 2, 7, 8, 1, 4]);
+    }
+
     #[test]
     fn test_first_index_of() {
         let env = Env::default();
@@ -1379,6 +1879,157 @@ mod test {
         assert_eq!(vec.binary_search(5), Ok(3));
     }
 
+    #[test]
+    fn test_is_sorted() {
+        let env = Env::default();
+
+        assert!(Vec::<i64>::new(&env).is_sorted());
+        assert!(vec![&env, 5].is_sorted());
+        assert!(vec![&env, 0, 3, 5, 5, 7, 9].is_sorted());
+        assert!(!vec![&env, 0, 5, 3, 7].is_sorted());
+        assert!(!vec![&env, 9, 7, 5].is_sorted());
+        assert!(!vec![&env, 1, 2, 3, 0].is_sorted());
+    }
+
+    #[test]
+    fn test_eq_by_content() {
+        let env = Env::default();
+
+        let a = vec![&env, 1u32, 2, 3];
+        let mut b = Vec::new(&env);
+        b.push_front(3u32);
+        b.push_front(2);
+        b.push_front(1);
+        assert_eq!(a, b);
+
+        // Equal items in a different order are not equal.
+        assert_ne!(a, vec![&env, 3u32, 2, 1]);
+        assert_ne!(a, vec![&env, 1u32, 2]);
+    }
+
+    #[test]
+    fn test_binary_insert() {
+        let env = Env::default();
+
+        let mut vec: Vec<i64> = vec![&env];
+        vec.binary_insert(5);
+        assert_eq!(vec, vec![&env, 5]);
+
+        vec.binary_insert(1);
+        assert_eq!(vec, vec![&env, 1, 5]);
+
+        vec.binary_insert(9);
+        assert_eq!(vec, vec![&env, 1, 5, 9]);
+
+        vec.binary_insert(7);
+        assert_eq!(vec, vec![&env, 1, 5, 7, 9]);
+
+        vec.binary_insert(5);
+        assert_eq!(vec, vec![&env, 1, 5, 5, 7, 9]);
+        vec.binary_insert(1);
+        assert_eq!(vec, vec![&env, 1, 1, 5, 5, 7, 9]);
+        vec.binary_insert(9);
+        assert_eq!(vec, vec![&env, 1, 1, 5, 5, 7, 9, 9]);
+    }
+
+    #[test]
+    fn test_binary_insert_equal_items_after_existing() {
+        let env = Env::default();
+
+        // Only the first field takes part in the ordering, so the second
+        // field shows where equal items were inserted.
+        #[derive(Clone, Debug)]
+        struct Entry(u32, u32);
+        impl PartialEq for Entry {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Entry {}
+        impl PartialOrd for Entry {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Entry {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        impl TryFromVal<Env, Val> for Entry {
+            type Error = ConversionError;
+            fn try_from_val(env: &Env, v: &Val) -> Result<Self, Self::Error> {
+                let (a, b) = <(u32, u32)>::try_from_val(env, v)?;
+                Ok(Entry(a, b))
+            }
+        }
+        impl TryFromVal<Env, Entry> for Val {
+            type Error = ConversionError;
+            fn try_from_val(env: &Env, v: &Entry) -> Result<Self, Self::Error> {
+                Val::try_from_val(env, &(v.0, v.1))
+            }
+        }
+
+        let mut vec: Vec<Entry> = Vec::new(&env);
+        vec.binary_insert(Entry(2, 0));
+        vec.binary_insert(Entry(1, 0));
+        vec.binary_insert(Entry(2, 1));
+        vec.binary_insert(Entry(1, 1));
+        vec.binary_insert(Entry(2, 2));
+        let entries: std::vec::Vec<(u32, u32)> = vec.iter().map(|e| (e.0, e.1)).collect();
+        assert_eq!(entries, [(1, 0), (1, 1), (2, 0), (2, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn test_max_min() {
+        let env = Env::default();
+
+        let vec: Vec<i128> = vec![&env];
+        assert_eq!(vec.max_item(), None);
+        assert_eq!(vec.min_item(), None);
+
+        let vec: Vec<i128> = vec![&env, -4];
+        assert_eq!(vec.max_item(), Some(-4));
+        assert_eq!(vec.min_item(), Some(-4));
+
+        let vec: Vec<i128> = vec![&env, 3, -7, i128::MAX, 0, i128::MIN, 5];
+        assert_eq!(vec.max_item(), Some(i128::MAX));
+        assert_eq!(vec.min_item(), Some(i128::MIN));
+    }
+
+    #[test]
+    fn test_max_min_ties() {
+        let env = Env::default();
+
+        let vec: Vec<(u32, u32)> = vec![&env, (1, 2), (0, 5), (1, 2), (0, 5)];
+        assert_eq!(vec.max_item(), Some((1, 2)));
+        assert_eq!(vec.min_item(), Some((0, 5)));
+
+        // The last of the maximum items and the first of the minimum items are
+        // returned.
+        let vec: Vec<(u32, u32)> = vec![&env, (1, 0), (5, 1), (1, 2), (5, 3)];
+        assert_eq!(vec.max_by_key(|(k, _)| *k), Some((5, 3)));
+        assert_eq!(vec.min_by_key(|(k, _)| *k), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_max_min_by_key() {
+        let env = Env::default();
+
+        let vec: Vec<(u32, i128)> = vec![&env];
+        assert_eq!(vec.max_by_key(|(_, bid)| *bid), None);
+        assert_eq!(vec.min_by_key(|(_, bid)| *bid), None);
+
+        let vec: Vec<(u32, i128)> = vec![&env, (1, 100)];
+        assert_eq!(vec.max_by_key(|(_, bid)| *bid), Some((1, 100)));
+        assert_eq!(vec.min_by_key(|(_, bid)| *bid), Some((1, 100)));
+
+        let vec: Vec<(u32, i128)> = vec![&env, (1, 100), (2, 300), (3, -50), (4, 200)];
+        assert_eq!(vec.max_by_key(|(_, bid)| *bid), Some((2, 300)));
+        assert_eq!(vec.min_by_key(|(_, bid)| *bid), Some((3, -50)));
+        assert_eq!(vec.max_by_key(|(id, _)| *id), Some((4, 200)));
+    }
+
     #[cfg(not(target_family = "wasm"))]
     #[test]
     fn test_scval_accessibility_from_udt_types() {
@@ -1683,6 +2334,55 @@ mod test {
         assert_eq!(v.try_get(1), Err(ConversionError.into()));
     }
 
+    #[test]
+    fn test_zip() {
+        let env = Env::default();
+
+        let a: Vec<u32> = vec![&env, 1, 2, 3];
+        let b: Vec<i64> = vec![&env, -1, -2, -3];
+        assert_eq!(a.zip(&b), vec![&env, (1, -1), (2, -2), (3, -3)]);
+        assert_eq!(a.zip_exact(&b), vec![&env, (1, -1), (2, -2), (3, -3)]);
+
+        // Pairs stop at the end of the shorter vec.
+        let short: Vec<i64> = vec![&env, -1];
+        assert_eq!(a.zip(&short), vec![&env, (1, -1)]);
+        assert_eq!(short.zip(&a), vec![&env, (-1, 1)]);
+
+        let empty: Vec<i64> = vec![&env];
+        assert_eq!(a.zip(&empty), vec![&env]);
+        assert_eq!(empty.zip_exact(&Vec::<u32>::new(&env)), vec![&env]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec::zip_exact with mismatched lengths")]
+    fn test_zip_exact_panics_on_length_mismatch() {
+        let env = Env::default();
+
+        let a: Vec<u32> = vec![&env, 1, 2, 3];
+        let b: Vec<i64> = vec![&env, -1];
+        let _ = a.zip_exact(&b);
+    }
+
+    #[test]
+    fn test_iter_enumerated() {
+        let env = Env::default();
+
+        let v: Vec<i64> = vec![&env];
+        assert_eq!(v.iter_enumerated().next(), None);
+
+        let v: Vec<i64> = vec![&env, 10, 20, 30];
+        let mut iter = v.iter_enumerated();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((0, 10)));
+        assert_eq!(iter.next(), Some((1, 20)));
+        assert_eq!(iter.next(), Some((2, 30)));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = v.iter_enumerated();
+        assert_eq!(iter.next_back(), Some((2, 30)));
+        assert_eq!(iter.next(), Some((0, 10)));
+    }
+
     #[test]
     fn test_get_unchecked() {
         let env = Env::default();
@@ -1719,6 +2419,15 @@ mod test {
         _ = v.get_unchecked(v.len()); // out of bound get
     }
 
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 5")]
+    fn test_get_unchecked_panic_includes_index_and_len() {
+        let env = Env::default();
+
+        let v: Vec<i64> = vec![&env, 1, 2, 3];
+        _ = v.get_unchecked(5);
+    }
+
     #[test]
     fn test_try_get_unchecked() {
         let env = Env::default();
@@ -0,0 +1,8 @@
+//! Compile errors reported for invalid contracts and misuse of the SDK types.
+#![cfg(feature = "testutils")]
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
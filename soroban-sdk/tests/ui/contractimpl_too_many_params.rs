@@ -0,0 +1,26 @@
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn add(
+        _env: Env,
+        a: u32,
+        b: u32,
+        c: u32,
+        d: u32,
+        e: u32,
+        f: u32,
+        g: u32,
+        h: u32,
+        i: u32,
+        j: u32,
+        k: u32,
+    ) -> u32 {
+        a + b + c + d + e + f + g + h + i + j + k
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use soroban_sdk::{storage::ReadOnlyStorage, symbol_short};
+
+fn write(storage: &ReadOnlyStorage) {
+    storage.set(&symbol_short!("balance"), &10i128);
+    storage.remove(&symbol_short!("balance"));
+    storage.extend_ttl(&symbol_short!("balance"), 1, 1);
+}
+
+fn main() {}
@@ -7,10 +7,10 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use sha2::{Digest, Sha256};
 use stellar_xdr::curr as stellar_xdr;
-use stellar_xdr::ScSpecEntry;
+use stellar_xdr::{ScMetaEntry, ScSpecEntry};
 use syn::Error;
 
-use soroban_spec::read::{from_wasm, FromWasmError};
+use soroban_spec::read::{from_wasm, meta_from_wasm, FromWasmError};
 
 use types::{generate_enum, generate_error_enum, generate_struct, generate_union};
 
@@ -24,6 +24,8 @@ pub enum GenerateFromFileError {
     Parse(stellar_xdr::Error),
     #[error("getting contract spec: {0}")]
     GetSpec(FromWasmError),
+    #[error("getting contract meta: {0}")]
+    GetMeta(FromWasmError),
 }
 
 pub fn generate_from_file(
@@ -52,8 +54,32 @@ pub fn generate_from_wasm(
     }
 
     let spec = from_wasm(wasm).map_err(GenerateFromFileError::GetSpec)?;
+    let meta = meta_from_wasm(wasm).map_err(GenerateFromFileError::GetMeta)?;
     let code = generate(&spec, file, &sha256);
-    Ok(code)
+    let meta_code = generate_meta(&meta);
+    Ok(quote! {
+        #code
+        #meta_code
+    })
+}
+
+/// Generates the `META` associated constant of the client, holding the key
+/// and value of each meta entry.
+pub fn generate_meta(meta: &[ScMetaEntry]) -> TokenStream {
+    let entries = meta.iter().map(|m| match m {
+        ScMetaEntry::ScMetaV0(m) => {
+            let key = m.key.to_utf8_string_lossy();
+            let val = m.val.to_utf8_string_lossy();
+            quote! { (#key, #val) }
+        }
+    });
+    quote! {
+        impl<'a> Client<'a> {
+            /// The meta entries of the contract, as `(key, value)` pairs in
+            /// the order they appear in the contract.
+            pub const META: &'static [(&'static str, &'static str)] = &[#(#entries),*];
+        }
+    }
 }
 
 pub fn generate(specs: &[ScSpecEntry], file: &str, sha256: &str) -> TokenStream {
@@ -1,7 +1,7 @@
 use std::io::Cursor;
 
 use stellar_xdr::curr as stellar_xdr;
-use stellar_xdr::{Limited, Limits, ReadXdr, ScSpecEntry};
+use stellar_xdr::{Limited, Limits, ReadXdr, ScMetaEntry, ScSpecEntry};
 use wasmparser::{BinaryReaderError, Parser, Payload};
 
 // TODO: Move these functions into stellar_xdr.
@@ -63,3 +63,31 @@ pub fn from_wasm(wasm: &[u8]) -> Result<Vec<ScSpecEntry>, FromWasmError> {
     let spec = raw_from_wasm(wasm)?;
     parse_raw(&spec).map_err(FromWasmError::Parse)
 }
+
+pub fn parse_meta_raw(meta: &[u8]) -> Result<Vec<ScMetaEntry>, stellar_xdr::Error> {
+    let cursor = Cursor::new(meta);
+    let entries = ScMetaEntry::read_xdr_iter(&mut Limited::new(
+        cursor,
+        Limits {
+            depth: 500,
+            len: 0x1000000,
+        },
+    ))
+    .collect::<Result<Vec<_>, _>>()?;
+    Ok(entries)
+}
+
+/// Returns the meta entries in the contract meta custom section of the wasm,
+/// or no entries if the wasm has no such section.
+pub fn meta_from_wasm(wasm: &[u8]) -> Result<Vec<ScMetaEntry>, FromWasmError> {
+    let mut entries = Vec::new();
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload.map_err(FromWasmError::Read)?;
+        if let Payload::CustomSection(section) = payload {
+            if section.name() == "contractmetav0" {
+                entries.extend(parse_meta_raw(section.data()).map_err(FromWasmError::Parse)?);
+            }
+        };
+    }
+    Ok(entries)
+}
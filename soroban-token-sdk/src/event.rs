@@ -1,4 +1,4 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, TryFromVal, Val, Vec};
 
 pub struct Events {
     env: Env,
@@ -47,3 +47,252 @@ impl Events {
         self.env.events().publish(topics, amount);
     }
 }
+
+/// A token event as published by [`Events`], decoded from its topics and data.
+///
+/// Use [`TokenEvent::decode`] in tests to check that a token contract
+/// publishes events in the shape of SEP-41, which is the shape indexers
+/// expect.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenEvent {
+    Approve {
+        from: Address,
+        to: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    },
+    Transfer {
+        from: Address,
+        to: Address,
+        amount: i128,
+    },
+    Mint {
+        admin: Address,
+        to: Address,
+        amount: i128,
+    },
+    Clawback {
+        admin: Address,
+        from: Address,
+        amount: i128,
+    },
+    SetAuthorized {
+        admin: Address,
+        id: Address,
+        authorize: bool,
+    },
+    SetAdmin {
+        admin: Address,
+        new_admin: Address,
+    },
+    Burn {
+        from: Address,
+        amount: i128,
+    },
+}
+
+impl TokenEvent {
+    /// Decodes the topics and data of an event.
+    ///
+    /// Returns None if the event is not a token event, or if its topics or
+    /// data do not have the shape of the token event named by its first
+    /// topic.
+    pub fn decode(env: &Env, topics: &Vec<Val>, data: &Val) -> Option<TokenEvent> {
+        let name = Symbol::try_from_val(env, &topics.get(0)?).ok()?;
+        let address = |i| Address::try_from_val(env, &topics.get(i)?).ok();
+        let topic_count = topics.len();
+        let event = if name == Symbol::new(env, "approve") && topic_count == 3 {
+            let (amount, expiration_ledger) = <(i128, u32)>::try_from_val(env, data).ok()?;
+            TokenEvent::Approve {
+                from: address(1)?,
+                to: address(2)?,
+                amount,
+                expiration_ledger,
+            }
+        } else if name == symbol_short!("transfer") && topic_count == 3 {
+            TokenEvent::Transfer {
+                from: address(1)?,
+                to: address(2)?,
+                amount: i128::try_from_val(env, data).ok()?,
+            }
+        } else if name == symbol_short!("mint") && topic_count == 3 {
+            TokenEvent::Mint {
+                admin: address(1)?,
+                to: address(2)?,
+                amount: i128::try_from_val(env, data).ok()?,
+            }
+        } else if name == symbol_short!("clawback") && topic_count == 3 {
+            TokenEvent::Clawback {
+                admin: address(1)?,
+                from: address(2)?,
+                amount: i128::try_from_val(env, data).ok()?,
+            }
+        } else if name == Symbol::new(env, "set_authorized") && topic_count == 3 {
+            TokenEvent::SetAuthorized {
+                admin: address(1)?,
+                id: address(2)?,
+                authorize: bool::try_from_val(env, data).ok()?,
+            }
+        } else if name == symbol_short!("set_admin") && topic_count == 2 {
+            TokenEvent::SetAdmin {
+                admin: address(1)?,
+                new_admin: Address::try_from_val(env, data).ok()?,
+            }
+        } else if name == symbol_short!("burn") && topic_count == 2 {
+            TokenEvent::Burn {
+                from: address(1)?,
+                amount: i128::try_from_val(env, data).ok()?,
+            }
+        } else {
+            return None;
+        };
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{contract, testutils::Address as _, testutils::Events as _, vec, IntoVal};
+
+    #[contract]
+    struct Token;
+
+    /// Publishes an event from a token contract and returns its topics after
+    /// checking that it decodes to the expected event.
+    fn publish(env: &Env, f: impl FnOnce(&Events), expected: TokenEvent) -> Vec<Val> {
+        let id = env.register(Token, ());
+        env.as_contract(&id, || f(&Events::new(env)));
+        let (contract, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(contract, id);
+        assert_eq!(TokenEvent::decode(env, &topics, &data), Some(expected));
+        topics
+    }
+
+    #[test]
+    fn test_decode() {
+        let env = Env::default();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+
+        let topics = publish(
+            &env,
+            |e| e.approve(a.clone(), b.clone(), 5, 100),
+            TokenEvent::Approve {
+                from: a.clone(),
+                to: b.clone(),
+                amount: 5,
+                expiration_ledger: 100,
+            },
+        );
+        assert_eq!(
+            topics,
+            (Symbol::new(&env, "approve"), a.clone(), b.clone()).into_val(&env)
+        );
+
+        let topics = publish(
+            &env,
+            |e| e.transfer(a.clone(), b.clone(), 5),
+            TokenEvent::Transfer {
+                from: a.clone(),
+                to: b.clone(),
+                amount: 5,
+            },
+        );
+        assert_eq!(
+            topics,
+            (symbol_short!("transfer"), a.clone(), b.clone()).into_val(&env)
+        );
+
+        let topics = publish(
+            &env,
+            |e| e.mint(a.clone(), b.clone(), 5),
+            TokenEvent::Mint {
+                admin: a.clone(),
+                to: b.clone(),
+                amount: 5,
+            },
+        );
+        assert_eq!(
+            topics,
+            (symbol_short!("mint"), a.clone(), b.clone()).into_val(&env)
+        );
+
+        let topics = publish(
+            &env,
+            |e| e.clawback(a.clone(), b.clone(), 5),
+            TokenEvent::Clawback {
+                admin: a.clone(),
+                from: b.clone(),
+                amount: 5,
+            },
+        );
+        assert_eq!(
+            topics,
+            (symbol_short!("clawback"), a.clone(), b.clone()).into_val(&env)
+        );
+
+        let topics = publish(
+            &env,
+            |e| e.set_authorized(a.clone(), b.clone(), true),
+            TokenEvent::SetAuthorized {
+                admin: a.clone(),
+                id: b.clone(),
+                authorize: true,
+            },
+        );
+        assert_eq!(
+            topics,
+            (Symbol::new(&env, "set_authorized"), a.clone(), b.clone()).into_val(&env)
+        );
+
+        let topics = publish(
+            &env,
+            |e| e.set_admin(a.clone(), b.clone()),
+            TokenEvent::SetAdmin {
+                admin: a.clone(),
+                new_admin: b.clone(),
+            },
+        );
+        assert_eq!(
+            topics,
+            (symbol_short!("set_admin"), a.clone()).into_val(&env)
+        );
+
+        let topics = publish(
+            &env,
+            |e| e.burn(a.clone(), 5),
+            TokenEvent::Burn {
+                from: a.clone(),
+                amount: 5,
+            },
+        );
+        assert_eq!(topics, (symbol_short!("burn"), a.clone()).into_val(&env));
+    }
+
+    #[test]
+    fn test_decode_other_events() {
+        let env = Env::default();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let amount: Val = 5i128.into_val(&env);
+
+        // No topics, or an unknown name.
+        assert_eq!(TokenEvent::decode(&env, &vec![&env], &amount), None);
+        let topics = (symbol_short!("swap"), a.clone(), b.clone()).into_val(&env);
+        assert_eq!(TokenEvent::decode(&env, &topics, &amount), None);
+
+        // A known name with the wrong number of topics.
+        let topics = (symbol_short!("transfer"), a.clone()).into_val(&env);
+        assert_eq!(TokenEvent::decode(&env, &topics, &amount), None);
+
+        // A known name with topics or data of the wrong type.
+        let topics = (symbol_short!("transfer"), a.clone(), 1u32).into_val(&env);
+        assert_eq!(TokenEvent::decode(&env, &topics, &amount), None);
+        let topics = (symbol_short!("transfer"), a.clone(), b.clone()).into_val(&env);
+        assert_eq!(
+            TokenEvent::decode(&env, &topics, &5u64.into_val(&env)),
+            None
+        );
+    }
+}
@@ -1,5 +1,7 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl};
+use soroban_sdk::{contract, contractimpl, contractmeta};
+
+contractmeta!(key = "desc", val = "adds two u64s");
 
 #[contract]
 pub struct Contract;